@@ -82,6 +82,13 @@ impl ActorStateModeler {
         }
     }
 
+    /// Removes all actor states while keeping the allocated maps, so that the
+    /// modeler can be reused for another replay.
+    pub fn clear(&mut self) {
+        self.actor_states.clear();
+        self.actor_ids_by_type.clear();
+    }
+
     /// Processes a frame, including handling of new, updated, and deleted actors.
     ///
     /// # Arguments
@@ -1,4 +1,3 @@
-use boxcars;
 use subtr_actor::*;
 
 use std::env;
@@ -6,11 +5,7 @@ use std::env;
 fn main() {
     let args: Vec<_> = env::args().collect();
     let data = std::fs::read(&args[1]).unwrap();
-    let parsing = boxcars::ParserBuilder::new(&data[..])
-        .always_check_crc()
-        .must_parse_network_data()
-        .parse();
-    let replay = parsing.unwrap();
+    let replay = ReplayProcessor::parse_replay(&data[..], CrcMode::Always).unwrap();
 
     let mut collector = NDArrayCollector::<f32>::from_strings(
         &["InterpolatedBallRigidBodyNoVelocities"],
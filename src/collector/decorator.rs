@@ -5,6 +5,12 @@ use crate::*;
 /// frame is processed and the next desired frame time is before the
 /// `target_frame_duration`, [`TimeAdvance::Time`] is used to ensure the next
 /// frame will only be processed after the `target_frame_duration` has passed.
+///
+/// The underlying collector sees the state of the most recent network frame at
+/// each target time, so most feature adders hold their last value between
+/// frames. Continuous quantities can instead be interpolated between frames
+/// with the `Interpolated*` feature adders, such as
+/// [`InterpolatedPlayerBoost`] and [`InterpolatedPlayerRigidBodyNoVelocities`].
 pub struct FrameRateDecorator<'a, C> {
     collector: &'a mut C,
     target_frame_duration: f32,
@@ -74,3 +80,277 @@ impl<'a, C: Collector> Collector for FrameRateDecorator<'a, C> {
         Ok(next_target_time)
     }
 }
+
+/// A struct which decorates a [`Collector`] implementation so that, like a
+/// [`FrameRateDecorator`], it is invoked once per window of
+/// `window_duration` seconds, but at the end of each window rather than at
+/// its start. By then the state of every network frame within the window has
+/// been processed, so the underlying collector can summarize the whole window
+/// instead of taking a snapshot of its first frame.
+///
+/// The decorator itself only controls at which `current_time` values the
+/// underlying collector is invoked. The averaging is done by feature adders
+/// such as [`AveragedBallRigidBody`] and [`AveragedPlayerRigidBody`], which
+/// compute time weighted means of rigid bodies over the window that ends at
+/// `current_time`, and should be given the same window duration. Other
+/// feature adders see the state of the most recent network frame, as with a
+/// [`FrameRateDecorator`].
+///
+/// No rows are produced for the first, partial, window that starts at the
+/// first invocation, so processing a replay produces one row fewer than a
+/// [`FrameRateDecorator`] with the same frame duration.
+pub struct AveragingDecorator<'a, C> {
+    collector: &'a mut C,
+    window_duration: f32,
+    window_end: Option<f32>,
+}
+
+impl<'a, C> AveragingDecorator<'a, C> {
+    /// Constructs a new [`AveragingDecorator`] with the given window duration
+    /// in seconds and underlying [`Collector`] reference.
+    pub fn new(window_duration: f32, collector: &'a mut C) -> Self {
+        Self {
+            collector,
+            window_duration,
+            window_end: None,
+        }
+    }
+
+    /// Constructs a new [`AveragingDecorator`] which produces `fps` windows
+    /// per second.
+    pub fn new_from_fps(fps: f32, collector: &'a mut C) -> Self {
+        Self::new(1.0 / fps, collector)
+    }
+}
+
+impl<'a, C: Collector> Collector for AveragingDecorator<'a, C> {
+    fn process_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        frame: &boxcars::Frame,
+        frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        let next_window_end = current_time + self.window_duration;
+        if self.window_end.is_none() {
+            self.window_end = Some(next_window_end);
+            return Ok(TimeAdvance::Time(next_window_end));
+        }
+
+        let next_target_time =
+            match self
+                .collector
+                .process_frame(processor, frame, frame_number, current_time)?
+            {
+                TimeAdvance::NextFrame => next_window_end,
+                TimeAdvance::Time(t) => f32::max(t, next_window_end),
+            };
+        self.window_end = Some(next_target_time);
+        Ok(TimeAdvance::Time(next_target_time))
+    }
+}
+
+/// A struct which decorates a [`Collector`] implementation so that it is
+/// invoked exactly once per network frame, at the time of that frame. Any
+/// [`TimeAdvance::Time`] returned by the underlying collector is replaced with
+/// [`TimeAdvance::NextFrame`], so no resampling between or across frames ever
+/// happens. See [`Collector::process_replay_native`].
+pub struct NativeFrameDecorator<'a, C> {
+    collector: &'a mut C,
+}
+
+impl<'a, C> NativeFrameDecorator<'a, C> {
+    pub fn new(collector: &'a mut C) -> Self {
+        Self { collector }
+    }
+}
+
+impl<'a, C: Collector> Collector for NativeFrameDecorator<'a, C> {
+    fn process_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        frame: &boxcars::Frame,
+        frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        self.collector
+            .process_frame(processor, frame, frame_number, current_time)?;
+        Ok(TimeAdvance::NextFrame)
+    }
+}
+
+/// A [`Collector`] which routes each frame to one of two underlying
+/// collectors depending on `current_time`, so that a replay can be split in
+/// time (e.g. into training and validation data) in a single pass.
+///
+/// Frames with a `current_time` strictly less than `split_time` are passed to
+/// `before`, and frames with a `current_time` greater than or equal to
+/// `split_time` are passed to `after`. Both collectors are given the same
+/// [`ReplayProcessor`], so any replay metadata they derive from it is
+/// consistent between the two.
+///
+/// The [`TimeAdvance`] returned by the collector that handled the frame is
+/// used to advance time, except that a [`TimeAdvance::Time`] returned by
+/// `before` is capped at `split_time`, so that `after` is always first invoked
+/// at the split boundary rather than at some point past it.
+pub struct SplitCollector<'a, A, B> {
+    before: &'a mut A,
+    after: &'a mut B,
+    split_time: f32,
+}
+
+impl<'a, A, B> SplitCollector<'a, A, B> {
+    /// Constructs a new [`SplitCollector`].
+    ///
+    /// # Arguments
+    ///
+    /// * `split_time`: The replay time, in seconds, at which frames start being
+    ///   passed to `after` instead of `before`.
+    /// * `before`: The collector that receives frames before `split_time`.
+    /// * `after`: The collector that receives frames at or after `split_time`.
+    pub fn new(split_time: f32, before: &'a mut A, after: &'a mut B) -> Self {
+        Self {
+            before,
+            after,
+            split_time,
+        }
+    }
+
+    /// Returns whether frames at `current_time` are passed to the `after`
+    /// collector.
+    pub fn is_after_split(&self, current_time: f32) -> bool {
+        current_time >= self.split_time
+    }
+}
+
+impl<'a, A: Collector, B: Collector> Collector for SplitCollector<'a, A, B> {
+    fn process_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        frame: &boxcars::Frame,
+        frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        if self.is_after_split(current_time) {
+            return self
+                .after
+                .process_frame(processor, frame, frame_number, current_time);
+        }
+        let advance = self
+            .before
+            .process_frame(processor, frame, frame_number, current_time)?;
+        Ok(match advance {
+            TimeAdvance::Time(t) => TimeAdvance::Time(f32::min(t, self.split_time)),
+            TimeAdvance::NextFrame => TimeAdvance::NextFrame,
+        })
+    }
+}
+
+/// A [`Collector`] which passes frames to both of two underlying collectors,
+/// so that e.g. an [`NDArrayCollector`] and a [`ReplayDataCollector`] can be
+/// filled in a single pass over a replay rather than processing it once for
+/// each. The underlying collectors are retrieved with [`Self::into_inner`]
+/// once processing is done.
+///
+/// Each collector is only invoked when the [`TimeAdvance`] it last returned
+/// is due, so each of them sees the same frames and times it would see if it
+/// processed the replay on its own, even when they advance time differently,
+/// e.g. when wrapped in [`FrameRateDecorator`]s with different frame rates.
+/// The composite itself returns the earliest time at which either collector
+/// is due next.
+pub struct CompositeCollector<A, B> {
+    first: A,
+    second: B,
+    first_advance: Option<(TimeAdvance, usize)>,
+    second_advance: Option<(TimeAdvance, usize)>,
+}
+
+impl<A, B> CompositeCollector<A, B> {
+    /// Constructs a new [`CompositeCollector`] from the two collectors that
+    /// receive its frames.
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            first_advance: None,
+            second_advance: None,
+        }
+    }
+
+    /// Consumes the [`CompositeCollector`] and returns the two underlying
+    /// collectors, in the order they were given to [`Self::new`].
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+/// Invokes `collector` if the [`TimeAdvance`] it returned when it was last
+/// invoked, in the frame with the given number, is due, and records what it
+/// returns.
+fn process_frame_if_due<C: Collector>(
+    collector: &mut C,
+    last_advance: &mut Option<(TimeAdvance, usize)>,
+    processor: &ReplayProcessor,
+    frame: &boxcars::Frame,
+    frame_number: usize,
+    current_time: f32,
+) -> SubtrActorResult<()> {
+    let is_due = match last_advance {
+        None => true,
+        Some((TimeAdvance::NextFrame, last_frame_number)) => frame_number > *last_frame_number,
+        Some((TimeAdvance::Time(t), _)) => current_time >= *t,
+    };
+    if is_due {
+        let advance = collector.process_frame(processor, frame, frame_number, current_time)?;
+        *last_advance = Some((advance, frame_number));
+    }
+    Ok(())
+}
+
+impl<A: Collector, B: Collector> Collector for CompositeCollector<A, B> {
+    fn process_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        frame: &boxcars::Frame,
+        frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        process_frame_if_due(
+            &mut self.first,
+            &mut self.first_advance,
+            processor,
+            frame,
+            frame_number,
+            current_time,
+        )?;
+        process_frame_if_due(
+            &mut self.second,
+            &mut self.second_advance,
+            processor,
+            frame,
+            frame_number,
+            current_time,
+        )?;
+        // A collector that asked for the next frame is due at its time, if
+        // there is one.
+        let next_frame_time = processor
+            .replay
+            .network_frames
+            .as_ref()
+            .and_then(|network_frames| network_frames.frames.get(frame_number + 1))
+            .map(|next_frame| next_frame.time);
+        let due_time = |advance: &Option<(TimeAdvance, usize)>| match advance {
+            Some((TimeAdvance::Time(t), _)) => Some(*t),
+            _ => next_frame_time,
+        };
+        Ok(
+            match (
+                due_time(&self.first_advance),
+                due_time(&self.second_advance),
+            ) {
+                (Some(first), Some(second)) => TimeAdvance::Time(f32::min(first, second)),
+                _ => TimeAdvance::NextFrame,
+            },
+        )
+    }
+}
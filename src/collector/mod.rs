@@ -1,10 +1,16 @@
 pub mod decorator;
 pub mod ndarray;
+pub mod per_player;
+pub mod possession;
 pub mod replay_data;
+pub mod row_stream;
 
 pub use self::ndarray::*;
 pub use decorator::*;
+pub use per_player::*;
+pub use possession::*;
 pub use replay_data::*;
+pub use row_stream::*;
 
 use crate::*;
 use boxcars;
@@ -59,6 +65,26 @@ pub trait Collector: Sized {
         ReplayProcessor::new(replay)?.process(&mut self)?;
         Ok(self)
     }
+
+    /// Process an entire replay, invoking [`Self::process_frame`] exactly once
+    /// per network frame, at the time of that frame, regardless of the
+    /// [`TimeAdvance`] values it returns. Collectors that always return
+    /// [`TimeAdvance::NextFrame`], like [`NDArrayCollector`], already behave
+    /// this way with [`Self::process_replay`]; this method makes that
+    /// guarantee explicit. See [`NativeFrameDecorator`].
+    ///
+    /// # Arguments
+    ///
+    /// * `replay` - The [`boxcars::Replay`] to process.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Collector`] itself, potentially modified by the processing
+    /// of the replay.
+    fn process_replay_native(mut self, replay: &boxcars::Replay) -> SubtrActorResult<Self> {
+        ReplayProcessor::new(replay)?.process(&mut NativeFrameDecorator::new(&mut self))?;
+        Ok(self)
+    }
 }
 
 impl<G> Collector for G
@@ -75,3 +101,30 @@ where
         self(processor, frame, frame_number, current_time)
     }
 }
+
+/// Processes each of `replays` with a new [`Collector`] built by
+/// `make_collector`, in parallel on the global [`rayon`] thread pool, and
+/// returns the processed collectors in the same order as `replays`.
+///
+/// Since a [`ReplayProcessor`] borrows its replay and a collector holds state
+/// for a single replay, each replay is given its own collector rather than
+/// sharing one between threads. Errors encountered while processing a replay
+/// are returned in place of its collector, so a replay that fails to process
+/// doesn't prevent the others from being processed.
+///
+/// Only available with the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn process_replays_parallel<C, F>(
+    replays: &[boxcars::Replay],
+    make_collector: F,
+) -> Vec<SubtrActorResult<C>>
+where
+    C: Collector + Send,
+    F: Fn() -> C + Sync,
+{
+    use rayon::prelude::*;
+    replays
+        .par_iter()
+        .map(|replay| make_collector().process_replay(replay))
+        .collect()
+}
@@ -30,6 +30,31 @@ impl NDArrayColumnHeaders {
             player_headers,
         }
     }
+
+    /// Builds the column headers of the features produced by the given
+    /// [`FeatureAdder`] and [`PlayerFeatureAdder`] instances.
+    pub fn from_feature_adders<F>(
+        feature_adders: &FeatureAdders<F>,
+        player_feature_adders: &PlayerFeatureAdders<F>,
+    ) -> Self {
+        let global_headers = feature_adders
+            .iter()
+            .flat_map(move |fa| {
+                fa.get_column_headers()
+                    .iter()
+                    .map(move |column_name| format!("{}", column_name))
+            })
+            .collect();
+        let player_headers = player_feature_adders
+            .iter()
+            .flat_map(move |pfa| {
+                pfa.get_column_headers()
+                    .iter()
+                    .map(move |base_name| format!("{}", base_name))
+            })
+            .collect();
+        Self::new(global_headers, player_headers)
+    }
 }
 
 /// A struct that contains both the metadata of a replay and the associated
@@ -51,6 +76,44 @@ impl ReplayMetaWithHeaders {
         self.headers_vec_from(|_, _info, index| format!("Player {} - ", index))
     }
 
+    /// Like [`Self::headers_vec`], but prefixes player columns with each
+    /// player's [`PlayerInfo::display_name`] rather than their index, so that
+    /// columns can be correlated with players by name.
+    ///
+    /// Players that share a name have their index in
+    /// [`ReplayMeta::player_order`] appended to it, repeatedly if that still
+    /// collides with another name, so every header is unique even in replays
+    /// with identically named players, such as bots.
+    pub fn headers_vec_with_names(&self) -> Vec<String> {
+        let player_names = self.unique_player_names();
+        self.headers_vec_from(|_, _info, index| format!("{} - ", player_names[index]))
+    }
+
+    /// An alias of [`Self::headers_vec_with_names`].
+    pub fn headers_vec_with_player_names(&self) -> Vec<String> {
+        self.headers_vec_with_names()
+    }
+
+    fn unique_player_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .replay_meta
+            .player_order()
+            .enumerate()
+            .map(|(index, info)| info.display_name(index))
+            .collect();
+        loop {
+            let colliding: Vec<usize> = (0..names.len())
+                .filter(|index| names.iter().filter(|n| **n == names[*index]).count() > 1)
+                .collect();
+            if colliding.is_empty() {
+                return names;
+            }
+            for index in colliding {
+                names[index] = format!("{} ({})", names[index], index);
+            }
+        }
+    }
+
     pub fn headers_vec_from<F>(&self, player_prefix_getter: F) -> Vec<String>
     where
         F: Fn(&Self, &PlayerInfo, usize) -> String,
@@ -72,6 +135,59 @@ impl ReplayMetaWithHeaders {
     }
 }
 
+/// Writes `array` to `writer` as CSV. The first line contains the headers from
+/// [`ReplayMetaWithHeaders::headers_vec`], and each row of `array` is written
+/// as one line after it, so the header line is written even if `array` has no
+/// rows. Headers containing commas, double quotes or line breaks are quoted.
+/// NaN and infinite values are written as empty cells.
+pub fn write_ndarray_csv<W: std::io::Write>(
+    meta: &ReplayMetaWithHeaders,
+    array: &ndarray::Array2<f32>,
+    mut writer: W,
+) -> std::io::Result<()> {
+    let headers: Vec<String> = meta
+        .headers_vec()
+        .iter()
+        .map(|header| quote_csv_field(header))
+        .collect();
+    writeln!(writer, "{}", headers.join(","))?;
+    for row in array.rows() {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|value| {
+                if value.is_finite() {
+                    value.to_string()
+                } else {
+                    String::new()
+                }
+            })
+            .collect();
+        writeln!(writer, "{}", cells.join(","))?;
+    }
+    writer.flush()
+}
+
+fn quote_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// A feature adder that failed to produce data for a replay, as reported by
+/// [`NDArrayCollector::check_compatibility`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct IncompatibleFeatureAdder {
+    /// The name of the feature adder, see [`FeatureAdder::name`].
+    pub name: String,
+    /// Whether the feature adder is a [`PlayerFeatureAdder`] rather than a
+    /// [`FeatureAdder`].
+    pub is_player_feature_adder: bool,
+    /// A description of the error the feature adder produced.
+    pub reason: String,
+}
+
 /// [`NDArrayCollector`] is a [`Collector`] which transforms frame-based replay
 /// data into a 2-dimensional array of type [`ndarray::Array2`], where each
 /// element is of a specified floating point type.
@@ -82,14 +198,28 @@ impl ReplayMetaWithHeaders {
 ///
 /// It's main entrypoint is [`Self::get_meta_and_ndarray`], which provides
 /// [`ndarray::Array2`] along with column headers and replay metadata.
+///
+/// When used without a [`FrameRateDecorator`], it produces one row for every
+/// network frame in which the ball exists, since it always returns
+/// [`TimeAdvance::NextFrame`](collector::TimeAdvance::NextFrame). Use
+/// [`Collector::process_replay_native`] to make this explicit.
 pub struct NDArrayCollector<F> {
     feature_adders: FeatureAdders<F>,
     player_feature_adders: PlayerFeatureAdders<F>,
     data: Vec<F>,
     replay_meta: Option<ReplayMeta>,
     frames_added: usize,
+    player_filter: Option<PlayerFilter>,
+    time_window: Option<(f32, f32)>,
+    drop_bodyless_players: bool,
+    validity_mask: Option<Vec<bool>>,
+    players_with_rigid_body: std::collections::HashSet<PlayerId>,
 }
 
+/// A predicate deciding which players an [`NDArrayCollector`] should include.
+/// See [`NDArrayCollector::with_player_filter`].
+pub type PlayerFilter = Arc<dyn Fn(&PlayerInfo) -> bool + Send + Sync>;
+
 impl<F> NDArrayCollector<F> {
     /// Creates a new instance of `NDArrayCollector`.
     ///
@@ -118,9 +248,128 @@ impl<F> NDArrayCollector<F> {
             data: Vec::new(),
             replay_meta: None,
             frames_added: 0,
+            player_filter: None,
+            time_window: None,
+            drop_bodyless_players: false,
+            players_with_rigid_body: std::collections::HashSet::new(),
+            validity_mask: None,
+        }
+    }
+
+    /// Restricts the players for which [`PlayerFeatureAdder`] columns are
+    /// produced to those for which `predicate` returns `true`.
+    ///
+    /// The filter is applied to the [`ReplayMeta`] reported by this collector,
+    /// so [`ReplayMeta::player_count`], [`ReplayMeta::player_order`] and the
+    /// headers built from them describe exactly the players present in the
+    /// output. Players keep the relative order in which they appear in
+    /// [`ReplayProcessor::iter_player_ids_in_order`].
+    pub fn with_player_filter<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&PlayerInfo) -> bool + Send + Sync + 'static,
+    {
+        self.player_filter = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Makes this collector record, for every row and player, whether the
+    /// player's car had a rigid body, i.e. whether the player's columns in the
+    /// row describe a car that was actually on the field rather than the
+    /// defaults that are emitted for absent players. The mask is returned by
+    /// [`Self::get_meta_ndarray_and_mask`].
+    pub fn with_validity_mask(mut self) -> Self {
+        self.validity_mask = Some(Vec::new());
+        self
+    }
+
+    /// Returns a new collector with the same feature adders, player filter,
+    /// time window, bodyless player handling and validity mask setting as this
+    /// one, but without any collected data or replay
+    /// metadata. This allows one configured collector to serve as a template
+    /// when processing many replays with the same set of features.
+    ///
+    /// Feature adders that keep state between frames, such as
+    /// [`BallAcceleration`], are replaced by new instances (see
+    /// [`FeatureAdder::fresh`]), so the clones can be used at the same time,
+    /// e.g. from several threads. Stateless adders are shared.
+    pub fn clone_empty(&self) -> Self {
+        Self {
+            feature_adders: self
+                .feature_adders
+                .iter()
+                .map(|fa| fa.fresh().unwrap_or_else(|| fa.clone()))
+                .collect(),
+            player_feature_adders: self
+                .player_feature_adders
+                .iter()
+                .map(|pfa| pfa.fresh().unwrap_or_else(|| pfa.clone()))
+                .collect(),
+            data: Vec::new(),
+            replay_meta: None,
+            frames_added: 0,
+            player_filter: self.player_filter.clone(),
+            time_window: self.time_window,
+            drop_bodyless_players: self.drop_bodyless_players,
+            players_with_rigid_body: std::collections::HashSet::new(),
+            validity_mask: self.validity_mask.as_ref().map(|_| Vec::new()),
         }
     }
 
+    /// Restricts the rows recorded by this collector to frames whose
+    /// `current_time` lies within `[start, end]`, inclusive at both ends.
+    /// Frames outside the window are still used to update the
+    /// [`ReplayProcessor`], but no features are computed for them, so the
+    /// number of rows of the resulting [`ndarray::Array2`] and
+    /// [`Self::frames_added`] count only frames within the window.
+    pub fn set_time_window(&mut self, start: f32, end: f32) {
+        self.time_window = Some((start, end));
+    }
+
+    /// Sets whether players whose car never had a rigid body in any recorded
+    /// row, such as AFK players, are dropped from the output.
+    ///
+    /// The set of players is fixed when processing begins, so columns are
+    /// still collected for such players during processing. They are removed
+    /// by [`Self::get_meta_and_ndarray`], which also removes the players from
+    /// the returned [`ReplayMeta`], so that the expanded headers keep lining
+    /// up with the columns of the array.
+    pub fn set_drop_bodyless_players(&mut self, drop_bodyless_players: bool) {
+        self.drop_bodyless_players = drop_bodyless_players;
+    }
+
+    /// Returns the number of rows that have been recorded so far.
+    pub fn frames_added(&self) -> usize {
+        self.frames_added
+    }
+
+    fn is_in_time_window(&self, current_time: f32) -> bool {
+        self.time_window
+            .map(|(start, end)| start <= current_time && current_time <= end)
+            .unwrap_or(true)
+    }
+
+    /// Returns the names of the global and player feature adders that this
+    /// collector was built with, in the order in which their columns appear.
+    ///
+    /// # Returns
+    ///
+    /// A tuple whose first element contains the names of the [`FeatureAdder`]
+    /// instances and whose second element contains the names of the
+    /// [`PlayerFeatureAdder`] instances. See [`FeatureAdder::name`] for how
+    /// these names are determined.
+    pub fn feature_adder_names(&self) -> (Vec<String>, Vec<String>) {
+        (
+            self.feature_adders
+                .iter()
+                .map(|fa| fa.name().to_string())
+                .collect(),
+            self.player_feature_adders
+                .iter()
+                .map(|pfa| pfa.name().to_string())
+                .collect(),
+        )
+    }
+
     /// Returns the column headers of the 2-dimensional array produced by the
     /// [`NDArrayCollector`].
     ///
@@ -129,25 +378,7 @@ impl<F> NDArrayCollector<F> {
     /// An instance of [`NDArrayColumnHeaders`] representing the column headers
     /// in the collected data.
     pub fn get_column_headers(&self) -> NDArrayColumnHeaders {
-        let global_headers = self
-            .feature_adders
-            .iter()
-            .flat_map(move |fa| {
-                fa.get_column_headers()
-                    .iter()
-                    .map(move |column_name| format!("{}", column_name))
-            })
-            .collect();
-        let player_headers = self
-            .player_feature_adders
-            .iter()
-            .flat_map(move |pfa| {
-                pfa.get_column_headers()
-                    .iter()
-                    .map(move |base_name| format!("{}", base_name))
-            })
-            .collect();
-        NDArrayColumnHeaders::new(global_headers, player_headers)
+        NDArrayColumnHeaders::from_feature_adders(&self.feature_adders, &self.player_feature_adders)
     }
 
     /// This function consumes the [`NDArrayCollector`] instance and returns the
@@ -159,8 +390,17 @@ impl<F> NDArrayCollector<F> {
     /// [`ndarray::Array2`].
     ///
     /// This method is a shorthand for calling [`Self::get_meta_and_ndarray`]
-    /// and discarding the replay metadata and headers.
+    /// and discarding the replay metadata and headers, except that a
+    /// collector that has not processed any frames returns an empty array
+    /// with one column for each global feature, since without a replay there
+    /// are no players and no metadata.
     pub fn get_ndarray(self) -> SubtrActorResult<ndarray::Array2<F>> {
+        if self.replay_meta.is_none() && self.frames_added == 0 {
+            let global_feature_count = self.get_column_headers().global_headers.len();
+            return ndarray::Array2::from_shape_vec((0, global_feature_count), Vec::new())
+                .map_err(SubtrActorErrorVariant::NDArrayShapeError)
+                .map_err(SubtrActorError::new);
+        }
         self.get_meta_and_ndarray().map(|a| a.1)
     }
 
@@ -173,12 +413,28 @@ impl<F> NDArrayCollector<F> {
     /// - [`ReplayMetaWithHeaders`]: The replay metadata along with the headers
     /// for each column in the ndarray.
     /// - [`ndarray::Array2<F>`]: The collected features as a 2D ndarray.
+    ///
+    /// When no rows were collected, e.g. because the ball never existed, the
+    /// array is empty but still has the correct number of columns.
+    /// [`SubtrActorErrorVariant::InconsistentFeatureLength`] is returned if
+    /// the feature adders did not produce the number of features their
+    /// headers describe.
     pub fn get_meta_and_ndarray(
-        self,
+        mut self,
     ) -> SubtrActorResult<(ReplayMetaWithHeaders, ndarray::Array2<F>)> {
+        if self.drop_bodyless_players {
+            self.remove_bodyless_players()?;
+        }
         let features_per_row = self.try_get_frame_feature_count()?;
         let expected_length = features_per_row * self.frames_added;
-        assert!(self.data.len() == expected_length);
+        if self.data.len() != expected_length {
+            return SubtrActorError::new_result(
+                SubtrActorErrorVariant::InconsistentFeatureLength {
+                    expected: expected_length,
+                    actual: self.data.len(),
+                },
+            );
+        }
         let column_headers = self.get_column_headers();
         Ok((
             ReplayMetaWithHeaders {
@@ -193,6 +449,44 @@ impl<F> NDArrayCollector<F> {
         ))
     }
 
+    /// Like [`Self::get_meta_and_ndarray`], but additionally returns the mask
+    /// recorded because of [`Self::with_validity_mask`]. The mask has one row
+    /// for each row of the features array and one column for each player, in
+    /// the order of [`ReplayMeta::player_order`]. An element is `true` if the
+    /// player's car had a rigid body in that row.
+    ///
+    /// Returns [`SubtrActorErrorVariant::ValidityMaskNotRecorded`] if the
+    /// collector was not built with [`Self::with_validity_mask`].
+    pub fn get_meta_ndarray_and_mask(
+        mut self,
+    ) -> SubtrActorResult<(
+        ReplayMetaWithHeaders,
+        ndarray::Array2<F>,
+        ndarray::Array2<bool>,
+    )> {
+        let mask = self.validity_mask.take().ok_or(SubtrActorError::new(
+            SubtrActorErrorVariant::ValidityMaskNotRecorded,
+        ))?;
+        let mut keep_player = self.try_get_keep_player()?;
+        let player_count = keep_player.len();
+        if !self.drop_bodyless_players {
+            keep_player = vec![true; player_count];
+        }
+        let kept_player_count = keep_player.iter().filter(|keep| **keep).count();
+        let mask: Vec<bool> = mask
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| keep_player[index % player_count])
+            .map(|(_, valid)| valid)
+            .collect();
+        let frames_added = self.frames_added;
+        let (meta, array) = self.get_meta_and_ndarray()?;
+        let mask = ndarray::Array2::from_shape_vec((frames_added, kept_player_count), mask)
+            .map_err(SubtrActorErrorVariant::NDArrayShapeError)
+            .map_err(SubtrActorError::new)?;
+        Ok((meta, array, mask))
+    }
+
     /// Processes a [`boxcars::Replay`] and returns its metadata along with column headers.
     ///
     /// This method first processes the replay using a [`ReplayProcessor`]. It
@@ -227,6 +521,114 @@ impl<F> NDArrayCollector<F> {
         })
     }
 
+    /// Processes just enough of `replay` to determine its players, and
+    /// returns the fully expanded list of column headers, with the player
+    /// headers repeated and prefixed for each player, exactly as they line up
+    /// with the columns of the array produced by this collector.
+    ///
+    /// The replay needs to be (partially) processed because the number of
+    /// players, and therefore the number of columns, depends on it. This is a
+    /// shorthand for calling [`Self::process_and_get_meta_and_headers`]
+    /// followed by [`ReplayMetaWithHeaders::headers_vec`], and is intended to
+    /// give language bindings a single call that returns the headers.
+    pub fn process_and_get_expanded_column_headers(
+        &mut self,
+        replay: &boxcars::Replay,
+    ) -> SubtrActorResult<Vec<String>> {
+        Ok(self.process_and_get_meta_and_headers(replay)?.headers_vec())
+    }
+
+    /// Processes the beginning of `replay` and checks that every feature adder
+    /// of this collector can produce data for it, so that replays of game
+    /// modes that the feature adders don't support can be rejected before
+    /// committing to a full pass.
+    ///
+    /// The feature adders are evaluated once, on the first frame in which the
+    /// ball exists, or on frame [`ACTOR_ID_DISCOVERY_FRAMES`] if it doesn't
+    /// exist by then, and player feature adders are evaluated for every
+    /// player. Each adder that fails is reported in a
+    /// [`SubtrActorErrorVariant::IncompatibleFeatureAdders`] error along with
+    /// the reason it failed. Feature adders that fall back to default values
+    /// rather than failing can't be detected this way.
+    ///
+    /// The check is done with fresh instances of any feature adders that keep
+    /// state (see [`FeatureAdder::fresh`]), so it doesn't affect the features
+    /// this collector goes on to produce.
+    pub fn check_compatibility(&self, replay: &boxcars::Replay) -> SubtrActorResult<()> {
+        let checked = self.clone_empty();
+        let mut processor = ReplayProcessor::new(replay)?;
+        let mut incompatible = Vec::new();
+        let mut handler = |processor: &ReplayProcessor,
+                           frame: &boxcars::Frame,
+                           frame_number: usize,
+                           current_time: f32| {
+            if frame_number < ACTOR_ID_DISCOVERY_FRAMES
+                && !processor.ball_rigid_body_exists().unwrap_or(false)
+            {
+                return Ok(collector::TimeAdvance::NextFrame);
+            }
+            incompatible =
+                checked.find_incompatible_adders(processor, frame, frame_number, current_time);
+            SubtrActorError::new_result(SubtrActorErrorVariant::FinishProcessingEarly)
+        };
+        let process_result = processor.process(&mut handler);
+        if let Err(error) = process_result {
+            if !matches!(error.variant, SubtrActorErrorVariant::FinishProcessingEarly) {
+                return Err(error);
+            }
+        }
+        if incompatible.is_empty() {
+            Ok(())
+        } else {
+            SubtrActorError::new_result(SubtrActorErrorVariant::IncompatibleFeatureAdders(
+                incompatible,
+            ))
+        }
+    }
+
+    fn find_incompatible_adders(
+        &self,
+        processor: &ReplayProcessor,
+        frame: &boxcars::Frame,
+        frame_number: usize,
+        current_time: f32,
+    ) -> Vec<IncompatibleFeatureAdder> {
+        let mut scratch = Vec::new();
+        let global_errors = self.feature_adders.iter().filter_map(|fa| {
+            fa.add_features(processor, frame, frame_number, current_time, &mut scratch)
+                .err()
+                .map(|error| IncompatibleFeatureAdder {
+                    name: fa.name().to_string(),
+                    is_player_feature_adder: false,
+                    reason: error.variant.to_string(),
+                })
+        });
+        let mut incompatible: Vec<_> = global_errors.collect();
+
+        let mut scratch = Vec::new();
+        for pfa in self.player_feature_adders.iter() {
+            let error = processor.iter_player_ids_in_order().find_map(|player_id| {
+                pfa.add_features(
+                    player_id,
+                    processor,
+                    frame,
+                    frame_number,
+                    current_time,
+                    &mut scratch,
+                )
+                .err()
+            });
+            if let Some(error) = error {
+                incompatible.push(IncompatibleFeatureAdder {
+                    name: pfa.name().to_string(),
+                    is_player_feature_adder: true,
+                    reason: error.variant.to_string(),
+                });
+            }
+        }
+        incompatible
+    }
+
     fn try_get_frame_feature_count(&self) -> SubtrActorResult<usize> {
         let player_count = self
             .replay_meta
@@ -248,12 +650,86 @@ impl<F> NDArrayCollector<F> {
         Ok(global_feature_count + player_feature_count)
     }
 
+    fn try_get_keep_player(&self) -> SubtrActorResult<Vec<bool>> {
+        let replay_meta = self.replay_meta.as_ref().ok_or(SubtrActorError::new(
+            SubtrActorErrorVariant::CouldNotBuildReplayMeta,
+        ))?;
+        Ok(replay_meta
+            .player_order()
+            .map(|info| self.players_with_rigid_body.contains(&info.remote_id))
+            .collect())
+    }
+
+    fn remove_bodyless_players(&mut self) -> SubtrActorResult<()> {
+        let features_per_row = self.try_get_frame_feature_count()?;
+        let global_feature_count: usize = self
+            .feature_adders
+            .iter()
+            .map(|fa| fa.features_added())
+            .sum();
+        let player_feature_count: usize = self
+            .player_feature_adders
+            .iter()
+            .map(|pfa| pfa.features_added())
+            .sum();
+        let keep_player = self.try_get_keep_player()?;
+        if keep_player.iter().all(|keep| *keep) {
+            return Ok(());
+        }
+
+        let keep_column: Vec<bool> = (0..features_per_row)
+            .map(|column| {
+                column < global_feature_count
+                    || keep_player[(column - global_feature_count) / player_feature_count]
+            })
+            .collect();
+        let data = std::mem::take(&mut self.data);
+        self.data = data
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| keep_column[index % features_per_row])
+            .map(|(_, value)| value)
+            .collect();
+
+        let replay_meta = self.replay_meta.as_mut().ok_or(SubtrActorError::new(
+            SubtrActorErrorVariant::CouldNotBuildReplayMeta,
+        ))?;
+        let players_with_rigid_body = &self.players_with_rigid_body;
+        replay_meta
+            .team_zero
+            .retain(|info| players_with_rigid_body.contains(&info.remote_id));
+        replay_meta
+            .team_one
+            .retain(|info| players_with_rigid_body.contains(&info.remote_id));
+        Ok(())
+    }
+
     fn maybe_set_replay_meta(&mut self, processor: &ReplayProcessor) -> SubtrActorResult<()> {
         if let None = self.replay_meta {
-            self.replay_meta = Some(processor.get_replay_meta()?);
+            for feature_adder in self.feature_adders.iter() {
+                feature_adder.check_replay(processor.replay)?;
+            }
+            for player_feature_adder in self.player_feature_adders.iter() {
+                player_feature_adder.check_replay(processor.replay)?;
+            }
+            let mut replay_meta = processor.get_replay_meta()?;
+            if let Some(player_filter) = &self.player_filter {
+                replay_meta.team_zero.retain(|info| player_filter(info));
+                replay_meta.team_one.retain(|info| player_filter(info));
+            }
+            self.replay_meta = Some(replay_meta);
         }
         Ok(())
     }
+
+    fn includes_player(&self, player_id: &PlayerId) -> bool {
+        self.player_filter.is_none()
+            || self
+                .replay_meta
+                .as_ref()
+                .map(|meta| meta.player_order().any(|info| &info.remote_id == player_id))
+                .unwrap_or(false)
+    }
 }
 
 impl<F> Collector for NDArrayCollector<F> {
@@ -266,6 +742,10 @@ impl<F> Collector for NDArrayCollector<F> {
     ) -> SubtrActorResult<collector::TimeAdvance> {
         self.maybe_set_replay_meta(processor)?;
 
+        if !self.is_in_time_window(current_time) {
+            return Ok(collector::TimeAdvance::NextFrame);
+        }
+
         if !processor.ball_rigid_body_exists()? {
             return Ok(collector::TimeAdvance::NextFrame);
         }
@@ -280,7 +760,20 @@ impl<F> Collector for NDArrayCollector<F> {
             )?;
         }
 
-        for player_id in processor.iter_player_ids_in_order() {
+        let player_ids: Vec<_> = processor
+            .iter_player_ids_in_order()
+            .filter(|player_id| self.includes_player(player_id))
+            .collect();
+        for player_id in player_ids {
+            if self.drop_bodyless_players
+                && !self.players_with_rigid_body.contains(player_id)
+                && processor.get_player_rigid_body(player_id).is_ok()
+            {
+                self.players_with_rigid_body.insert(player_id.clone());
+            }
+            if let Some(validity_mask) = self.validity_mask.as_mut() {
+                validity_mask.push(processor.get_player_rigid_body(player_id).is_ok());
+            }
             for player_feature_adder in self.player_feature_adders.iter() {
                 player_feature_adder.add_features(
                     player_id,
@@ -299,38 +792,212 @@ impl<F> Collector for NDArrayCollector<F> {
     }
 }
 
-impl NDArrayCollector<f32> {
-    pub fn from_strings(fa_names: &[&str], pfa_names: &[&str]) -> SubtrActorResult<Self> {
-        let feature_adders: Vec<Arc<dyn FeatureAdder<f32> + Send + Sync>> = fa_names
+impl<F> NDArrayCollector<F> {
+    fn from_strings_and_maps(
+        fa_names: &[&str],
+        pfa_names: &[&str],
+        name_to_feature_adder: &NameToFeatureAdder<F>,
+        name_to_player_feature_adder: &NameToPlayerFeatureAdder<F>,
+    ) -> SubtrActorResult<Self> {
+        let unknown_name = |name: &&str| {
+            SubtrActorError::new(SubtrActorErrorVariant::UnknownFeatureAdderName(
+                name.to_string(),
+            ))
+        };
+        let feature_adders: FeatureAdders<F> = fa_names
             .iter()
             .map(|name| {
-                Ok(NAME_TO_GLOBAL_FEATURE_ADDER
+                let constructor = name_to_feature_adder
                     .get(name)
-                    .ok_or_else(|| {
-                        SubtrActorError::new(SubtrActorErrorVariant::UnknownFeatureAdderName(
-                            name.to_string(),
-                        ))
-                    })?
-                    .clone())
+                    .ok_or_else(|| unknown_name(name))?;
+                Ok(constructor())
             })
             .collect::<SubtrActorResult<Vec<_>>>()?;
-        let player_feature_adders: Vec<Arc<dyn PlayerFeatureAdder<f32> + Send + Sync>> = pfa_names
+        let player_feature_adders: PlayerFeatureAdders<F> = pfa_names
             .iter()
             .map(|name| {
-                Ok(NAME_TO_PLAYER_FEATURE_ADDER
+                let constructor = name_to_player_feature_adder
                     .get(name)
-                    .ok_or_else(|| {
-                        SubtrActorError::new(SubtrActorErrorVariant::UnknownFeatureAdderName(
-                            name.to_string(),
-                        ))
-                    })?
-                    .clone())
+                    .ok_or_else(|| unknown_name(name))?;
+                Ok(constructor())
             })
             .collect::<SubtrActorResult<Vec<_>>>()?;
         Ok(Self::new(feature_adders, player_feature_adders))
     }
 }
 
+impl NDArrayCollector<f32> {
+    pub fn from_strings(fa_names: &[&str], pfa_names: &[&str]) -> SubtrActorResult<Self> {
+        Self::from_strings_and_maps(
+            fa_names,
+            pfa_names,
+            &NAME_TO_GLOBAL_FEATURE_ADDER,
+            &NAME_TO_PLAYER_FEATURE_ADDER,
+        )
+    }
+}
+
+impl NDArrayCollector<f64> {
+    /// The same as `from_strings` for `NDArrayCollector<f32>`, but the named
+    /// feature adders produce `f64` values. Features are still computed as
+    /// `f32` by the feature adders and widened when they are added.
+    pub fn from_strings(fa_names: &[&str], pfa_names: &[&str]) -> SubtrActorResult<Self> {
+        Self::from_strings_and_maps(
+            fa_names,
+            pfa_names,
+            &NAME_TO_GLOBAL_FEATURE_ADDER_F64,
+            &NAME_TO_PLAYER_FEATURE_ADDER_F64,
+        )
+    }
+}
+
+// The registries of named feature adders hold constructors rather than
+// instances, so that every collector gets its own instances of adders that
+// keep state between frames, such as [`BallAcceleration`].
+type FeatureAdderConstructor<F> = fn() -> Arc<dyn FeatureAdder<F> + Send + Sync + 'static>;
+type PlayerFeatureAdderConstructor<F> =
+    fn() -> Arc<dyn PlayerFeatureAdder<F> + Send + Sync + 'static>;
+type NameToFeatureAdder<F> = std::collections::HashMap<&'static str, FeatureAdderConstructor<F>>;
+type NameToPlayerFeatureAdder<F> =
+    std::collections::HashMap<&'static str, PlayerFeatureAdderConstructor<F>>;
+
+/// A builder for an [`NDArrayCollector`] that makes it possible to mix the
+/// built in feature adders, looked up by name as in
+/// [`NDArrayCollector::from_strings`], with custom [`FeatureAdder`] and
+/// [`PlayerFeatureAdder`] instances, such as closure based ones. Columns appear
+/// in the order in which their adders were added.
+///
+/// Unknown names are reported with
+/// [`SubtrActorErrorVariant::UnknownFeatureAdderName`] by [`Self::build`].
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use subtr_actor::*;
+///
+/// # fn f() -> SubtrActorResult<()> {
+/// let collector = NDArrayCollectorBuilder::<f32>::new()
+///     .add_global_named("BallRigidBody")
+///     .add_global(Arc::new((
+///         |processor: &ReplayProcessor,
+///          _frame: &boxcars::Frame,
+///          _index: usize,
+///          _time: f32|
+///          -> SubtrActorResult<[f32; 1]> {
+///             Ok([processor.get_seconds_remaining()? as f32])
+///         },
+///         &["seconds remaining"],
+///     )))
+///     .add_player_named("PlayerBoost")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct NDArrayCollectorBuilder<F: 'static> {
+    feature_adders: FeatureAdders<F>,
+    player_feature_adders: PlayerFeatureAdders<F>,
+    name_to_feature_adder: &'static NameToFeatureAdder<F>,
+    name_to_player_feature_adder: &'static NameToPlayerFeatureAdder<F>,
+    unknown_name: Option<String>,
+}
+
+impl NDArrayCollectorBuilder<f32> {
+    /// Creates a builder without any feature adders.
+    pub fn new() -> Self {
+        Self::with_maps(&NAME_TO_GLOBAL_FEATURE_ADDER, &NAME_TO_PLAYER_FEATURE_ADDER)
+    }
+}
+
+impl NDArrayCollectorBuilder<f64> {
+    /// Creates a builder without any feature adders, whose named feature
+    /// adders produce `f64` values. See `NDArrayCollector::<f64>::from_strings`.
+    pub fn new() -> Self {
+        Self::with_maps(
+            &NAME_TO_GLOBAL_FEATURE_ADDER_F64,
+            &NAME_TO_PLAYER_FEATURE_ADDER_F64,
+        )
+    }
+}
+
+impl Default for NDArrayCollectorBuilder<f32> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for NDArrayCollectorBuilder<f64> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: 'static> NDArrayCollectorBuilder<F> {
+    fn with_maps(
+        name_to_feature_adder: &'static NameToFeatureAdder<F>,
+        name_to_player_feature_adder: &'static NameToPlayerFeatureAdder<F>,
+    ) -> Self {
+        Self {
+            feature_adders: Vec::new(),
+            player_feature_adders: Vec::new(),
+            name_to_feature_adder,
+            name_to_player_feature_adder,
+            unknown_name: None,
+        }
+    }
+
+    /// Adds the built in global [`FeatureAdder`] registered under `name`.
+    pub fn add_global_named(mut self, name: &str) -> Self {
+        match self.name_to_feature_adder.get(name) {
+            Some(constructor) => self.feature_adders.push(constructor()),
+            None => self.record_unknown_name(name),
+        }
+        self
+    }
+
+    /// Adds the built in [`PlayerFeatureAdder`] registered under `name`.
+    pub fn add_player_named(mut self, name: &str) -> Self {
+        match self.name_to_player_feature_adder.get(name) {
+            Some(constructor) => self.player_feature_adders.push(constructor()),
+            None => self.record_unknown_name(name),
+        }
+        self
+    }
+
+    /// Adds a custom global [`FeatureAdder`].
+    pub fn add_global(mut self, feature_adder: Arc<dyn FeatureAdder<F> + Send + Sync>) -> Self {
+        self.feature_adders.push(feature_adder);
+        self
+    }
+
+    /// Adds a custom [`PlayerFeatureAdder`].
+    pub fn add_player(
+        mut self,
+        player_feature_adder: Arc<dyn PlayerFeatureAdder<F> + Send + Sync>,
+    ) -> Self {
+        self.player_feature_adders.push(player_feature_adder);
+        self
+    }
+
+    /// Builds the [`NDArrayCollector`], or returns an error naming the first
+    /// unknown feature adder name that was added.
+    pub fn build(self) -> SubtrActorResult<NDArrayCollector<F>> {
+        if let Some(name) = self.unknown_name {
+            return SubtrActorError::new_result(SubtrActorErrorVariant::UnknownFeatureAdderName(
+                name,
+            ));
+        }
+        Ok(NDArrayCollector::new(
+            self.feature_adders,
+            self.player_feature_adders,
+        ))
+    }
+
+    fn record_unknown_name(&mut self, name: &str) {
+        if self.unknown_name.is_none() {
+            self.unknown_name = Some(name.to_string());
+        }
+    }
+}
+
 impl<F: TryFrom<f32> + Send + Sync + 'static> Default for NDArrayCollector<F>
 where
     <F as TryFrom<f32>>::Error: std::fmt::Debug,
@@ -354,12 +1021,39 @@ where
 /// [`LengthCheckedFeatureAdder`] trait instead, which provides compile-time
 /// guarantees about the number of features returned.
 pub trait FeatureAdder<F> {
+    /// A name identifying this feature adder. Adders built with the macros in
+    /// this module report the name of their struct, which is also the name
+    /// under which the built in adders can be found by
+    /// [`NDArrayCollector::from_strings`]. Other implementations default to
+    /// their type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
     fn features_added(&self) -> usize {
         self.get_column_headers().len()
     }
 
     fn get_column_headers(&self) -> &[&str];
 
+    /// Returns a new instance of this feature adder, without any of the state
+    /// it accumulated while adding features, or `None` if it keeps no state
+    /// between invocations, in which case a single instance can be shared.
+    /// [`NDArrayCollector::clone_empty`] uses this so that its clones never
+    /// share stateful adders such as [`BallAcceleration`].
+    fn fresh(&self) -> Option<Arc<dyn FeatureAdder<F> + Send + Sync>> {
+        None
+    }
+
+    /// Checks that this feature adder can extract its features from the
+    /// given replay. [`NDArrayCollector`] calls this once, before adding the
+    /// features of the first frame of a replay, so that configuration errors
+    /// are reported even if they would not otherwise surface until some
+    /// later frame, or at all.
+    fn check_replay(&self, _replay: &boxcars::Replay) -> SubtrActorResult<()> {
+        Ok(())
+    }
+
     fn add_features(
         &self,
         processor: &ReplayProcessor,
@@ -397,10 +1091,24 @@ pub trait LengthCheckedFeatureAdder<F, const N: usize> {
 /// bridge the gap between the two traits, as Rust's type system does not
 /// currently provide a way to prove to the compiler that there will always be
 /// exactly one implementation of [`LengthCheckedFeatureAdder`] for each type.
+///
+/// Types that keep state between invocations should pass `fresh = $fresh`,
+/// where `$fresh` is a function that builds a new instance from an existing
+/// one, to implement [`FeatureAdder::fresh`].
 #[macro_export]
 macro_rules! impl_feature_adder {
     ($struct_name:ident) => {
-        impl<F: TryFrom<f32>> FeatureAdder<F> for $struct_name<F>
+        impl_feature_adder!(@impl $struct_name, [], {});
+    };
+    ($struct_name:ident, fresh = $fresh:expr) => {
+        impl_feature_adder!(@impl $struct_name, [+ Send + Sync + 'static], {
+            fn fresh(&self) -> Option<std::sync::Arc<dyn FeatureAdder<F> + Send + Sync>> {
+                Some(std::sync::Arc::new($fresh(self)))
+            }
+        });
+    };
+    (@impl $struct_name:ident, [$($bounds:tt)*], { $($fresh:tt)* }) => {
+        impl<F: TryFrom<f32> $($bounds)*> FeatureAdder<F> for $struct_name<F>
         where
             <F as TryFrom<f32>>::Error: std::fmt::Debug,
         {
@@ -425,6 +1133,12 @@ macro_rules! impl_feature_adder {
             fn get_column_headers(&self) -> &[&str] {
                 self.get_column_headers_array()
             }
+
+            fn name(&self) -> &str {
+                stringify!($struct_name)
+            }
+
+            $($fresh)*
         }
     };
 }
@@ -437,12 +1151,30 @@ macro_rules! impl_feature_adder {
 /// instead, which provides compile-time guarantees about the number of features
 /// returned.
 pub trait PlayerFeatureAdder<F> {
+    /// A name identifying this player feature adder. See
+    /// [`FeatureAdder::name`].
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+
     fn features_added(&self) -> usize {
         self.get_column_headers().len()
     }
 
     fn get_column_headers(&self) -> &[&str];
 
+    /// Returns a new instance of this player feature adder, without any of
+    /// the state it accumulated. See [`FeatureAdder::fresh`].
+    fn fresh(&self) -> Option<Arc<dyn PlayerFeatureAdder<F> + Send + Sync>> {
+        None
+    }
+
+    /// Checks that this player feature adder can extract its features from
+    /// the given replay. See [`FeatureAdder::check_replay`].
+    fn check_replay(&self, _replay: &boxcars::Replay) -> SubtrActorResult<()> {
+        Ok(())
+    }
+
     fn add_features(
         &self,
         player_id: &PlayerId,
@@ -482,11 +1214,22 @@ pub trait LengthCheckedPlayerFeatureAdder<F, const N: usize> {
 /// to bridge the gap between the two traits, as Rust's type system does not
 /// currently provide a way to prove to the compiler that there will always be
 /// exactly one implementation of [`LengthCheckedPlayerFeatureAdder`] for each
-/// type.
+/// type. As with [`impl_feature_adder!`], types that keep state between
+/// invocations should pass `fresh = $fresh`.
 #[macro_export]
 macro_rules! impl_player_feature_adder {
     ($struct_name:ident) => {
-        impl<F: TryFrom<f32>> PlayerFeatureAdder<F> for $struct_name<F>
+        impl_player_feature_adder!(@impl $struct_name, [], {});
+    };
+    ($struct_name:ident, fresh = $fresh:expr) => {
+        impl_player_feature_adder!(@impl $struct_name, [+ Send + Sync + 'static], {
+            fn fresh(&self) -> Option<std::sync::Arc<dyn PlayerFeatureAdder<F> + Send + Sync>> {
+                Some(std::sync::Arc::new($fresh(self)))
+            }
+        });
+    };
+    (@impl $struct_name:ident, [$($bounds:tt)*], { $($fresh:tt)* }) => {
+        impl<F: TryFrom<f32> $($bounds)*> PlayerFeatureAdder<F> for $struct_name<F>
         where
             <F as TryFrom<f32>>::Error: std::fmt::Debug,
         {
@@ -511,6 +1254,12 @@ macro_rules! impl_player_feature_adder {
             fn get_column_headers(&self) -> &[&str] {
                 self.get_column_headers_array()
             }
+
+            fn name(&self) -> &str {
+                stringify!($struct_name)
+            }
+
+            $($fresh)*
         }
     };
 }
@@ -533,6 +1282,12 @@ where
     fn get_column_headers(&self) -> &[&str] {
         &self.1.as_slice()
     }
+
+    /// Closure based feature adders have no name of their own, so they are
+    /// identified by their first column header.
+    fn name(&self) -> &str {
+        self.1.first().copied().unwrap_or("closure")
+    }
 }
 
 impl<G, F, const N: usize> PlayerFeatureAdder<F> for (G, &[&str; N])
@@ -560,6 +1315,12 @@ where
     fn get_column_headers(&self) -> &[&str] {
         &self.1.as_slice()
     }
+
+    /// Identified by the first column header, as with closure based
+    /// [`FeatureAdder`] instances.
+    fn name(&self) -> &str {
+        self.1.first().copied().unwrap_or("closure")
+    }
 }
 
 /// This macro creates a global [`FeatureAdder`] struct and implements the
@@ -630,11 +1391,20 @@ macro_rules! build_global_feature_adder {
 /// # Parameters
 ///
 /// * `$struct_name`: The name of the existing struct.
+/// * `fresh = $fresh` (optional): A function or closure that builds a new
+///   instance from an existing one, for structs that keep state between
+///   invocations. See [`FeatureAdder::fresh`].
 /// * `$prop_getter`: The function or closure used to calculate the features.
 /// * `$( $column_names:expr ),*`: A comma-separated list of column names as strings.
 #[macro_export]
 macro_rules! global_feature_adder {
+    ($struct_name:ident, fresh = $fresh:expr, $prop_getter:expr, $( $column_names:expr ),* $(,)?) => {
+        global_feature_adder!(@impl [, fresh = $fresh], $struct_name, $prop_getter, $( $column_names ),*);
+    };
     ($struct_name:ident, $prop_getter:expr, $( $column_names:expr ),* $(,)?) => {
+        global_feature_adder!(@impl [], $struct_name, $prop_getter, $( $column_names ),*);
+    };
+    (@impl [$($fresh:tt)*], $struct_name:ident, $prop_getter:expr, $( $column_names:expr ),*) => {
         macro_rules! _global_feature_adder {
             ($count:ident) => {
                 impl<F: TryFrom<f32>> LengthCheckedFeatureAdder<F, $count> for $struct_name<F>
@@ -656,7 +1426,7 @@ macro_rules! global_feature_adder {
                     }
                 }
 
-                impl_feature_adder!($struct_name);
+                impl_feature_adder!($struct_name $($fresh)*);
             };
         }
         paste::paste! {
@@ -758,11 +1528,20 @@ macro_rules! build_player_feature_adder {
 /// # Parameters
 ///
 /// * `$struct_name`: The name of the existing struct.
+/// * `fresh = $fresh` (optional): A function or closure that builds a new
+///   instance from an existing one, for structs that keep state between
+///   invocations. See [`PlayerFeatureAdder::fresh`].
 /// * `$prop_getter`: The function or closure used to calculate the features.
 /// * `$( $column_names:expr ),*`: A comma-separated list of column names as strings.
 #[macro_export]
 macro_rules! player_feature_adder {
+    ($struct_name:ident, fresh = $fresh:expr, $prop_getter:expr, $( $column_names:expr ),* $(,)?) => {
+        player_feature_adder!(@impl [, fresh = $fresh], $struct_name, $prop_getter, $( $column_names ),*);
+    };
     ($struct_name:ident, $prop_getter:expr, $( $column_names:expr ),* $(,)?) => {
+        player_feature_adder!(@impl [], $struct_name, $prop_getter, $( $column_names ),*);
+    };
+    (@impl [$($fresh:tt)*], $struct_name:ident, $prop_getter:expr, $( $column_names:expr ),*) => {
         macro_rules! _player_feature_adder {
             ($count:ident) => {
                 impl<F: TryFrom<f32>> LengthCheckedPlayerFeatureAdder<F, $count> for $struct_name<F>
@@ -785,7 +1564,7 @@ macro_rules! player_feature_adder {
                     }
                 }
 
-                impl_player_feature_adder!($struct_name);
+                impl_player_feature_adder!($struct_name $($fresh)*);
             };
         }
         paste::paste! {
@@ -951,6 +1730,19 @@ where
     convert_all_floats!(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,)
 }
 
+// Emits the distances from the ball to the center of each goal, see
+// ReplayProcessor::get_ball_distance_to_goals. Like BallRigidBody, this fails
+// when the ball has no rigid body.
+build_global_feature_adder!(
+    BallDistanceToGoals,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let (team_zero_distance, team_one_distance) = processor.get_ball_distance_to_goals()?;
+        convert_all_floats!(team_zero_distance, team_one_distance)
+    },
+    "Ball - distance to blue goal",
+    "Ball - distance to orange goal"
+);
+
 build_global_feature_adder!(
     SecondsRemaining,
     |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
@@ -959,6 +1751,53 @@ build_global_feature_adder!(
     "seconds remaining"
 );
 
+// Emits the seconds remaining with the time elapsed since the last integer
+// tick of the clock subtracted, see
+// ReplayProcessor::get_continuous_seconds_remaining.
+build_global_feature_adder!(
+    ContinuousSecondsRemaining,
+    |_, processor: &ReplayProcessor, _frame, _index, current_time: f32| {
+        convert_all_floats!(processor.get_continuous_seconds_remaining(current_time)?)
+    },
+    "continuous seconds remaining"
+);
+
+// Emits 1.0 during the final `CLUTCH_TIME_SECONDS` of regulation and
+// throughout overtime. The game clock does not advance while it is paused
+// (e.g. after a goal or during a kickoff countdown), so paused stretches
+// inside the final seconds are flagged just like live play. In overtime the
+// clock sits at or below zero, but the overtime flag is checked first so that
+// overtime is always flagged regardless of what the clock reports.
+build_global_feature_adder!(
+    ClutchTime,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let is_clutch_time = processor.get_is_overtime()?
+            || processor.get_seconds_remaining()? <= CLUTCH_TIME_SECONDS;
+        convert_all_floats!(if is_clutch_time { 1.0 } else { 0.0 })
+    },
+    "clutch time"
+);
+
+// Emits 1.0 while the game is in overtime, see ReplayProcessor::get_is_overtime.
+build_global_feature_adder!(
+    GameOvertime,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let is_overtime = processor.get_is_overtime()?;
+        convert_all_floats!(if is_overtime { 1.0 } else { 0.0 })
+    },
+    "overtime"
+);
+
+// Emits the raw state code of the game event, see GameState for the meaning
+// of the known codes.
+build_global_feature_adder!(
+    ReplicatedStateName,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        convert_all_floats!(processor.get_game_state_name()? as f32)
+    },
+    "game state"
+);
+
 build_global_feature_adder!(
     CurrentTime,
     |_, _processor, _frame, _index, current_time: f32| { convert_all_floats!(current_time) },
@@ -973,6 +1812,280 @@ build_global_feature_adder!(
     "frame time"
 );
 
+// Columns follow the order of STANDARD_BOOST_PADS, so that they are the same
+// across replays.
+build_global_feature_adder!(
+    BoostPadTimers,
+    |_, processor: &ReplayProcessor, _frame, _index, current_time: f32| {
+        let respawn_times = processor
+            .get_boost_pad_respawn_times(current_time)
+            .into_iter()
+            .map(|v| F::try_from(v).map_err(convert_float_conversion_error))
+            .collect::<SubtrActorResult<Vec<F>>>()?;
+        respawn_times
+            .try_into()
+            .map_err(convert_float_conversion_error)
+    },
+    "boost pad 0 respawn",
+    "boost pad 1 respawn",
+    "boost pad 2 respawn",
+    "boost pad 3 respawn",
+    "boost pad 4 respawn",
+    "boost pad 5 respawn",
+    "boost pad 6 respawn",
+    "boost pad 7 respawn",
+    "boost pad 8 respawn",
+    "boost pad 9 respawn",
+    "boost pad 10 respawn",
+    "boost pad 11 respawn",
+    "boost pad 12 respawn",
+    "boost pad 13 respawn",
+    "boost pad 14 respawn",
+    "boost pad 15 respawn",
+    "boost pad 16 respawn",
+    "boost pad 17 respawn",
+    "boost pad 18 respawn",
+    "boost pad 19 respawn",
+    "boost pad 20 respawn",
+    "boost pad 21 respawn",
+    "boost pad 22 respawn",
+    "boost pad 23 respawn",
+    "boost pad 24 respawn",
+    "boost pad 25 respawn",
+    "boost pad 26 respawn",
+    "boost pad 27 respawn",
+    "boost pad 28 respawn",
+    "boost pad 29 respawn",
+    "boost pad 30 respawn",
+    "boost pad 31 respawn",
+    "boost pad 32 respawn",
+    "boost pad 33 respawn",
+);
+
+// Emits 1.0 for each pad that is available to be picked up and 0.0 for each
+// pad that is waiting to respawn. Columns follow the order of
+// STANDARD_BOOST_PADS, like those of BoostPadTimers.
+build_global_feature_adder!(
+    BoostPadStates,
+    |_, processor: &ReplayProcessor, _frame, _index, current_time: f32| {
+        let states = processor
+            .get_boost_pad_states(current_time)?
+            .into_iter()
+            .map(|state| {
+                F::try_from(if state.is_active { 1.0 } else { 0.0 })
+                    .map_err(convert_float_conversion_error)
+            })
+            .collect::<SubtrActorResult<Vec<F>>>()?;
+        states.try_into().map_err(convert_float_conversion_error)
+    },
+    "boost pad 0 active",
+    "boost pad 1 active",
+    "boost pad 2 active",
+    "boost pad 3 active",
+    "boost pad 4 active",
+    "boost pad 5 active",
+    "boost pad 6 active",
+    "boost pad 7 active",
+    "boost pad 8 active",
+    "boost pad 9 active",
+    "boost pad 10 active",
+    "boost pad 11 active",
+    "boost pad 12 active",
+    "boost pad 13 active",
+    "boost pad 14 active",
+    "boost pad 15 active",
+    "boost pad 16 active",
+    "boost pad 17 active",
+    "boost pad 18 active",
+    "boost pad 19 active",
+    "boost pad 20 active",
+    "boost pad 21 active",
+    "boost pad 22 active",
+    "boost pad 23 active",
+    "boost pad 24 active",
+    "boost pad 25 active",
+    "boost pad 26 active",
+    "boost pad 27 active",
+    "boost pad 28 active",
+    "boost pad 29 active",
+    "boost pad 30 active",
+    "boost pad 31 active",
+    "boost pad 32 active",
+    "boost pad 33 active",
+);
+
+/// The time to the goal line reported by [`BallShotGeometry`] when the ball is
+/// not moving toward that goal line.
+const SHOT_NEVER_REACHES_GOAL_LINE_TIME: f32 = 1000.0;
+
+/// Returns whether the ball's trajectory crosses the goal line at `goal_y`
+/// within the goal mouth (as `1.0` or `0.0`), and how many seconds it takes to
+/// get there. See [`extrapolate_to_goal_line`].
+fn get_shot_geometry(rigid_body: &boxcars::RigidBody, goal_y: f32) -> (f32, f32) {
+    let velocity = rigid_body
+        .linear_velocity
+        .unwrap_or_else(or_zero_boxcars_3f);
+    match extrapolate_to_goal_line(&rigid_body.location, &velocity, goal_y) {
+        Some((time_to_goal_line, on_target)) => {
+            (if on_target { 1.0 } else { 0.0 }, time_to_goal_line)
+        }
+        None => (0.0, SHOT_NEVER_REACHES_GOAL_LINE_TIME),
+    }
+}
+
+build_global_feature_adder!(
+    BallShotGeometry,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let rigid_body = processor.get_ball_rigid_body()?;
+        let (on_team_zero_goal, time_to_team_zero_goal) =
+            get_shot_geometry(rigid_body, -GOAL_LINE_Y);
+        let (on_team_one_goal, time_to_team_one_goal) = get_shot_geometry(rigid_body, GOAL_LINE_Y);
+        convert_all_floats!(
+            on_team_zero_goal,
+            time_to_team_zero_goal,
+            on_team_one_goal,
+            time_to_team_one_goal
+        )
+    },
+    "Ball - on target team zero goal",
+    "Ball - time to team zero goal line",
+    "Ball - on target team one goal",
+    "Ball - time to team one goal line",
+);
+
+// The field is split into thirds along its length. From team zero's
+// perspective, -1.0 is the defensive third (the one containing team zero's
+// goal, at negative y), 0.0 is the middle third, and 1.0 is the offensive
+// third.
+build_global_feature_adder!(
+    BallFieldThird,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let ball_y = processor.get_ball_rigid_body()?.location.y;
+        let third_length = GOAL_LINE_Y / 3.0;
+        let third = if ball_y < -third_length {
+            -1.0
+        } else if ball_y > third_length {
+            1.0
+        } else {
+            0.0
+        };
+        convert_all_floats!(third)
+    },
+    "Ball - field third"
+);
+
+// Emits 1.0 when the ball's rigid body reports that it is sleeping, meaning
+// that its position is not being updated.
+build_global_feature_adder!(
+    BallIsSleeping,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let sleeping = processor.get_ball_rigid_body()?.sleeping;
+        convert_all_floats!(if sleeping { 1.0 } else { 0.0 })
+    },
+    "Ball - is sleeping"
+);
+
+// Emits 1.0 while the ball is in play and 0.0 otherwise, e.g. during kickoff
+// countdowns and goal replays. See ReplayProcessor::is_ball_in_play.
+build_global_feature_adder!(
+    BallInPlay,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let in_play = processor.is_ball_in_play();
+        convert_all_floats!(if in_play { 1.0 } else { 0.0 })
+    },
+    "Ball - in play"
+);
+
+// Emits 1.0 when the ball is above and within the rim of either hoop, see
+// util::is_in_hoop_zone. Replays of any mode other than Hoops always emit 0.0.
+build_global_feature_adder!(
+    BallInHoopZone,
+    |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
+        let in_hoop_zone = matches!(processor.get_game_mode(), Ok(GameMode::Hoops))
+            && is_in_hoop_zone(&processor.get_ball_rigid_body()?.location);
+        convert_all_floats!(if in_hoop_zone { 1.0 } else { 0.0 })
+    },
+    "Ball - in hoop zone"
+);
+
+/// Returns the acceleration implied by a change in velocity from `previous`,
+/// a time and velocity, to `velocity` at `current_time`. Zero is returned when
+/// there is no previous velocity or no time has passed since it was recorded.
+fn get_acceleration(
+    previous: Option<(f32, glam::Vec3)>,
+    current_time: f32,
+    velocity: glam::Vec3,
+) -> glam::Vec3 {
+    match previous {
+        Some((previous_time, previous_velocity)) if current_time > previous_time => {
+            (velocity - previous_velocity) / (current_time - previous_time)
+        }
+        _ => glam::Vec3::ZERO,
+    }
+}
+
+/// Emits the acceleration of the ball, in unreal units per second squared,
+/// computed as the change in its replicated linear velocity since the
+/// previous time at which this adder was invoked, divided by the time elapsed
+/// since then. Because the elapsed time is measured between invocations, the
+/// result follows the cadence at which the collector samples the replay (e.g.
+/// when using a [`FrameRateDecorator`]). Zeros are emitted on the first
+/// invocation and whenever the ball has no velocity.
+///
+/// The previous velocity is remembered by the adder itself, and is discarded
+/// when the adder is invoked with a time earlier than the one it last saw, as
+/// happens when it is reused for another replay. An instance should therefore
+/// not be shared by collectors that run at the same time. Collectors that look
+/// their feature adders up by name, as [`NDArrayCollector::from_strings`]
+/// does, and those made by [`NDArrayCollector::clone_empty`] each get a new
+/// instance.
+#[derive(derive_new::new)]
+pub struct BallAcceleration<F> {
+    #[new(default)]
+    previous_velocity: std::sync::Mutex<Option<(f32, glam::Vec3)>>,
+    _zero: std::marker::PhantomData<F>,
+}
+
+impl<F> BallAcceleration<F> {
+    pub fn arc_new() -> Arc<Self> {
+        Arc::new(Self::new())
+    }
+
+    fn get_acceleration(&self, processor: &ReplayProcessor, current_time: f32) -> glam::Vec3 {
+        let mut previous_velocity = self
+            .previous_velocity
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let previous =
+            previous_velocity.filter(|(previous_time, _)| *previous_time <= current_time);
+        let velocity = processor
+            .get_ball_rigid_body()
+            .ok()
+            .and_then(|rigid_body| rigid_body.linear_velocity)
+            .map(|velocity| vec_to_glam(&velocity));
+        *previous_velocity = velocity.map(|velocity| (current_time, velocity));
+        velocity
+            .map(|velocity| get_acceleration(previous, current_time, velocity))
+            .unwrap_or(glam::Vec3::ZERO)
+    }
+}
+
+global_feature_adder!(
+    BallAcceleration,
+    fresh = |_: &Self| Self::new(),
+    |s: &BallAcceleration<F>,
+     processor: &ReplayProcessor,
+     _frame: &boxcars::Frame,
+     _index,
+     current_time: f32| {
+        let acceleration = s.get_acceleration(processor, current_time);
+        convert_all_floats!(acceleration.x, acceleration.y, acceleration.z)
+    },
+    "Ball - acceleration x",
+    "Ball - acceleration y",
+    "Ball - acceleration z",
+);
+
 build_global_feature_adder!(
     BallRigidBody,
     |_, processor: &ReplayProcessor, _frame, _index, _current_time| {
@@ -1057,6 +2170,49 @@ global_feature_adder!(
     "Ball - rotation w",
 );
 
+/// Emits the same features as [`BallRigidBody`], but averaged over the
+/// `window_duration` seconds that precede the current time, see
+/// [`ReplayProcessor::get_averaged_ball_rigid_body`]. This is intended to be
+/// used with an [`AveragingDecorator`] with the same window duration, so that
+/// each row summarizes all the motion since the previous row.
+#[derive(derive_new::new)]
+pub struct AveragedBallRigidBody<F> {
+    window_duration: f32,
+    _zero: std::marker::PhantomData<F>,
+}
+
+impl<F> AveragedBallRigidBody<F> {
+    pub fn arc_new(window_duration: f32) -> Arc<Self> {
+        Arc::new(Self::new(window_duration))
+    }
+}
+
+global_feature_adder!(
+    AveragedBallRigidBody,
+    |s: &AveragedBallRigidBody<F>,
+     processor: &ReplayProcessor,
+     _frame: &boxcars::Frame,
+     _index,
+     current_time: f32| {
+        get_rigid_body_properties(
+            &processor
+                .get_averaged_ball_rigid_body(current_time - s.window_duration, current_time)?,
+        )
+    },
+    "Ball - averaged position x",
+    "Ball - averaged position y",
+    "Ball - averaged position z",
+    "Ball - averaged rotation x",
+    "Ball - averaged rotation y",
+    "Ball - averaged rotation z",
+    "Ball - averaged linear velocity x",
+    "Ball - averaged linear velocity y",
+    "Ball - averaged linear velocity z",
+    "Ball - averaged angular velocity x",
+    "Ball - averaged angular velocity y",
+    "Ball - averaged angular velocity z",
+);
+
 build_player_feature_adder!(
     PlayerRigidBody,
     |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
@@ -1156,6 +2312,206 @@ player_feature_adder!(
     "i rotation w"
 );
 
+/// Emits the same features as [`PlayerRigidBody`], but averaged over the
+/// `window_duration` seconds that precede the current time, see
+/// [`ReplayProcessor::get_averaged_player_rigid_body`] and
+/// [`AveragedBallRigidBody`].
+#[derive(derive_new::new)]
+pub struct AveragedPlayerRigidBody<F> {
+    window_duration: f32,
+    _zero: std::marker::PhantomData<F>,
+}
+
+impl<F> AveragedPlayerRigidBody<F> {
+    pub fn arc_new(window_duration: f32) -> Arc<Self> {
+        Arc::new(Self::new(window_duration))
+    }
+}
+
+player_feature_adder!(
+    AveragedPlayerRigidBody,
+    |s: &AveragedPlayerRigidBody<F>,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame: &boxcars::Frame,
+     _index,
+     current_time: f32| {
+        processor
+            .get_averaged_player_rigid_body(
+                player_id,
+                current_time - s.window_duration,
+                current_time,
+            )
+            .map(|v| get_rigid_body_properties(&v))
+            .unwrap_or_else(|_| default_rb_state())
+    },
+    "averaged position x",
+    "averaged position y",
+    "averaged position z",
+    "averaged rotation x",
+    "averaged rotation y",
+    "averaged rotation z",
+    "averaged linear velocity x",
+    "averaged linear velocity y",
+    "averaged linear velocity z",
+    "averaged angular velocity x",
+    "averaged angular velocity y",
+    "averaged angular velocity z",
+);
+
+// Emits the orientation of the player's car, as Euler angles, from the
+// perspective of the player's team. See to_team_relative_rotation.
+build_player_feature_adder!(
+    PlayerTeamRelativeRotation,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let (Ok(rb), Ok(is_team_0)) = (
+            processor.get_player_rigid_body(player_id),
+            processor.get_player_is_team_0(player_id),
+        ) else {
+            return convert_all_floats!(0.0, 0.0, 0.0);
+        };
+        let rotation = processor.to_team_relative_rotation(&rb.rotation, is_team_0);
+        let (rx, ry, rz) = quat_to_glam(&rotation).to_euler(glam::EulerRot::XYZ);
+        convert_all_floats!(rx, ry, rz)
+    },
+    "team relative rotation x",
+    "team relative rotation y",
+    "team relative rotation z",
+);
+
+/// Emits the angle, in radians, through which the player's car has rotated
+/// since the previous frame at which this adder was invoked for that player,
+/// computed from the replicated rotations rather than the replicated angular
+/// velocity. `0.0` is emitted on the first frame for each player, and
+/// whenever the player has no car.
+///
+/// The previous rotation of each player is remembered by the adder itself.
+/// That memory is discarded when the adder is invoked with a frame index
+/// earlier than the one it last saw, as happens when it is reused for another
+/// replay.
+#[derive(derive_new::new)]
+pub struct PlayerRotationDelta<F> {
+    #[new(default)]
+    previous_rotations:
+        std::sync::Mutex<std::collections::HashMap<PlayerId, (usize, boxcars::Quaternion)>>,
+    _zero: std::marker::PhantomData<F>,
+}
+
+impl<F> PlayerRotationDelta<F> {
+    pub fn arc_new() -> Arc<Self> {
+        Arc::new(Self::new())
+    }
+
+    fn get_delta(
+        &self,
+        player_id: &PlayerId,
+        processor: &ReplayProcessor,
+        frame_count: usize,
+    ) -> f32 {
+        let mut previous_rotations = self
+            .previous_rotations
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if previous_rotations
+            .values()
+            .any(|(previous_frame, _)| *previous_frame > frame_count)
+        {
+            previous_rotations.clear();
+        }
+        let rotation = match processor.get_player_rigid_body(player_id) {
+            Ok(rigid_body) => rigid_body.rotation,
+            Err(_) => {
+                previous_rotations.remove(player_id);
+                return 0.0;
+            }
+        };
+        previous_rotations
+            .insert(player_id.clone(), (frame_count, rotation))
+            .map(|(_, previous)| quaternion_angle_between(&previous, &rotation))
+            .unwrap_or(0.0)
+    }
+}
+
+player_feature_adder!(
+    PlayerRotationDelta,
+    fresh = |_: &Self| Self::new(),
+    |s: &PlayerRotationDelta<F>,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame: &boxcars::Frame,
+     frame_count: usize,
+     _current_time: f32| {
+        convert_all_floats!(s.get_delta(player_id, processor, frame_count))
+    },
+    "rotation delta"
+);
+
+/// Emits the acceleration of the player's car, in unreal units per second
+/// squared. See [`BallAcceleration`], which this mirrors for each player.
+#[derive(derive_new::new)]
+pub struct PlayerAcceleration<F> {
+    #[new(default)]
+    previous_velocities: std::sync::Mutex<std::collections::HashMap<PlayerId, (f32, glam::Vec3)>>,
+    _zero: std::marker::PhantomData<F>,
+}
+
+impl<F> PlayerAcceleration<F> {
+    pub fn arc_new() -> Arc<Self> {
+        Arc::new(Self::new())
+    }
+
+    fn get_acceleration(
+        &self,
+        player_id: &PlayerId,
+        processor: &ReplayProcessor,
+        current_time: f32,
+    ) -> glam::Vec3 {
+        let mut previous_velocities = self
+            .previous_velocities
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if previous_velocities
+            .values()
+            .any(|(previous_time, _)| *previous_time > current_time)
+        {
+            previous_velocities.clear();
+        }
+        let velocity = processor
+            .get_player_rigid_body(player_id)
+            .ok()
+            .and_then(|rigid_body| rigid_body.linear_velocity)
+            .map(|velocity| vec_to_glam(&velocity));
+        match velocity {
+            Some(velocity) => {
+                let previous =
+                    previous_velocities.insert(player_id.clone(), (current_time, velocity));
+                get_acceleration(previous, current_time, velocity)
+            }
+            None => {
+                previous_velocities.remove(player_id);
+                glam::Vec3::ZERO
+            }
+        }
+    }
+}
+
+player_feature_adder!(
+    PlayerAcceleration,
+    fresh = |_: &Self| Self::new(),
+    |s: &PlayerAcceleration<F>,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame: &boxcars::Frame,
+     _index,
+     current_time: f32| {
+        let acceleration = s.get_acceleration(player_id, processor, current_time);
+        convert_all_floats!(acceleration.x, acceleration.y, acceleration.z)
+    },
+    "acceleration x",
+    "acceleration y",
+    "acceleration z",
+);
+
 build_player_feature_adder!(
     PlayerBoost,
     |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
@@ -1164,6 +2520,150 @@ build_player_feature_adder!(
     "boost level"
 );
 
+// Emits the most recently replicated ping of the player, in milliseconds, with
+// a resolution of PING_BYTE_MILLISECONDS, see ReplayProcessor::get_player_ping.
+build_player_feature_adder!(
+    PlayerPing,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        convert_all_floats!(processor.get_player_ping(player_id)? as f32)
+    },
+    "ping"
+);
+
+// Emits the throttle and steering inputs of the player's car, normalized to
+// the range [-1.0, 1.0]. Inputs that have not been replicated emit 0.0.
+build_player_feature_adder!(
+    PlayerThrottleSteer,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let normalized =
+            |value: SubtrActorResult<u8>| value.map(normalize_replicated_input).unwrap_or(0.0);
+        convert_all_floats!(
+            normalized(processor.get_player_throttle(player_id)),
+            normalized(processor.get_player_steer(player_id)),
+        )
+    },
+    "throttle",
+    "steer"
+);
+
+// Emits 1.0 while the handbrake (powerslide) of the player's car is engaged.
+build_player_feature_adder!(
+    PlayerHandbrake,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let handbrake = processor.get_player_handbrake(player_id).unwrap_or(false);
+        convert_all_floats!(if handbrake { 1.0 } else { 0.0 })
+    },
+    "handbrake"
+);
+
+// Emits 1.0 while the player's car is supersonic, see
+// ReplayProcessor::get_player_is_supersonic, and 0.0 otherwise, including
+// while the car has no rigid body.
+build_player_feature_adder!(
+    PlayerSupersonic,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let supersonic = processor.get_player_is_supersonic(player_id);
+        convert_all_floats!(if supersonic { 1.0 } else { 0.0 })
+    },
+    "supersonic"
+);
+
+// Emits 1.0 while the player's car appears to be on the ground, see
+// ReplayProcessor::get_player_on_ground, and 0.0 otherwise, including while
+// the car has no rigid body.
+build_player_feature_adder!(
+    PlayerOnGround,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let on_ground = processor.get_player_on_ground(player_id).unwrap_or(false);
+        convert_all_floats!(if on_ground { 1.0 } else { 0.0 })
+    },
+    "on ground"
+);
+
+// Emits the distance between the player's car and the ball, see
+// ReplayProcessor::get_player_ball_distance. UNKNOWN_BALL_DISTANCE_VALUE is
+// emitted instead while either rigid body is missing or sleeping.
+build_player_feature_adder!(
+    PlayerBallDistance,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let is_awake = |rigid_body: SubtrActorResult<&boxcars::RigidBody>| {
+            rigid_body.map(|rb| !rb.sleeping).unwrap_or(false)
+        };
+        let distance = if is_awake(processor.get_player_rigid_body(player_id))
+            && is_awake(processor.get_ball_rigid_body())
+        {
+            processor
+                .get_player_ball_distance(player_id)
+                .unwrap_or(UNKNOWN_BALL_DISTANCE_VALUE)
+        } else {
+            UNKNOWN_BALL_DISTANCE_VALUE
+        };
+        convert_all_floats!(distance)
+    },
+    "ball distance"
+);
+
+// Emits the third of the field the player's car is in, relative to their team,
+// as 0.0 (defensive), 1.0 (middle) or 2.0 (offensive), see
+// ReplayProcessor::get_player_field_third. UNKNOWN_FIELD_THIRD_VALUE is emitted
+// instead while the car has no rigid body.
+build_player_feature_adder!(
+    PlayerFieldThird,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let field_third = processor
+            .get_player_field_third(player_id)
+            .map(|third| third as f32)
+            .unwrap_or(UNKNOWN_FIELD_THIRD_VALUE);
+        convert_all_floats!(field_third)
+    },
+    "field third"
+);
+
+// Emits the angular speed of the player's car, see
+// ReplayProcessor::get_player_angular_speed, or 0.0 while the car has no rigid
+// body.
+build_player_feature_adder!(
+    PlayerAngularSpeed,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        convert_all_floats!(processor.get_player_angular_speed(player_id).unwrap_or(0.0))
+    },
+    "angular speed"
+);
+
+// Like PlayerBoost, but linearly interpolates the boost level between network
+// frames, see ReplayProcessor::get_interpolated_player_boost_level. This is
+// meant for use with a FrameRateDecorator, where `current_time` usually falls
+// between two frames.
+//
+// Only continuous quantities have interpolating variants: this one and the
+// Interpolated*RigidBodyNoVelocities adders. Boolean and categorical features,
+// such as those of PlayerJump, PlayerAnyJump or PlayerDemolishedBy, are always
+// held at the value of the most recent frame, since a value between two states
+// would not be meaningful.
+build_player_feature_adder!(
+    InterpolatedPlayerBoost,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, current_time: f32| {
+        convert_all_floats!(processor
+            .get_interpolated_player_boost_level(player_id, current_time)
+            .unwrap_or(0.0))
+    },
+    "i boost level"
+);
+
+// Emits 1.0 when the car's rigid body reports that it is sleeping, meaning
+// that its position is not being updated. Missing cars emit 0.0.
+build_player_feature_adder!(
+    PlayerIsSleeping,
+    |_, player_id: &PlayerId, processor: &ReplayProcessor, _frame, _index, _current_time: f32| {
+        let sleeping = processor
+            .get_player_rigid_body(player_id)
+            .map(|rb| rb.sleeping)
+            .unwrap_or(false);
+        convert_all_floats!(if sleeping { 1.0 } else { 0.0 })
+    },
+    "is sleeping"
+);
+
 fn u8_get_f32(v: u8) -> SubtrActorResult<f32> {
     v.try_into().map_err(convert_float_conversion_error)
 }
@@ -1217,15 +2717,87 @@ build_player_feature_adder!(
     "any_jump_active"
 );
 
+/// Emits 1.0 for the player who last touched the ball, as long as the touch
+/// happened within the last `max_frames_since_touch` frames, and 0.0 for every
+/// other player. The registered adder uses [`DEFAULT_LAST_TOUCH_MAX_FRAMES`].
+/// See [`ReplayProcessor::get_last_ball_touch`].
+#[derive(derive_new::new)]
+pub struct PlayerLastTouch<F> {
+    max_frames_since_touch: usize,
+    _zero: std::marker::PhantomData<F>,
+}
+
+impl<F> PlayerLastTouch<F> {
+    pub fn arc_new(max_frames_since_touch: usize) -> Arc<Self> {
+        Arc::new(Self::new(max_frames_since_touch))
+    }
+}
+
+player_feature_adder!(
+    PlayerLastTouch,
+    |s: &PlayerLastTouch<F>,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame: &boxcars::Frame,
+     frame_number: usize,
+     _current_time: f32| {
+        let is_last_toucher = processor
+            .get_last_ball_touch()?
+            .map(|(toucher, touch_frame)| {
+                &toucher == player_id
+                    && frame_number.saturating_sub(touch_frame) <= s.max_frames_since_touch
+            })
+            .unwrap_or(false);
+        convert_all_floats!(if is_last_toucher { 1.0 } else { 0.0 })
+    },
+    "last touch"
+);
+
 const DEMOLISH_APPEARANCE_FRAME_COUNT: usize = 30;
 
-build_player_feature_adder!(
+/// Emits which player, if any, demolished the player within the last
+/// `DEMOLISH_APPEARANCE_FRAME_COUNT` (30) frames, encoded as follows:
+///
+/// * [`NOT_DEMOLISHED_VALUE`] (`-1.0`) if the player was not recently
+///   demolished.
+/// * The index of the attacker in
+///   [`ReplayProcessor::iter_player_ids_in_order`] if the attacker is one of
+///   the replay's players.
+/// * `unknown_attacker_value` if the attacker is not one of the replay's
+///   players. The registered adder uses [`DEFAULT_UNKNOWN_DEMOLISHER_VALUE`]
+///   (`-2.0`). Any custom value should be negative and distinct from
+///   [`NOT_DEMOLISHED_VALUE`] so that it can't be mistaken for either of the
+///   other cases.
+#[derive(derive_new::new)]
+pub struct PlayerDemolishedBy<F> {
+    unknown_attacker_value: f32,
+    _zero: std::marker::PhantomData<F>,
+}
+
+impl<F> PlayerDemolishedBy<F> {
+    pub fn arc_new(unknown_attacker_value: f32) -> Arc<Self> {
+        Arc::new(Self::new(unknown_attacker_value))
+    }
+
+    /// Encodes a demolition as described in [`PlayerDemolishedBy`]. `None`
+    /// means the player was not recently demolished, and `Some(None)` means
+    /// that the attacker is not among the replay's players.
+    pub fn encode_demolisher(&self, demolisher_index: Option<Option<usize>>) -> f32 {
+        match demolisher_index {
+            None => NOT_DEMOLISHED_VALUE,
+            Some(Some(index)) => index as f32,
+            Some(None) => self.unknown_attacker_value,
+        }
+    }
+}
+
+player_feature_adder!(
     PlayerDemolishedBy,
-    |_,
+    |s: &PlayerDemolishedBy<F>,
      player_id: &PlayerId,
      processor: &ReplayProcessor,
-     _frame,
-     frame_number,
+     _frame: &boxcars::Frame,
+     frame_number: usize,
      _current_time: f32| {
         let demolisher_index = processor
             .demolishes
@@ -1238,62 +2810,365 @@ build_player_feature_adder!(
                 processor
                     .iter_player_ids_in_order()
                     .position(|player_id| player_id == &demolish_info.attacker)
-                    .unwrap_or_else(|| processor.iter_player_ids_in_order().count())
-            })
-            .and_then(|v| i32::try_from(v).ok())
-            .unwrap_or(-1);
-        convert_all_floats!(demolisher_index as f32)
+            });
+        convert_all_floats!(s.encode_demolisher(demolisher_index))
     },
     "player demolished by"
 );
 
-lazy_static! {
-    static ref NAME_TO_GLOBAL_FEATURE_ADDER: std::collections::HashMap<&'static str, Arc<dyn FeatureAdder<f32> + Send + Sync + 'static>> = {
-        let mut m: std::collections::HashMap<
-            &'static str,
-            Arc<dyn FeatureAdder<f32> + Send + Sync + 'static>,
-        > = std::collections::HashMap::new();
-        macro_rules! insert_adder {
-            ($adder_name:ident, $( $arguments:expr ),*) => {
-                m.insert(stringify!($adder_name), $adder_name::<f32>::arc_new($ ( $arguments ),*));
-            };
-            ($adder_name:ident) => {
-                insert_adder!($adder_name,)
+// Emits 1.0 while the player has a car on the field and 0.0 otherwise, e.g.
+// while they are demolished, see ReplayProcessor::get_player_is_active.
+build_player_feature_adder!(
+    PlayerIsActive,
+    |_,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame,
+     _frame_number,
+     _current_time: f32| {
+        convert_all_floats!(if processor.get_player_is_active(player_id) {
+            1.0
+        } else {
+            0.0
+        })
+    },
+    "is active"
+);
+
+// Emits the running count of demolitions the player has inflicted, see
+// ReplayProcessor::get_player_demolishes_inflicted.
+build_player_feature_adder!(
+    PlayerDemosInflicted,
+    |_,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame,
+     frame_number,
+     _current_time: f32| {
+        convert_all_floats!(
+            processor.get_player_demolishes_inflicted(player_id, frame_number) as f32
+        )
+    },
+    "demos inflicted"
+);
+
+// Emits the running count of times the player has been demolished, see
+// ReplayProcessor::get_player_demolishes_taken.
+build_player_feature_adder!(
+    PlayerDemosTaken,
+    |_,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame,
+     frame_number,
+     _current_time: f32| {
+        convert_all_floats!(processor.get_player_demolishes_taken(player_id, frame_number) as f32)
+    },
+    "demos taken"
+);
+
+build_player_feature_adder!(
+    PlayerDemolishImpactSpeed,
+    |_,
+     player_id: &PlayerId,
+     processor: &ReplayProcessor,
+     _frame,
+     frame_number,
+     _current_time: f32| {
+        let impact_speed = processor
+            .demolishes
+            .iter()
+            .find(|demolish_info| {
+                &demolish_info.victim == player_id
+                    && frame_number - demolish_info.frame < DEMOLISH_APPEARANCE_FRAME_COUNT
+            })
+            .map(|demolish_info| {
+                (vec_to_glam(&demolish_info.attacker_velocity)
+                    - vec_to_glam(&demolish_info.victim_velocity))
+                .length()
+            })
+            .unwrap_or(0.0);
+        convert_all_floats!(impact_speed)
+    },
+    "player demolish impact speed"
+);
+
+/// The actors associated with a player from which a
+/// [`GenericNumericPlayerAdder`] can read attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerActorType {
+    /// The player's replication info actor.
+    Player,
+    Car,
+    Boost,
+    Jump,
+    DoubleJump,
+    Dodge,
+}
+
+impl std::str::FromStr for PlayerActorType {
+    type Err = SubtrActorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "player" => Ok(PlayerActorType::Player),
+            "car" => Ok(PlayerActorType::Car),
+            "boost" => Ok(PlayerActorType::Boost),
+            "jump" => Ok(PlayerActorType::Jump),
+            "double_jump" => Ok(PlayerActorType::DoubleJump),
+            "dodge" => Ok(PlayerActorType::Dodge),
+            _ => SubtrActorError::new_result(SubtrActorErrorVariant::UnknownPlayerActorType(
+                s.to_string(),
+            )),
+        }
+    }
+}
+
+impl PlayerActorType {
+    fn get_actor_id(
+        &self,
+        processor: &ReplayProcessor,
+        player_id: &PlayerId,
+    ) -> SubtrActorResult<boxcars::ActorId> {
+        match self {
+            PlayerActorType::Player => processor.get_player_actor_id(player_id),
+            PlayerActorType::Car => processor.get_car_actor_id(player_id),
+            PlayerActorType::Boost => processor.get_boost_actor_id(player_id),
+            PlayerActorType::Jump => processor.get_jump_actor_id(player_id),
+            PlayerActorType::DoubleJump => processor.get_double_jump_actor_id(player_id),
+            PlayerActorType::Dodge => processor.get_dodge_actor_id(player_id),
+        }
+    }
+}
+
+/// Returns a `'static` copy of `header`, which column headers need to be
+/// returned from [`PlayerFeatureAdder::get_column_headers`]. Each distinct
+/// header is allocated only once, however many adders use it.
+fn intern_column_header(header: &str) -> &'static str {
+    // The unwrap here is fine because the lock is only held while the set is
+    // accessed, which doesn't panic.
+    let mut headers = INTERNED_COLUMN_HEADERS.lock().unwrap();
+    match headers.get(header) {
+        Some(interned) => interned,
+        None => {
+            let interned: &'static str = Box::leak(header.to_string().into_boxed_str());
+            headers.insert(interned);
+            interned
+        }
+    }
+}
+
+/// A [`PlayerFeatureAdder`] that emits a single numeric attribute of one of
+/// the actors associated with each player, where both the actor and the
+/// attribute are chosen at runtime. This makes it possible to extract
+/// attributes that have no dedicated feature adder, e.g. from language
+/// bindings, without writing new Rust code.
+///
+/// Byte, integer, float, boolean and enum attributes are supported. When the
+/// player's actor or the attribute's value is missing, `0.0` is emitted. An
+/// attribute key that is not the name of any object in the replay is reported
+/// by [`PlayerFeatureAdder::check_replay`] before any features are added, and
+/// an attribute that is not numeric results in an error when it is read.
+pub struct GenericNumericPlayerAdder {
+    actor_type: PlayerActorType,
+    attribute_key: String,
+    column_headers: [&'static str; 1],
+}
+
+impl GenericNumericPlayerAdder {
+    /// Creates a new [`GenericNumericPlayerAdder`].
+    ///
+    /// # Arguments
+    ///
+    /// * `actor_type` - One of `"player"`, `"car"`, `"boost"`, `"jump"`,
+    ///   `"double_jump"` or `"dodge"`. See [`PlayerActorType`].
+    /// * `attribute_key` - The object name of the attribute, e.g.
+    ///   `"TAGame.Vehicle_TA:ReplicatedThrottle"`.
+    /// * `header` - The column header for the emitted feature.
+    pub fn new(actor_type: &str, attribute_key: &str, header: &str) -> SubtrActorResult<Self> {
+        Ok(Self {
+            actor_type: actor_type.parse()?,
+            attribute_key: attribute_key.to_string(),
+            column_headers: [intern_column_header(header)],
+        })
+    }
+
+    pub fn arc_new(
+        actor_type: &str,
+        attribute_key: &str,
+        header: &str,
+    ) -> SubtrActorResult<Arc<dyn PlayerFeatureAdder<f32> + Send + Sync + 'static>> {
+        Ok(Arc::new(Self::new(actor_type, attribute_key, header)?))
+    }
+
+    fn get_value(
+        &self,
+        player_id: &PlayerId,
+        processor: &ReplayProcessor,
+    ) -> SubtrActorResult<f32> {
+        let actor_id = match self.actor_type.get_actor_id(processor, player_id) {
+            Ok(actor_id) => actor_id,
+            Err(_) => return Ok(0.0),
+        };
+        let attribute = match processor.read_actor_attribute(&actor_id, &self.attribute_key) {
+            Ok(attribute) => attribute,
+            Err(e) => match e.variant {
+                SubtrActorErrorVariant::UnknownObjectName { .. } => return Err(e),
+                _ => return Ok(0.0),
+            },
+        };
+        match attribute {
+            boxcars::Attribute::Byte(v) => Ok(v as f32),
+            boxcars::Attribute::Int(v) => Ok(v as f32),
+            boxcars::Attribute::Int64(v) => Ok(v as f32),
+            boxcars::Attribute::QWord(v) => Ok(v as f32),
+            boxcars::Attribute::Float(v) => Ok(v),
+            boxcars::Attribute::Enum(v) => Ok(v as f32),
+            boxcars::Attribute::Boolean(v) => Ok(if v { 1.0 } else { 0.0 }),
+            attribute => {
+                SubtrActorError::new_result(SubtrActorErrorVariant::UnexpectedAttributeType {
+                    expected_type: "numeric".to_string(),
+                    actual_type: attribute_to_tag(&attribute).to_string(),
+                })
             }
         }
-        insert_adder!(BallRigidBody);
-        insert_adder!(BallRigidBodyNoVelocities);
-        insert_adder!(VelocityAddedBallRigidBodyNoVelocities);
-        insert_adder!(InterpolatedBallRigidBodyNoVelocities, 0.0);
-        insert_adder!(SecondsRemaining);
-        insert_adder!(CurrentTime);
-        insert_adder!(FrameTime);
-        m
-    };
-    static ref NAME_TO_PLAYER_FEATURE_ADDER: std::collections::HashMap<
-        &'static str,
-        Arc<dyn PlayerFeatureAdder<f32> + Send + Sync + 'static>,
-    > = {
-        let mut m: std::collections::HashMap<
-            &'static str,
-            Arc<dyn PlayerFeatureAdder<f32> + Send + Sync + 'static>,
-        > = std::collections::HashMap::new();
-        macro_rules! insert_adder {
-            ($adder_name:ident, $( $arguments:expr ),*) => {
-                m.insert(stringify!($adder_name), $adder_name::<f32>::arc_new($ ( $arguments ),*));
-            };
-            ($adder_name:ident) => {
-                insert_adder!($adder_name,)
-            };
+    }
+}
+
+impl PlayerFeatureAdder<f32> for GenericNumericPlayerAdder {
+    fn name(&self) -> &str {
+        "GenericNumericPlayerAdder"
+    }
+
+    fn get_column_headers(&self) -> &[&str] {
+        &self.column_headers
+    }
+
+    fn check_replay(&self, replay: &boxcars::Replay) -> SubtrActorResult<()> {
+        if replay.objects.contains(&self.attribute_key) {
+            Ok(())
+        } else {
+            SubtrActorError::new_result(SubtrActorErrorVariant::UnknownObjectName {
+                name: self.attribute_key.clone(),
+            })
         }
-        insert_adder!(PlayerRigidBody);
-        insert_adder!(PlayerRigidBodyNoVelocities);
-        insert_adder!(VelocityAddedPlayerRigidBodyNoVelocities);
-        insert_adder!(InterpolatedPlayerRigidBodyNoVelocities, 0.003);
-        insert_adder!(PlayerBoost);
-        insert_adder!(PlayerJump);
-        insert_adder!(PlayerAnyJump);
-        insert_adder!(PlayerDemolishedBy);
-        m
-    };
+    }
+
+    fn add_features(
+        &self,
+        player_id: &PlayerId,
+        processor: &ReplayProcessor,
+        _frame: &boxcars::Frame,
+        _frame_count: usize,
+        _current_time: f32,
+        vector: &mut Vec<f32>,
+    ) -> SubtrActorResult<()> {
+        vector.push(self.get_value(player_id, processor)?);
+        Ok(())
+    }
+}
+
+fn global_feature_adders_by_name<F>() -> NameToFeatureAdder<F>
+where
+    F: TryFrom<f32> + Send + Sync + 'static,
+    <F as TryFrom<f32>>::Error: std::fmt::Debug,
+{
+    let mut m: NameToFeatureAdder<F> = std::collections::HashMap::new();
+    macro_rules! insert_adder {
+        ($adder_name:ident, $( $arguments:expr ),*) => {
+            m.insert(
+                stringify!($adder_name),
+                || -> Arc<dyn FeatureAdder<F> + Send + Sync + 'static> {
+                    $adder_name::<F>::arc_new($ ( $arguments ),*)
+                },
+            );
+        };
+        ($adder_name:ident) => {
+            insert_adder!($adder_name,)
+        }
+    }
+    insert_adder!(BallRigidBody);
+    insert_adder!(BallRigidBodyNoVelocities);
+    insert_adder!(VelocityAddedBallRigidBodyNoVelocities);
+    insert_adder!(InterpolatedBallRigidBodyNoVelocities, 0.0);
+    insert_adder!(AveragedBallRigidBody, DEFAULT_AVERAGING_WINDOW_SECONDS);
+    insert_adder!(BallDistanceToGoals);
+    insert_adder!(SecondsRemaining);
+    insert_adder!(ContinuousSecondsRemaining);
+    insert_adder!(ClutchTime);
+    insert_adder!(GameOvertime);
+    insert_adder!(ReplicatedStateName);
+    insert_adder!(CurrentTime);
+    insert_adder!(FrameTime);
+    insert_adder!(BoostPadTimers);
+    insert_adder!(BoostPadStates);
+    insert_adder!(BallShotGeometry);
+    insert_adder!(BallFieldThird);
+    insert_adder!(BallIsSleeping);
+    insert_adder!(BallInPlay);
+    insert_adder!(BallInHoopZone);
+    insert_adder!(BallAcceleration);
+    m
+}
+
+fn player_feature_adders_by_name<F>() -> NameToPlayerFeatureAdder<F>
+where
+    F: TryFrom<f32> + Send + Sync + 'static,
+    <F as TryFrom<f32>>::Error: std::fmt::Debug,
+{
+    let mut m: NameToPlayerFeatureAdder<F> = std::collections::HashMap::new();
+    macro_rules! insert_adder {
+        ($adder_name:ident, $( $arguments:expr ),*) => {
+            m.insert(
+                stringify!($adder_name),
+                || -> Arc<dyn PlayerFeatureAdder<F> + Send + Sync + 'static> {
+                    $adder_name::<F>::arc_new($ ( $arguments ),*)
+                },
+            );
+        };
+        ($adder_name:ident) => {
+            insert_adder!($adder_name,)
+        };
+    }
+    insert_adder!(PlayerRigidBody);
+    insert_adder!(PlayerRigidBodyNoVelocities);
+    insert_adder!(VelocityAddedPlayerRigidBodyNoVelocities);
+    insert_adder!(InterpolatedPlayerRigidBodyNoVelocities, 0.003);
+    insert_adder!(AveragedPlayerRigidBody, DEFAULT_AVERAGING_WINDOW_SECONDS);
+    insert_adder!(PlayerBoost);
+    insert_adder!(InterpolatedPlayerBoost);
+    insert_adder!(PlayerThrottleSteer);
+    insert_adder!(PlayerHandbrake);
+    insert_adder!(PlayerSupersonic);
+    insert_adder!(PlayerOnGround);
+    insert_adder!(PlayerBallDistance);
+    insert_adder!(PlayerFieldThird);
+    insert_adder!(PlayerAngularSpeed);
+    insert_adder!(PlayerPing);
+    insert_adder!(PlayerJump);
+    insert_adder!(PlayerAnyJump);
+    insert_adder!(PlayerDemolishedBy, DEFAULT_UNKNOWN_DEMOLISHER_VALUE);
+    insert_adder!(PlayerLastTouch, DEFAULT_LAST_TOUCH_MAX_FRAMES);
+    insert_adder!(PlayerIsActive);
+    insert_adder!(PlayerDemosInflicted);
+    insert_adder!(PlayerDemosTaken);
+    insert_adder!(PlayerDemolishImpactSpeed);
+    insert_adder!(PlayerIsSleeping);
+    insert_adder!(PlayerTeamRelativeRotation);
+    insert_adder!(PlayerRotationDelta);
+    insert_adder!(PlayerAcceleration);
+    m
+}
+
+lazy_static! {
+    static ref INTERNED_COLUMN_HEADERS: std::sync::Mutex<std::collections::HashSet<&'static str>> =
+        std::sync::Mutex::new(std::collections::HashSet::new());
+    static ref NAME_TO_GLOBAL_FEATURE_ADDER: NameToFeatureAdder<f32> =
+        global_feature_adders_by_name();
+    static ref NAME_TO_PLAYER_FEATURE_ADDER: NameToPlayerFeatureAdder<f32> =
+        player_feature_adders_by_name();
+    static ref NAME_TO_GLOBAL_FEATURE_ADDER_F64: NameToFeatureAdder<f64> =
+        global_feature_adders_by_name();
+    static ref NAME_TO_PLAYER_FEATURE_ADDER_F64: NameToPlayerFeatureAdder<f64> =
+        player_feature_adders_by_name();
 }
@@ -0,0 +1,197 @@
+use crate::*;
+use ::ndarray;
+use std::collections::HashMap;
+
+/// The arrays produced by a [`PerPlayerNDArrayCollector`]. Every array has
+/// one row per collected frame, so rows with the same index in different
+/// arrays describe the same point in time.
+pub struct PerPlayerNDArrays<F> {
+    /// The replay metadata, along with the column headers of
+    /// [`Self::global`] (the global headers) and of each array in
+    /// [`Self::players`] (the player headers).
+    pub meta: ReplayMetaWithHeaders,
+    /// The features produced by the [`FeatureAdder`] instances, e.g. those
+    /// describing the ball.
+    pub global: ndarray::Array2<F>,
+    /// The features produced by the [`PlayerFeatureAdder`] instances for each
+    /// player.
+    pub players: HashMap<PlayerId, ndarray::Array2<F>>,
+}
+
+/// [`PerPlayerNDArrayCollector`] is a [`Collector`] which produces the same
+/// features as an [`NDArrayCollector`] built with the same feature adders, but
+/// rather than laying out the features of every player side by side in one
+/// wide array, it produces a separate array for each player, along with an
+/// array of the global features. See [`PerPlayerNDArrays`].
+///
+/// A player is considered absent from a row when any of the player feature
+/// adders fails for them because they have no car, or their car has no rigid
+/// body, e.g. after being demolished. All of that player's columns in the row
+/// are then filled with the `absent_player_value` given to [`Self::new`], so
+/// that every array keeps the same number of rows. Any other error of a
+/// player feature adder is returned.
+pub struct PerPlayerNDArrayCollector<F> {
+    feature_adders: FeatureAdders<F>,
+    player_feature_adders: PlayerFeatureAdders<F>,
+    absent_player_value: F,
+    global_data: Vec<F>,
+    player_data: Vec<(PlayerId, Vec<F>)>,
+    replay_meta: Option<ReplayMeta>,
+    frames_added: usize,
+}
+
+impl<F: Clone> PerPlayerNDArrayCollector<F> {
+    /// Creates a new [`PerPlayerNDArrayCollector`].
+    ///
+    /// # Arguments
+    ///
+    /// * `feature_adders` - The [`FeatureAdder`] instances that produce the
+    ///   columns of the global array.
+    /// * `player_feature_adders` - The [`PlayerFeatureAdder`] instances that
+    ///   produce the columns of each player's array.
+    /// * `absent_player_value` - The value used to fill the rows of players
+    ///   that are absent from a frame.
+    pub fn new(
+        feature_adders: FeatureAdders<F>,
+        player_feature_adders: PlayerFeatureAdders<F>,
+        absent_player_value: F,
+    ) -> Self {
+        Self {
+            feature_adders,
+            player_feature_adders,
+            absent_player_value,
+            global_data: Vec::new(),
+            player_data: Vec::new(),
+            replay_meta: None,
+            frames_added: 0,
+        }
+    }
+
+    /// Returns the column headers of the arrays produced by this collector.
+    /// The global headers describe the global array and the player headers
+    /// describe each player's array.
+    pub fn get_column_headers(&self) -> NDArrayColumnHeaders {
+        NDArrayColumnHeaders::from_feature_adders(&self.feature_adders, &self.player_feature_adders)
+    }
+
+    /// Returns the number of rows that have been recorded so far.
+    pub fn frames_added(&self) -> usize {
+        self.frames_added
+    }
+
+    /// Consumes the [`PerPlayerNDArrayCollector`] and returns the collected
+    /// features as [`PerPlayerNDArrays`].
+    pub fn get_arrays(self) -> SubtrActorResult<PerPlayerNDArrays<F>> {
+        let column_headers = self.get_column_headers();
+        let global_feature_count = column_headers.global_headers.len();
+        let player_feature_count = column_headers.player_headers.len();
+        let frames_added = self.frames_added;
+        let to_array = |data: Vec<F>, feature_count: usize| {
+            ndarray::Array2::from_shape_vec((frames_added, feature_count), data)
+                .map_err(SubtrActorErrorVariant::NDArrayShapeError)
+                .map_err(SubtrActorError::new)
+        };
+        let players = self
+            .player_data
+            .into_iter()
+            .map(|(player_id, data)| Ok((player_id, to_array(data, player_feature_count)?)))
+            .collect::<SubtrActorResult<HashMap<_, _>>>()?;
+        Ok(PerPlayerNDArrays {
+            meta: ReplayMetaWithHeaders {
+                replay_meta: self.replay_meta.ok_or(SubtrActorError::new(
+                    SubtrActorErrorVariant::CouldNotBuildReplayMeta,
+                ))?,
+                column_headers,
+            },
+            global: to_array(self.global_data, global_feature_count)?,
+            players,
+        })
+    }
+
+    fn player_feature_count(&self) -> usize {
+        self.player_feature_adders
+            .iter()
+            .map(|pfa| pfa.features_added())
+            .sum()
+    }
+
+    fn maybe_set_replay_meta(&mut self, processor: &ReplayProcessor) -> SubtrActorResult<()> {
+        if self.replay_meta.is_none() {
+            self.replay_meta = Some(processor.get_replay_meta()?);
+            self.player_data = processor
+                .iter_player_ids_in_order()
+                .map(|player_id| (player_id.clone(), Vec::new()))
+                .collect();
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether the error means that the player has no car, or that their
+/// car has no rigid body.
+fn is_absent_player_error(variant: &SubtrActorErrorVariant) -> bool {
+    match variant {
+        SubtrActorErrorVariant::ActorNotFound { name, .. } => *name == "Car",
+        SubtrActorErrorVariant::NoStateForActorId { .. } => true,
+        SubtrActorErrorVariant::PropertyNotFoundInState { property } => {
+            *property == RIGID_BODY_STATE_KEY
+        }
+        _ => false,
+    }
+}
+
+impl<F: Clone> Collector for PerPlayerNDArrayCollector<F> {
+    fn process_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        frame: &boxcars::Frame,
+        frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        self.maybe_set_replay_meta(processor)?;
+
+        if !processor.ball_rigid_body_exists()? {
+            return Ok(TimeAdvance::NextFrame);
+        }
+
+        for feature_adder in self.feature_adders.iter() {
+            feature_adder.add_features(
+                processor,
+                frame,
+                frame_number,
+                current_time,
+                &mut self.global_data,
+            )?;
+        }
+
+        let player_feature_count = self.player_feature_count();
+        for (player_id, data) in self.player_data.iter_mut() {
+            let row_start = data.len();
+            let result = self.player_feature_adders.iter().try_for_each(|pfa| {
+                pfa.add_features(
+                    player_id,
+                    processor,
+                    frame,
+                    frame_number,
+                    current_time,
+                    data,
+                )
+            });
+            match result {
+                Ok(()) => {}
+                Err(error) if is_absent_player_error(&error.variant) => {
+                    data.truncate(row_start);
+                    data.resize(
+                        row_start + player_feature_count,
+                        self.absent_player_value.clone(),
+                    );
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        self.frames_added += 1;
+
+        Ok(TimeAdvance::NextFrame)
+    }
+}
@@ -0,0 +1,220 @@
+use serde::Serialize;
+
+use crate::*;
+
+/// The touches and possession of a single player or team, see
+/// [`PossessionStats`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PossessionTally {
+    /// The number of times the ball was touched.
+    pub touches: usize,
+    /// The number of seconds of active play during which the ball was in
+    /// possession.
+    pub possession_seconds: f32,
+    /// The number of seconds of the longest uninterrupted possession.
+    pub longest_possession_seconds: f32,
+}
+
+impl PossessionTally {
+    fn add_possession(&mut self, seconds: f32, streak_seconds: f32) {
+        self.possession_seconds += seconds;
+        self.longest_possession_seconds = f32::max(self.longest_possession_seconds, streak_seconds);
+    }
+}
+
+/// The touch and possession statistics of a replay, as computed by a
+/// [`PossessionCollector`].
+///
+/// A team is in possession of the ball from the time one of its players
+/// touches it until a player of the other team touches it, play stops, or the
+/// ball goes untouched for longer than the timeout of the collector. A player
+/// is in possession in the same way, except that the possession also ends
+/// when a teammate touches the ball. Time is only attributed while the ball is
+/// in play, see [`ReplayProcessor::is_ball_in_play`], so the possession times
+/// of the two teams never add up to more than [`Self::active_play_seconds`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PossessionStats {
+    pub team_zero: PossessionTally,
+    pub team_one: PossessionTally,
+    pub players: Vec<(PlayerId, PossessionTally)>,
+    /// The number of seconds during which the ball was in play.
+    pub active_play_seconds: f32,
+}
+
+impl PossessionStats {
+    pub fn as_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    fn team_mut(&mut self, is_team_0: bool) -> &mut PossessionTally {
+        if is_team_0 {
+            &mut self.team_zero
+        } else {
+            &mut self.team_one
+        }
+    }
+
+    fn player_mut(&mut self, player_id: &PlayerId) -> &mut PossessionTally {
+        self.players
+            .get_entry(player_id.clone())
+            .or_insert_with(PossessionTally::default)
+    }
+}
+
+/// The possession that is currently running.
+struct Possession {
+    player: PlayerId,
+    is_team_0: bool,
+    last_touch_time: f32,
+    team_seconds: f32,
+    player_seconds: f32,
+}
+
+/// A [`Collector`] which computes [`PossessionStats`] from the
+/// [`ReplayProcessor::ball_touches`] of a replay.
+pub struct PossessionCollector {
+    timeout: f32,
+    stats: PossessionStats,
+    touches_seen: usize,
+    last_time: Option<f32>,
+    possession: Option<Possession>,
+}
+
+impl Default for PossessionCollector {
+    fn default() -> Self {
+        Self::new(DEFAULT_POSSESSION_TIMEOUT_SECONDS)
+    }
+}
+
+impl PossessionCollector {
+    /// Constructs a new [`PossessionCollector`] which ends a possession once
+    /// the ball has gone untouched for `timeout` seconds, so that a loose ball
+    /// isn't attributed to whoever touched it last.
+    pub fn new(timeout: f32) -> Self {
+        Self {
+            timeout,
+            stats: PossessionStats::default(),
+            touches_seen: 0,
+            last_time: None,
+            possession: None,
+        }
+    }
+
+    /// Processes `replay` and returns its [`PossessionStats`].
+    pub fn get_possession_stats(
+        mut self,
+        replay: &boxcars::Replay,
+    ) -> SubtrActorResult<PossessionStats> {
+        ReplayProcessor::new(replay)?.process(&mut self)?;
+        Ok(self.get_stats())
+    }
+
+    /// Returns the [`PossessionStats`] collected so far.
+    pub fn get_stats(self) -> PossessionStats {
+        self.stats
+    }
+
+    /// Advances the statistics to `current_time`, given every ball touch up to
+    /// that time and whether the ball is in play. This is what
+    /// [`Collector::process_frame`] does with the state of the
+    /// [`ReplayProcessor`], and is exposed so that possession can also be
+    /// computed from touches that were recorded elsewhere.
+    pub fn record_frame(
+        &mut self,
+        ball_touches: &[BallTouchInfo],
+        is_ball_in_play: bool,
+        current_time: f32,
+    ) {
+        let previous_time = self.last_time.unwrap_or(current_time);
+        let elapsed = f32::max(current_time - previous_time, 0.0);
+        self.last_time = Some(current_time);
+
+        if is_ball_in_play {
+            self.stats.active_play_seconds += elapsed;
+            self.attribute_possession(previous_time, current_time);
+        } else {
+            self.possession = None;
+        }
+
+        // The touch history is cleared when the processor is reset.
+        if ball_touches.len() < self.touches_seen {
+            self.touches_seen = 0;
+        }
+        for touch in ball_touches[self.touches_seen..].iter() {
+            self.record_touch(touch);
+        }
+        self.touches_seen = ball_touches.len();
+    }
+
+    fn attribute_possession(&mut self, start_time: f32, end_time: f32) {
+        let possession = match self.possession.as_mut() {
+            Some(possession) => possession,
+            None => return,
+        };
+        let timeout_time = possession.last_touch_time + self.timeout;
+        let seconds = f32::max(f32::min(end_time, timeout_time) - start_time, 0.0);
+        possession.team_seconds += seconds;
+        possession.player_seconds += seconds;
+        let (is_team_0, team_seconds, player_seconds) = (
+            possession.is_team_0,
+            possession.team_seconds,
+            possession.player_seconds,
+        );
+        let player = possession.player.clone();
+        if end_time >= timeout_time {
+            self.possession = None;
+        }
+        self.stats
+            .team_mut(is_team_0)
+            .add_possession(seconds, team_seconds);
+        self.stats
+            .player_mut(&player)
+            .add_possession(seconds, player_seconds);
+    }
+
+    fn record_touch(&mut self, touch: &BallTouchInfo) {
+        self.stats.team_mut(touch.is_team_0).touches += 1;
+        self.stats.player_mut(&touch.player).touches += 1;
+        let (team_seconds, player_seconds) = match self.possession.as_ref() {
+            Some(possession) if possession.is_team_0 == touch.is_team_0 => (
+                possession.team_seconds,
+                if possession.player == touch.player {
+                    possession.player_seconds
+                } else {
+                    0.0
+                },
+            ),
+            _ => (0.0, 0.0),
+        };
+        self.possession = Some(Possession {
+            player: touch.player.clone(),
+            is_team_0: touch.is_team_0,
+            last_touch_time: touch.time,
+            team_seconds,
+            player_seconds,
+        });
+    }
+}
+
+impl Collector for PossessionCollector {
+    fn process_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        _frame: &boxcars::Frame,
+        _frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        if self.stats.players.is_empty() {
+            self.stats.players = processor
+                .iter_player_ids_in_order()
+                .map(|player_id| (player_id.clone(), PossessionTally::default()))
+                .collect();
+        }
+        self.record_frame(
+            &processor.ball_touches,
+            processor.is_ball_in_play(),
+            current_time,
+        );
+        Ok(TimeAdvance::NextFrame)
+    }
+}
@@ -10,10 +10,15 @@ pub enum BallFrame {
 }
 
 impl BallFrame {
-    fn new_from_processor(processor: &ReplayProcessor, current_time: f32) -> Self {
+    fn new_from_processor(
+        processor: &ReplayProcessor,
+        current_time: f32,
+        close_enough: f32,
+    ) -> Self {
         if processor.get_ignore_ball_syncing().unwrap_or(false) {
             Self::Empty
-        } else if let Ok(rigid_body) = processor.get_interpolated_ball_rigid_body(current_time, 0.0)
+        } else if let Ok(rigid_body) =
+            processor.get_interpolated_ball_rigid_body(current_time, close_enough)
         {
             Self::new_from_rigid_body(rigid_body)
         } else {
@@ -32,6 +37,34 @@ impl BallFrame {
     }
 }
 
+/// The controller inputs of a player, as replicated for their car. Each input
+/// is `None` if it has not been replicated for the car.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerInputs {
+    /// The throttle, in the range `[-1.0, 1.0]`.
+    pub throttle: Option<f32>,
+    /// The steering, in the range `[-1.0, 1.0]`.
+    pub steer: Option<f32>,
+    /// Whether the handbrake (powerslide) is engaged.
+    pub handbrake: Option<bool>,
+}
+
+impl PlayerInputs {
+    fn new_from_processor(processor: &ReplayProcessor, player_id: &PlayerId) -> Self {
+        Self {
+            throttle: processor
+                .get_player_throttle(player_id)
+                .ok()
+                .map(normalize_replicated_input),
+            steer: processor
+                .get_player_steer(player_id)
+                .ok()
+                .map(normalize_replicated_input),
+            handbrake: processor.get_player_handbrake(player_id).ok(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum PlayerFrame {
     Empty,
@@ -42,6 +75,10 @@ pub enum PlayerFrame {
         jump_active: bool,
         double_jump_active: bool,
         dodge_active: bool,
+        /// The player's controller inputs, only present when enabled with
+        /// [`ReplayDataCollector::with_inputs`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        inputs: Option<PlayerInputs>,
     },
 }
 
@@ -50,27 +87,26 @@ impl PlayerFrame {
         processor: &ReplayProcessor,
         player_id: &PlayerId,
         current_time: f32,
+        include_inputs: bool,
+        close_enough: f32,
     ) -> SubtrActorResult<Self> {
         let rigid_body =
-            processor.get_interpolated_player_rigid_body(player_id, current_time, 0.0)?;
+            processor.get_interpolated_player_rigid_body(player_id, current_time, close_enough)?;
 
         if rigid_body.sleeping {
             return Ok(PlayerFrame::Empty);
         }
 
-        let boost_amount = processor.get_player_boost_level(player_id)?;
-        let boost_active = processor.get_boost_active(player_id).unwrap_or(0) % 2 == 1;
-        let jump_active = processor.get_jump_active(player_id).unwrap_or(0) % 2 == 1;
-        let double_jump_active = processor.get_double_jump_active(player_id).unwrap_or(0) % 2 == 1;
-        let dodge_active = processor.get_dodge_active(player_id).unwrap_or(0) % 2 == 1;
+        let states = processor.get_player_component_states(player_id)?;
 
         Ok(Self::from_data(
             rigid_body,
-            boost_amount,
-            boost_active,
-            jump_active,
-            double_jump_active,
-            dodge_active,
+            states.boost_amount,
+            states.boost_active,
+            states.jump_active,
+            states.double_jump_active,
+            states.dodge_active,
+            include_inputs.then(|| PlayerInputs::new_from_processor(processor, player_id)),
         ))
     }
 
@@ -81,6 +117,7 @@ impl PlayerFrame {
         jump_active: bool,
         double_jump_active: bool,
         dodge_active: bool,
+        inputs: Option<PlayerInputs>,
     ) -> Self {
         if rigid_body.sleeping {
             Self::Empty
@@ -92,6 +129,7 @@ impl PlayerFrame {
                 jump_active,
                 double_jump_active,
                 dodge_active,
+                inputs,
             }
         }
     }
@@ -139,17 +177,53 @@ impl BallData {
 pub struct MetadataFrame {
     pub time: f32,
     pub seconds_remaining: i32,
+    /// The time elapsed since the previous frame emitted by the collector,
+    /// which reflects any resampling (e.g. by a [`FrameRateDecorator`]) rather
+    /// than the spacing of the replay's network frames. It is `0.0` for the
+    /// first frame, and is only present when enabled with
+    /// [`ReplayDataCollector::with_frame_deltas`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_time: Option<f32>,
+    /// Whether the game was in overtime, see [`ReplayProcessor::get_is_overtime`].
+    pub is_overtime: bool,
+    /// The score of team 0, see [`ReplayProcessor::get_team_scores`].
+    pub team_0_score: i32,
+    /// The score of team 1, see [`ReplayProcessor::get_team_scores`].
+    pub team_1_score: i32,
 }
 
 impl MetadataFrame {
-    fn new_from_processor(processor: &ReplayProcessor, time: f32) -> SubtrActorResult<Self> {
-        Ok(Self::new(time, processor.get_seconds_remaining()?))
+    fn new_from_processor(
+        processor: &ReplayProcessor,
+        time: f32,
+        delta_time: Option<f32>,
+    ) -> SubtrActorResult<Self> {
+        let (team_0_score, team_1_score) = processor.get_team_scores()?;
+        Ok(Self::new(
+            time,
+            processor.get_seconds_remaining()?,
+            delta_time,
+            processor.get_is_overtime()?,
+            team_0_score,
+            team_1_score,
+        ))
     }
 
-    fn new(time: f32, seconds_remaining: i32) -> Self {
+    fn new(
+        time: f32,
+        seconds_remaining: i32,
+        delta_time: Option<f32>,
+        is_overtime: bool,
+        team_0_score: i32,
+        team_1_score: i32,
+    ) -> Self {
         MetadataFrame {
             time,
             seconds_remaining,
+            delta_time,
+            is_overtime,
+            team_0_score,
+            team_1_score,
         }
     }
 }
@@ -165,7 +239,29 @@ pub struct FrameData {
 pub struct ReplayData {
     pub frame_data: FrameData,
     pub meta: ReplayMeta,
-    pub demolish_infos: Vec<DemolishInfo>,
+    /// The demolitions in the replay, with the names and teams of the players
+    /// involved resolved from [`Self::meta`].
+    pub demolish_infos: Vec<DemolishInfoWithNames>,
+    pub kickoff_results: Vec<KickoffResult>,
+    /// Every detected touch of the ball, in order, see [`BallTouchInfo`].
+    pub ball_touches: Vec<BallTouchInfo>,
+    /// The goals in the replay, see [`GoalInfo`].
+    pub goals: Vec<GoalInfo>,
+    /// Touches that sent the ball on target, see [`ShotInfo`].
+    pub shots: Vec<ShotInfo>,
+    /// Touches that stopped the ball from going in, see [`SaveInfo`].
+    pub saves: Vec<SaveInfo>,
+    pub dribbles: Vec<DribbleInfo>,
+    pub boost_pickups: Vec<BoostPickupEvent>,
+    /// The number of seconds each player spent with less boost than the
+    /// threshold set with
+    /// [`ReplayDataCollector::with_boost_starved_threshold`]. Time during
+    /// which the player had no car, e.g. after being demolished, is excluded.
+    pub boost_starved_seconds: Vec<(PlayerId, f32)>,
+    /// The total distance, in unreal units (1 uu is 1 cm), traveled by each
+    /// player's car, as computed by
+    /// [`ReplayProcessor::get_distance_traveled`].
+    pub distance_traveled: Vec<(PlayerId, f32)>,
 }
 
 impl ReplayData {
@@ -202,17 +298,85 @@ impl FrameData {
     }
 }
 
+/// The data recorded by a [`ReplayDataCollector`] for a single frame, which is
+/// also the form of each line written by
+/// [`ReplayDataCollector::stream_frames`].
+#[derive(Serialize)]
+struct StreamedFrame {
+    frame: usize,
+    time: f32,
+    metadata: MetadataFrame,
+    ball: BallFrame,
+    players: Vec<(PlayerId, PlayerFrame)>,
+}
+
+fn write_json_line<W: std::io::Write, T: Serialize>(
+    writer: &mut W,
+    value: &T,
+) -> SubtrActorResult<()> {
+    let to_error =
+        |error: String| SubtrActorError::new(SubtrActorErrorVariant::StreamWriteError(error));
+    serde_json::to_writer(&mut *writer, value).map_err(|error| to_error(error.to_string()))?;
+    writer
+        .write_all(b"\n")
+        .map_err(|error| to_error(error.to_string()))
+}
+
 pub struct ReplayDataCollector {
     frame_data: FrameData,
+    boost_starved_threshold: f32,
+    boost_starved_seconds: Vec<(PlayerId, f32)>,
+    last_time: Option<f32>,
+    include_frame_deltas: bool,
+    include_inputs: bool,
+    close_enough: f32,
 }
 
 impl ReplayDataCollector {
     pub fn new() -> Self {
         ReplayDataCollector {
             frame_data: FrameData::new(),
+            boost_starved_threshold: DEFAULT_BOOST_STARVED_THRESHOLD,
+            boost_starved_seconds: Vec::new(),
+            last_time: None,
+            include_frame_deltas: false,
+            include_inputs: false,
+            close_enough: 0.0,
         }
     }
 
+    /// Sets the boost level, in the replicated units that range from 0 to 255,
+    /// below which a player is considered starved of boost. Defaults to
+    /// [`DEFAULT_BOOST_STARVED_THRESHOLD`].
+    pub fn with_boost_starved_threshold(mut self, threshold: f32) -> Self {
+        self.boost_starved_threshold = threshold;
+        self
+    }
+
+    /// Includes [`MetadataFrame::delta_time`] in every emitted metadata frame.
+    pub fn with_frame_deltas(mut self) -> Self {
+        self.include_frame_deltas = true;
+        self
+    }
+
+    /// Includes the [`PlayerInputs`] of each player in every emitted
+    /// [`PlayerFrame::Data`].
+    pub fn with_inputs(mut self) -> Self {
+        self.include_inputs = true;
+        self
+    }
+
+    /// Sets how close, in seconds, the time of a frame must be to that of a
+    /// network frame in which a rigid body was updated for that rigid body to
+    /// be used as is. Within this threshold, ball and player rigid bodies snap
+    /// to the nearest real update rather than being interpolated between
+    /// updates. Defaults to `0.0`, which interpolates unless the times match
+    /// exactly.
+    pub fn with_close_enough(mut self, threshold: f32) -> Self {
+        self.close_enough = threshold;
+        self
+    }
+
     pub fn get_frame_data(self) -> FrameData {
         self.frame_data
     }
@@ -220,14 +384,131 @@ impl ReplayDataCollector {
     pub fn get_replay_data(mut self, replay: &boxcars::Replay) -> SubtrActorResult<ReplayData> {
         let mut processor = ReplayProcessor::new(replay)?;
         processor.process(&mut self)?;
+        self.get_replay_data_from_processor(processor)
+    }
+
+    /// Builds the [`ReplayData`] from the frames this collector has recorded
+    /// and the events recorded by `processor`, which must be the processor
+    /// that drove this collector, e.g. through a [`CompositeCollector`]. This
+    /// is what [`Self::get_replay_data`] does once the replay is processed.
+    pub fn get_replay_data_from_processor(
+        mut self,
+        processor: ReplayProcessor,
+    ) -> SubtrActorResult<ReplayData> {
+        let boost_starved_seconds = std::mem::take(&mut self.boost_starved_seconds);
+        Self::build_replay_data(processor, self.get_frame_data(), boost_starved_seconds)
+    }
+
+    /// Processes `replay` and writes its frames to `writer` as newline
+    /// delimited JSON, rather than accumulating them in memory like
+    /// [`Self::get_replay_data`].
+    ///
+    /// Each frame is written on its own line as an object of the form
+    /// `{"frame": n, "time": t, "metadata": ..., "ball": ..., "players": [...]}`,
+    /// where `metadata`, `ball` and `players` hold the [`MetadataFrame`],
+    /// [`BallFrame`] and [`PlayerFrame`]s that [`Self::get_replay_data`] would
+    /// have recorded. A final trailer line holds a [`ReplayData`] with empty
+    /// [`ReplayData::frame_data`], carrying the metadata and the events of the
+    /// replay, e.g. [`ReplayData::demolish_infos`].
+    pub fn stream_frames<W: std::io::Write>(
+        mut self,
+        replay: &boxcars::Replay,
+        mut writer: W,
+    ) -> SubtrActorResult<()> {
+        let mut processor = ReplayProcessor::new(replay)?;
+        let mut write_frame = |processor: &ReplayProcessor,
+                               _frame: &boxcars::Frame,
+                               frame_number: usize,
+                               current_time: f32| {
+            let frame = self.next_frame(processor, frame_number, current_time)?;
+            write_json_line(&mut writer, &frame)?;
+            Ok(TimeAdvance::NextFrame)
+        };
+        processor.process(&mut write_frame)?;
+        let boost_starved_seconds = std::mem::take(&mut self.boost_starved_seconds);
+        let trailer = Self::build_replay_data(processor, FrameData::new(), boost_starved_seconds)?;
+        write_json_line(&mut writer, &trailer)?;
+        writer.flush().map_err(|error| {
+            SubtrActorError::new(SubtrActorErrorVariant::StreamWriteError(error.to_string()))
+        })
+    }
+
+    fn build_replay_data(
+        mut processor: ReplayProcessor,
+        frame_data: FrameData,
+        boost_starved_seconds: Vec<(PlayerId, f32)>,
+    ) -> SubtrActorResult<ReplayData> {
         let meta = processor.get_replay_meta()?;
+        let demolish_infos = processor
+            .demolishes
+            .drain(..)
+            .map(|info| DemolishInfoWithNames::from_meta(info, &meta))
+            .collect();
         Ok(ReplayData {
             meta,
-            demolish_infos: processor.demolishes,
-            frame_data: self.get_frame_data(),
+            kickoff_results: processor.get_kickoff_results(),
+            distance_traveled: processor.get_distance_traveled(),
+            demolish_infos,
+            ball_touches: processor.ball_touches,
+            goals: processor.goals,
+            shots: processor.shots,
+            saves: processor.saves,
+            dribbles: processor.dribbles,
+            boost_pickups: processor.boost_pickups,
+            boost_starved_seconds,
+            frame_data,
         })
     }
 
+    fn next_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<StreamedFrame> {
+        let elapsed = self.advance_time(current_time);
+        let delta_time = Some(elapsed).filter(|_| self.include_frame_deltas);
+        let metadata_frame =
+            MetadataFrame::new_from_processor(processor, current_time, delta_time)?;
+        let ball_frame = BallFrame::new_from_processor(processor, current_time, self.close_enough);
+        let player_frames = self.get_player_frames(processor, current_time)?;
+        self.update_boost_starved_seconds(&player_frames, elapsed);
+        Ok(StreamedFrame {
+            frame: frame_number,
+            time: current_time,
+            metadata: metadata_frame,
+            ball: ball_frame,
+            players: player_frames,
+        })
+    }
+
+    fn advance_time(&mut self, current_time: f32) -> f32 {
+        let elapsed = self
+            .last_time
+            .map(|last_time| current_time - last_time)
+            .unwrap_or(0.0);
+        self.last_time = Some(current_time);
+        elapsed
+    }
+
+    fn update_boost_starved_seconds(
+        &mut self,
+        player_frames: &[(PlayerId, PlayerFrame)],
+        elapsed: f32,
+    ) {
+        for (player_id, frame) in player_frames {
+            let starved_seconds = self
+                .boost_starved_seconds
+                .get_entry(player_id.clone())
+                .or_insert_with(|| 0.0);
+            if let PlayerFrame::Data { boost_amount, .. } = frame {
+                if *boost_amount < self.boost_starved_threshold {
+                    *starved_seconds += elapsed;
+                }
+            }
+        }
+    }
+
     fn get_player_frames(
         &self,
         processor: &ReplayProcessor,
@@ -238,8 +519,14 @@ impl ReplayDataCollector {
             .map(|player_id| {
                 (
                     player_id.clone(),
-                    PlayerFrame::new_from_processor(processor, player_id, current_time)
-                        .unwrap_or_else(|_err| PlayerFrame::Empty),
+                    PlayerFrame::new_from_processor(
+                        processor,
+                        player_id,
+                        current_time,
+                        self.include_inputs,
+                        self.close_enough,
+                    )
+                    .unwrap_or_else(|_err| PlayerFrame::Empty),
                 )
             })
             .collect())
@@ -251,14 +538,12 @@ impl Collector for ReplayDataCollector {
         &mut self,
         processor: &ReplayProcessor,
         _frame: &boxcars::Frame,
-        _frame_number: usize,
+        frame_number: usize,
         current_time: f32,
     ) -> SubtrActorResult<TimeAdvance> {
-        let metadata_frame = MetadataFrame::new_from_processor(processor, current_time)?;
-        let ball_frame = BallFrame::new_from_processor(processor, current_time);
-        let player_frames = self.get_player_frames(processor, current_time)?;
+        let frame = self.next_frame(processor, frame_number, current_time)?;
         self.frame_data
-            .add_frame(metadata_frame, ball_frame, player_frames)?;
+            .add_frame(frame.metadata, frame.ball, frame.players)?;
         Ok(TimeAdvance::NextFrame)
     }
 }
@@ -0,0 +1,139 @@
+use crate::*;
+
+/// [`RowStreamCollector`] is a [`Collector`] which produces the same rows as
+/// an [`NDArrayCollector`] built with the same feature adders, but rather than
+/// accumulating them into an [`::ndarray::Array2`], hands each row to a
+/// callback as soon as it is built. This makes it possible to feed the rows of
+/// arbitrarily long replays to a consumer while only ever holding a single row
+/// in memory.
+///
+/// Rows contain the global features followed by the player features of each
+/// player in the order of [`ReplayProcessor::iter_player_ids_in_order`], so
+/// that they line up with [`ReplayMetaWithHeaders::headers_vec`]. The replay
+/// metadata is determined before the first row is emitted, and can be
+/// obtained ahead of processing with [`Self::process_and_get_meta_and_headers`].
+pub struct RowStreamCollector<F, C> {
+    feature_adders: FeatureAdders<F>,
+    player_feature_adders: PlayerFeatureAdders<F>,
+    callback: C,
+    row: Vec<F>,
+    replay_meta: Option<ReplayMeta>,
+    rows_emitted: usize,
+}
+
+impl<F, C: FnMut(&[F])> RowStreamCollector<F, C> {
+    /// Creates a new [`RowStreamCollector`].
+    ///
+    /// # Arguments
+    ///
+    /// * `feature_adders` - The [`FeatureAdder`] instances that produce the
+    ///   global features of each row.
+    /// * `player_feature_adders` - The [`PlayerFeatureAdder`] instances that
+    ///   produce the features of each player in each row.
+    /// * `callback` - Invoked with each completed row.
+    pub fn new(
+        feature_adders: FeatureAdders<F>,
+        player_feature_adders: PlayerFeatureAdders<F>,
+        callback: C,
+    ) -> Self {
+        Self {
+            feature_adders,
+            player_feature_adders,
+            callback,
+            row: Vec::new(),
+            replay_meta: None,
+            rows_emitted: 0,
+        }
+    }
+
+    /// Returns the column headers of the rows produced by this collector.
+    pub fn get_column_headers(&self) -> NDArrayColumnHeaders {
+        NDArrayColumnHeaders::from_feature_adders(&self.feature_adders, &self.player_feature_adders)
+    }
+
+    /// Returns the number of rows that have been passed to the callback.
+    pub fn rows_emitted(&self) -> usize {
+        self.rows_emitted
+    }
+
+    /// Returns the replay metadata along with the column headers, if the
+    /// metadata has been determined.
+    pub fn get_meta_and_headers(&self) -> SubtrActorResult<ReplayMetaWithHeaders> {
+        Ok(ReplayMetaWithHeaders {
+            replay_meta: self
+                .replay_meta
+                .as_ref()
+                .ok_or(SubtrActorError::new(
+                    SubtrActorErrorVariant::CouldNotBuildReplayMeta,
+                ))?
+                .clone(),
+            column_headers: self.get_column_headers(),
+        })
+    }
+
+    /// Processes just enough of `replay` to determine its metadata, and
+    /// returns it along with the column headers, without emitting any rows.
+    /// This can be used to learn the length of the rows before any of them is
+    /// produced.
+    pub fn process_and_get_meta_and_headers(
+        &mut self,
+        replay: &boxcars::Replay,
+    ) -> SubtrActorResult<ReplayMetaWithHeaders> {
+        let mut processor = ReplayProcessor::new(replay)?;
+        processor.process_long_enough_to_get_actor_ids()?;
+        self.maybe_set_replay_meta(&processor)?;
+        self.get_meta_and_headers()
+    }
+
+    fn maybe_set_replay_meta(&mut self, processor: &ReplayProcessor) -> SubtrActorResult<()> {
+        if self.replay_meta.is_none() {
+            self.replay_meta = Some(processor.get_replay_meta()?);
+        }
+        Ok(())
+    }
+}
+
+impl<F, C: FnMut(&[F])> Collector for RowStreamCollector<F, C> {
+    fn process_frame(
+        &mut self,
+        processor: &ReplayProcessor,
+        frame: &boxcars::Frame,
+        frame_number: usize,
+        current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        self.maybe_set_replay_meta(processor)?;
+
+        if !processor.ball_rigid_body_exists()? {
+            return Ok(TimeAdvance::NextFrame);
+        }
+
+        self.row.clear();
+        for feature_adder in self.feature_adders.iter() {
+            feature_adder.add_features(
+                processor,
+                frame,
+                frame_number,
+                current_time,
+                &mut self.row,
+            )?;
+        }
+
+        for player_id in processor.iter_player_ids_in_order() {
+            for player_feature_adder in self.player_feature_adders.iter() {
+                player_feature_adder.add_features(
+                    player_id,
+                    processor,
+                    frame,
+                    frame_number,
+                    current_time,
+                    &mut self.row,
+                )?;
+            }
+        }
+
+        (self.callback)(&self.row);
+        self.rows_emitted += 1;
+
+        Ok(TimeAdvance::NextFrame)
+    }
+}
@@ -1,29 +1,64 @@
-pub static BALL_TYPES: [&str; 5] = [
+pub static BALL_TYPES: [&str; 9] = [
     "Archetypes.Ball.Ball_Default",
     "Archetypes.Ball.Ball_Basketball",
+    "Archetypes.Ball.Ball_BasketBall_Mutator",
     "Archetypes.Ball.Ball_Puck",
     "Archetypes.Ball.CubeBall",
     "Archetypes.Ball.Ball_Breakout",
+    "Archetypes.Ball.Ball_Beachball",
+    "Archetypes.Ball.Ball_Anniversary",
+    "Archetypes.Ball.Ball_Haunted",
 ];
 
+pub static BOOST_PAD_TYPE_NAME: &str = "VehiclePickup_Boost_TA";
 pub static BOOST_TYPE: &str = "Archetypes.CarComponents.CarComponent_Boost";
+pub static CAMERA_SETTINGS_TYPE: &str = "TAGame.Default__CameraSettingsActor_TA";
 pub static CAR_TYPE: &str = "Archetypes.Car.Car_Default";
 pub static DODGE_TYPE: &str = "Archetypes.CarComponents.CarComponent_Dodge";
 pub static DOUBLE_JUMP_TYPE: &str = "Archetypes.CarComponents.CarComponent_DoubleJump";
+pub static DROPSHOT_GAME_TYPE: &str = "Archetypes.GameEvent.GameEvent_Breakout";
 pub static GAME_TYPE: &str = "Archetypes.GameEvent.GameEvent_Soccar";
+pub static GRIDIRON_GAME_TYPE: &str = "Archetypes.GameEvent.GameEvent_Football";
+pub static HOCKEY_GAME_TYPE: &str = "Archetypes.GameEvent.GameEvent_Hockey";
+pub static HOOPS_GAME_TYPE: &str = "Archetypes.GameEvent.GameEvent_Basketball";
+pub static RUMBLE_GAME_TYPE: &str = "Archetypes.GameEvent.GameEvent_Items";
+pub static GAME_TYPES: [&str; 6] = [
+    GAME_TYPE,
+    HOOPS_GAME_TYPE,
+    HOCKEY_GAME_TYPE,
+    DROPSHOT_GAME_TYPE,
+    GRIDIRON_GAME_TYPE,
+    RUMBLE_GAME_TYPE,
+];
+pub static GAME_EVENT_TYPE_PREFIX: &str = "Archetypes.GameEvent.GameEvent_";
 pub static JUMP_TYPE: &str = "Archetypes.CarComponents.CarComponent_Jump";
 pub static PLAYER_REPLICATION_KEY: &str = "Engine.Pawn:PlayerReplicationInfo";
 pub static PLAYER_TYPE: &str = "TAGame.Default__PRI_TA";
+pub static TEAM_ONE_TYPE: &str = "Archetypes.Teams.Team1";
+pub static TEAM_ZERO_TYPE: &str = "Archetypes.Teams.Team0";
 
+pub static BALL_HIT_TEAM_NUM_KEY: &str = "TAGame.Ball_TA:HitTeamNum";
 pub static BOOST_AMOUNT_KEY: &str = "TAGame.CarComponent_Boost_TA:ReplicatedBoostAmount";
+pub static CAMERA_SETTINGS_PLAYER_KEY: &str = "TAGame.CameraSettingsActor_TA:PRI";
+pub static CAMERA_SETTINGS_PROFILE_KEY: &str = "TAGame.CameraSettingsActor_TA:ProfileSettings";
 pub static COMPONENT_ACTIVE_KEY: &str = "TAGame.CarComponent_TA:ReplicatedActive";
+pub static CUSTOM_TEAM_NAME_KEY: &str = "TAGame.Team_TA:CustomTeamName";
 pub static DEMOLISH_GOAL_EXPLOSION_KEY: &str = "TAGame.Car_TA:ReplicatedDemolishGoalExplosion";
+pub static HANDBRAKE_KEY: &str = "TAGame.Vehicle_TA:bReplicatedHandbrake";
 pub static IGNORE_SYNCING_KEY: &str = "TAGame.RBActor_TA:bIgnoreSyncing";
 pub static LAST_BOOST_AMOUNT_KEY: &str = "TAGame.CarComponent_Boost_TA:ReplicatedBoostAmount.Last";
+pub static OVERTIME_KEY: &str = "TAGame.GameEvent_Soccar_TA:bOverTime";
+pub static PLAYER_BOT_KEY: &str = "Engine.PlayerReplicationInfo:bBot";
 pub static PLAYER_NAME_KEY: &str = "Engine.PlayerReplicationInfo:PlayerName";
+pub static PLAYER_PING_KEY: &str = "Engine.PlayerReplicationInfo:Ping";
+pub static REPLICATED_STATE_NAME_KEY: &str = "TAGame.GameEvent_TA:ReplicatedStateName";
 pub static RIGID_BODY_STATE_KEY: &str = "TAGame.RBActor_TA:ReplicatedRBState";
+pub static ROUND_COUNTDOWN_KEY: &str = "TAGame.GameEvent_TA:ReplicatedRoundCountDownNumber";
 pub static SECONDS_REMAINING_KEY: &str = "TAGame.GameEvent_Soccar_TA:SecondsRemaining";
+pub static STEER_KEY: &str = "TAGame.Vehicle_TA:ReplicatedSteer";
 pub static TEAM_KEY: &str = "Engine.PlayerReplicationInfo:Team";
+pub static THROTTLE_KEY: &str = "TAGame.Vehicle_TA:ReplicatedThrottle";
+pub static TEAM_SCORE_KEY: &str = "Engine.TeamInfo:Score";
 pub static UNIQUE_ID_KEY: &str = "Engine.PlayerReplicationInfo:UniqueId";
 pub static VEHICLE_KEY: &str = "TAGame.CarComponent_TA:Vehicle";
 
@@ -31,4 +66,212 @@ pub static EMPTY_ACTOR_IDS: [boxcars::ActorId; 0] = [];
 
 pub static BOOST_USED_PER_SECOND: f32 = 80.0 / 0.93;
 
+/// The default boost level, in the replicated units that range from 0 to 255,
+/// below which a player is considered to be starved of boost. This corresponds
+/// to 10 boost as displayed in game.
+pub static DEFAULT_BOOST_STARVED_THRESHOLD: f32 = 255.0 * 0.1;
+
+/// The default fraction by which the frame rate of a replay's network frames
+/// may differ from its `RecordFPS` header before the replay is considered to
+/// have a frame rate mismatch. See
+/// [`ReplayProcessor::has_frame_rate_mismatch`](crate::ReplayProcessor::has_frame_rate_mismatch).
+pub static DEFAULT_FRAME_RATE_MISMATCH_TOLERANCE: f32 = 0.1;
+
+/// The number of seconds remaining on the game clock at or below which
+/// [`ClutchTime`](crate::ClutchTime) considers the game to be in clutch time.
+pub static CLUTCH_TIME_SECONDS: i32 = 30;
+
+/// The value emitted by [`PlayerDemolishedBy`](crate::PlayerDemolishedBy)
+/// for a player that was not recently demolished.
+pub static NOT_DEMOLISHED_VALUE: f32 = -1.0;
+/// The default value emitted by
+/// [`PlayerDemolishedBy`](crate::PlayerDemolishedBy) for a player that was
+/// recently demolished by an attacker that is not among the replay's players.
+pub static DEFAULT_UNKNOWN_DEMOLISHER_VALUE: f32 = -2.0;
+/// The value emitted by [`PlayerBallDistance`](crate::PlayerBallDistance)
+/// when the rigid body of the player's car or of the ball is missing or
+/// sleeping. It is far larger than any distance on the field, so that it
+/// can't be mistaken for a real distance.
+pub static UNKNOWN_BALL_DISTANCE_VALUE: f32 = 100000.0;
+
 pub static MAX_DEMOLISH_KNOWN_FRAMES_PASSED: usize = 100;
+/// The number of frames, roughly the first 10 seconds of a replay, that are
+/// processed to discover its players and their actors before the replay is
+/// processed in full. See
+/// [`ReplayProcessor::process_long_enough_to_get_actor_ids`](crate::ReplayProcessor::process_long_enough_to_get_actor_ids).
+pub static ACTOR_ID_DISCOVERY_FRAMES: usize = 10 * 30;
+
+/// The maximum distance between the center of the ball and the center of a car
+/// for the car to be credited with a ball touch.
+pub static BALL_TOUCH_MAX_DISTANCE: f32 = 300.0;
+/// The minimum change in ball velocity between rigid body updates that is
+/// considered a touch when no `HitTeamNum` update accompanies it.
+pub static BALL_TOUCH_MIN_VELOCITY_CHANGE: f32 = 150.0;
+pub static MIN_FRAMES_BETWEEN_BALL_TOUCHES: usize = 5;
+/// The default number of frames after a touch during which
+/// [`PlayerLastTouch`](crate::PlayerLastTouch) flags the player who made it.
+pub static DEFAULT_LAST_TOUCH_MAX_FRAMES: usize = 30;
+/// The duration, in seconds, of the window over which the averaged rigid body
+/// feature adders, such as [`AveragedBallRigidBody`](crate::AveragedBallRigidBody),
+/// average when they are looked up by name.
+pub static DEFAULT_AVERAGING_WINDOW_SECONDS: f32 = 0.1;
+/// The default number of seconds that the ball may go untouched before the
+/// [`PossessionCollector`](crate::PossessionCollector) stops attributing
+/// possession to the team that last touched it.
+pub static DEFAULT_POSSESSION_TIMEOUT_SECONDS: f32 = 5.0;
+
+/// The top speed of a car, in unreal units per second.
+pub static MAX_CAR_SPEED: f32 = 2300.0;
+/// Movements of a car between two rigid body updates that imply a speed
+/// greater than this are treated as teleports (e.g. resets after goals) rather
+/// than travel.
+pub static MAX_CAR_TRAVEL_SPEED: f32 = MAX_CAR_SPEED * 1.5;
+/// The speed, in unreal units per second, at or above which a car becomes
+/// supersonic.
+pub static SUPERSONIC_SPEED: f32 = 2200.0;
+/// The speed below which a supersonic car stops being supersonic. This is
+/// lower than [`SUPERSONIC_SPEED`] so that cars hovering around the threshold
+/// don't flicker in and out of being supersonic.
+pub static SUPERSONIC_EXIT_SPEED: f32 = 2100.0;
+
+/// The height of the floor of the field.
+pub static FIELD_FLOOR_Z: f32 = 0.0;
+/// The maximum height of the center of a car above [`FIELD_FLOOR_Z`] for the
+/// car to be considered on the ground. A car resting on its wheels sits at
+/// roughly 17 units.
+pub static CAR_ON_GROUND_MAX_HEIGHT: f32 = 40.0;
+/// The minimum z component of a car's up vector for the car to be considered
+/// on the ground, i.e. the car can't be tilted by more than about 45 degrees.
+pub static CAR_ON_GROUND_MIN_UP_Z: f32 = 0.7;
+/// The maximum vertical speed of a car for the car to be considered on the
+/// ground. This excludes the first frames of a jump, during which the car is
+/// still close to the floor.
+pub static CAR_ON_GROUND_MAX_VERTICAL_SPEED: f32 = 150.0;
+
+/// The range of heights of the ball above a car within which the ball may be
+/// resting on the car's roof, as required for a dribble.
+pub static DRIBBLE_MIN_HEIGHT_ABOVE_CAR: f32 = 80.0;
+pub static DRIBBLE_MAX_HEIGHT_ABOVE_CAR: f32 = 250.0;
+/// The maximum horizontal distance between the ball and a car for the ball to
+/// be considered to be carried by the car.
+pub static DRIBBLE_MAX_HORIZONTAL_DISTANCE: f32 = 150.0;
+/// The maximum speed of the ball relative to a car for the ball to be
+/// considered to be carried by the car.
+pub static DRIBBLE_MAX_RELATIVE_SPEED: f32 = 500.0;
+/// The minimum duration for which the ball needs to be carried for the carry
+/// to be recorded as a dribble.
+pub static MIN_DRIBBLE_SECONDS: f32 = 0.5;
+
+/// The maximum number of seconds a touched ball may take to reach the opposing
+/// goal for the touch to count as a [`ShotInfo`](crate::ShotInfo).
+pub static SHOT_MAX_SECONDS_TO_GOAL_LINE: f32 = 3.0;
+/// The maximum number of seconds a ball may have been from entering a team's
+/// goal for a touch by that team that redirects it to count as a
+/// [`SaveInfo`](crate::SaveInfo).
+pub static SAVE_MAX_SECONDS_TO_GOAL_LINE: f32 = 2.0;
+
+/// How long after the first touch of a kickoff the ball's velocity is sampled
+/// to determine the [`KickoffOutcome`](crate::KickoffOutcome).
+pub static KICKOFF_OUTCOME_DELAY_SECONDS: f32 = 1.0;
+pub static KICKOFF_OUTCOME_MIN_SPEED: f32 = 300.0;
+/// The maximum distance between a car and a kickoff spawn for the car to be
+/// considered to be at that [`KickoffPosition`](crate::KickoffPosition).
+pub static KICKOFF_SPAWN_MAX_DISTANCE: f32 = 300.0;
+
+/// The number of milliseconds represented by each unit of the replicated
+/// `Engine.PlayerReplicationInfo:Ping` byte, which Unreal Engine 3 compresses
+/// by dividing the ping by this amount.
+pub static PING_BYTE_MILLISECONDS: u16 = 4;
+
+/// How long it takes for a big boost pad to become available again after it
+/// has been picked up.
+pub static BIG_BOOST_PAD_RESPAWN_SECONDS: f32 = 10.0;
+/// How long it takes for a small boost pad to become available again after it
+/// has been picked up.
+pub static SMALL_BOOST_PAD_RESPAWN_SECONDS: f32 = 4.0;
+/// The radius around a big boost pad within which a car picks it up.
+pub static BIG_BOOST_PAD_PICKUP_RADIUS: f32 = 208.0;
+/// The radius around a small boost pad within which a car picks it up.
+pub static SMALL_BOOST_PAD_PICKUP_RADIUS: f32 = 144.0;
+/// The maximum distance between a car and one of the [`STANDARD_BOOST_PADS`]
+/// for a pickup made by that car to be attributed to that pad.
+pub static BOOST_PAD_MAX_MATCH_DISTANCE: f32 = 500.0;
+
+/// The `(x, y, is_big)` of every boost pad on a standard soccar field, sorted
+/// by y and then by x, so that index 0 is the small pad closest to the team
+/// zero (blue) goal and index 33 is the small pad closest to the team one
+/// (orange) goal. Feature adders that emit one value per pad use this order.
+pub static STANDARD_BOOST_PADS: [(f32, f32, bool); 34] = [
+    (0.0, -4240.0, false),
+    (-1792.0, -4184.0, false),
+    (1792.0, -4184.0, false),
+    (-3072.0, -4096.0, true),
+    (3072.0, -4096.0, true),
+    (-940.0, -3308.0, false),
+    (940.0, -3308.0, false),
+    (0.0, -2816.0, false),
+    (-3584.0, -2484.0, false),
+    (3584.0, -2484.0, false),
+    (-1788.0, -2300.0, false),
+    (1788.0, -2300.0, false),
+    (-2048.0, -1036.0, false),
+    (0.0, -1024.0, false),
+    (2048.0, -1036.0, false),
+    (-3584.0, 0.0, true),
+    (-1024.0, 0.0, false),
+    (1024.0, 0.0, false),
+    (3584.0, 0.0, true),
+    (-2048.0, 1036.0, false),
+    (0.0, 1024.0, false),
+    (2048.0, 1036.0, false),
+    (-1788.0, 2300.0, false),
+    (1788.0, 2300.0, false),
+    (-3584.0, 2484.0, false),
+    (3584.0, 2484.0, false),
+    (0.0, 2816.0, false),
+    (-940.0, 3308.0, false),
+    (940.0, 3308.0, false),
+    (-3072.0, 4096.0, true),
+    (3072.0, 4096.0, true),
+    (-1792.0, 4184.0, false),
+    (1792.0, 4184.0, false),
+    (0.0, 4240.0, false),
+];
+
+/// The distance from the center of the field to each goal line. The team zero
+/// (blue) goal line is at `-GOAL_LINE_Y` and the team one (orange) goal line
+/// is at `GOAL_LINE_Y`.
+pub static GOAL_LINE_Y: f32 = 5120.0;
+/// Half of the width of the mouth of a standard soccar goal.
+pub static GOAL_HALF_WIDTH: f32 = 892.755;
+/// The height of the crossbar of a standard soccar goal.
+pub static GOAL_HEIGHT: f32 = 642.775;
+/// The center of the mouth of the team zero (blue) goal, halfway up the goal
+/// line.
+pub static TEAM_ZERO_GOAL_CENTER: (f32, f32, f32) = (0.0, -GOAL_LINE_Y, GOAL_HEIGHT / 2.0);
+/// The center of the mouth of the team one (orange) goal, halfway up the goal
+/// line.
+pub static TEAM_ONE_GOAL_CENTER: (f32, f32, f32) = (0.0, GOAL_LINE_Y, GOAL_HEIGHT / 2.0);
+/// The distance from the center of the field to the lines that divide the
+/// field between the goal lines into thirds, see
+/// [`get_field_third`](crate::get_field_third).
+pub static FIELD_THIRD_BOUNDARY_Y: f32 = GOAL_LINE_Y / 3.0;
+/// The value emitted by [`PlayerFieldThird`](crate::PlayerFieldThird) when the
+/// player's car has no rigid body.
+pub static UNKNOWN_FIELD_THIRD_VALUE: f32 = -1.0;
+
+/// The distance along the y axis from the center of the Hoops (Dunk House)
+/// arena to the center of the rim of each hoop. As with soccar goals, the team
+/// zero (blue) hoop is at `-HOOPS_RIM_Y` and the team one (orange) hoop is at
+/// `HOOPS_RIM_Y`. Both hoops are centered on `x = 0`.
+///
+/// XXX: This and the other `HOOPS_RIM_*` values are rough approximations.
+/// They have not been measured from Hoops replays or checked against the
+/// geometry of the arena.
+pub static HOOPS_RIM_Y: f32 = 2950.0;
+/// The height of the rim of each hoop above the floor of the Hoops arena. An
+/// approximation, see [`HOOPS_RIM_Y`].
+pub static HOOPS_RIM_HEIGHT: f32 = 365.0;
+/// The radius of the (circular) rim of each hoop in the Hoops arena. An
+/// approximation, see [`HOOPS_RIM_Y`].
+pub static HOOPS_RIM_RADIUS: f32 = 350.0;
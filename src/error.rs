@@ -1,6 +1,7 @@
 use crate::*;
 use boxcars::Attribute;
-use std::backtrace::Backtrace;
+use serde::Serialize;
+use std::backtrace::{Backtrace, BacktraceStatus};
 use thiserror::Error;
 
 /// [`SubtrActorErrorVariant`] is an enumeration of all the specific error
@@ -10,6 +11,9 @@ use thiserror::Error;
 /// amongst others.
 #[derive(Error, Debug, Clone)]
 pub enum SubtrActorErrorVariant {
+    #[error("Replay could not be parsed: {0}")]
+    ReplayParseError(String),
+
     #[error("Replay has no network frames")]
     NoNetworkFrames,
 
@@ -64,6 +68,15 @@ pub enum SubtrActorErrorVariant {
     #[error("Couldn't find object id for {name}")]
     ObjectIdNotFound { name: &'static str },
 
+    #[error("{name:?} is not the name of any object in the replay")]
+    UnknownObjectName { name: String },
+
+    #[error("ActorId {actor_id:?} has no value for {name:?}")]
+    ActorAttributeNotFound {
+        actor_id: boxcars::ActorId,
+        name: String,
+    },
+
     #[error("No value found for derived key {name:?}")]
     DerivedKeyValueNotFound { name: String },
 
@@ -85,9 +98,15 @@ pub enum SubtrActorErrorVariant {
     #[error("Error returned to deliberately end processing early")]
     FinishProcessingEarly,
 
+    #[error("Collected {actual:} features, but expected {expected:}")]
+    InconsistentFeatureLength { expected: usize, actual: usize },
+
     #[error("Player stats header not found")]
     PlayerStatsHeaderNotFound,
 
+    #[error("Player stats header does not identify every player")]
+    IncompletePlayerStatsHeader,
+
     #[error("Interpolation time order was incorrect start_time {start_time:} {time:} {end_time:}")]
     InterpolationTimeOrderError {
         start_time: f32,
@@ -101,6 +120,9 @@ pub enum SubtrActorErrorVariant {
     #[error("Could not find {property:} in state")]
     PropertyNotFoundInState { property: &'static str },
 
+    #[error("Could not determine the formation of the first kickoff")]
+    InitialFormationNotFound,
+
     #[error("Could not build replay meta")]
     CouldNotBuildReplayMeta,
 
@@ -112,6 +134,73 @@ pub enum SubtrActorErrorVariant {
 
     #[error("{0:?} was not a recognized feature adder")]
     UnknownFeatureAdderName(String),
+
+    #[error("{0:?} was not a recognized player actor type")]
+    UnknownPlayerActorType(String),
+
+    #[error("The start time {start_time:} of the time window is after its end time {end_time:}")]
+    InvalidTimeWindow { start_time: f32, end_time: f32 },
+
+    #[error("The time {time:} is outside of the replay")]
+    TimeOutsideReplay { time: f32 },
+
+    #[error("The validity mask was not recorded, see NDArrayCollector::with_validity_mask")]
+    ValidityMaskNotRecorded,
+
+    #[error("Could not write streamed replay data: {0}")]
+    StreamWriteError(String),
+
+    #[error("Feature adders can not produce data for this replay: {0:?}")]
+    IncompatibleFeatureAdders(Vec<IncompatibleFeatureAdder>),
+}
+
+impl SubtrActorErrorVariant {
+    /// Returns a short identifier for the variant, such as
+    /// `"no_network_frames"`, that doesn't depend on any of its fields. Unlike
+    /// the [`std::fmt::Display`] and [`std::fmt::Debug`] output, these codes
+    /// are stable, so that bindings can map errors to their own error types.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ReplayParseError(_) => "replay_parse_error",
+            Self::NoNetworkFrames => "no_network_frames",
+            Self::FrameIndexOutOfBounds => "frame_index_out_of_bounds",
+            Self::InconsistentPlayerSet { .. } => "inconsistent_player_set",
+            Self::NoUpdateAfterFrame { .. } => "no_update_after_frame",
+            Self::NoBoostAmountValue => "no_boost_amount_value",
+            Self::UnexpectedAttributeType { .. } => "unexpected_attribute_type",
+            Self::NoMatchingPlayerId { .. } => "no_matching_player_id",
+            Self::NoGameActor => "no_game_actor",
+            Self::ActorIdAlreadyExists { .. } => "actor_id_already_exists",
+            Self::ActorNotFound { .. } => "actor_not_found",
+            Self::NoStateForActorId { .. } => "no_state_for_actor_id",
+            Self::ObjectIdNotFound { .. } => "object_id_not_found",
+            Self::UnknownObjectName { .. } => "unknown_object_name",
+            Self::ActorAttributeNotFound { .. } => "actor_attribute_not_found",
+            Self::DerivedKeyValueNotFound { .. } => "derived_key_value_not_found",
+            Self::BallActorNotFound => "ball_actor_not_found",
+            Self::UnknownPlayerTeam { .. } => "unknown_player_team",
+            Self::UnknownTeamObjectId { .. } => "unknown_team_object_id",
+            Self::EmptyTeamName { .. } => "empty_team_name",
+            Self::FinishProcessingEarly => "finish_processing_early",
+            Self::InconsistentFeatureLength { .. } => "inconsistent_feature_length",
+            Self::PlayerStatsHeaderNotFound => "player_stats_header_not_found",
+            Self::IncompletePlayerStatsHeader => "incomplete_player_stats_header",
+            Self::InterpolationTimeOrderError { .. } => "interpolation_time_order_error",
+            Self::UpdatedActorIdDoesNotExist { .. } => "updated_actor_id_does_not_exist",
+            Self::PropertyNotFoundInState { .. } => "property_not_found_in_state",
+            Self::InitialFormationNotFound => "initial_formation_not_found",
+            Self::CouldNotBuildReplayMeta => "could_not_build_replay_meta",
+            Self::FloatConversionError => "float_conversion_error",
+            Self::NDArrayShapeError(_) => "ndarray_shape_error",
+            Self::UnknownFeatureAdderName(_) => "unknown_feature_adder_name",
+            Self::UnknownPlayerActorType(_) => "unknown_player_actor_type",
+            Self::InvalidTimeWindow { .. } => "invalid_time_window",
+            Self::TimeOutsideReplay { .. } => "time_outside_replay",
+            Self::ValidityMaskNotRecorded => "validity_mask_not_recorded",
+            Self::StreamWriteError(_) => "stream_write_error",
+            Self::IncompatibleFeatureAdders(_) => "incompatible_feature_adders",
+        }
+    }
 }
 
 /// [`SubtrActorError`] struct provides an error variant
@@ -133,6 +222,28 @@ impl SubtrActorError {
     pub fn new_result<T>(variant: SubtrActorErrorVariant) -> Result<T, Self> {
         Err(Self::new(variant))
     }
+
+    /// Returns a [`SubtrActorErrorPayload`] describing this error, for
+    /// bindings that need to pass errors on in a structured form.
+    pub fn to_error_payload(&self) -> SubtrActorErrorPayload {
+        SubtrActorErrorPayload {
+            code: self.variant.code(),
+            message: self.variant.to_string(),
+            has_backtrace: self.backtrace.status() == BacktraceStatus::Captured,
+        }
+    }
+}
+
+/// A serializable summary of a [`SubtrActorError`], see
+/// [`SubtrActorError::to_error_payload`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubtrActorErrorPayload {
+    /// The [`SubtrActorErrorVariant::code`] of the error.
+    pub code: &'static str,
+    /// The human readable message of the error.
+    pub message: String,
+    /// Whether a backtrace was captured when the error was created.
+    pub has_backtrace: bool,
 }
 
 pub type SubtrActorResult<T> = Result<T, SubtrActorError>;
@@ -118,7 +118,7 @@
 //!             InterpolatedPlayerRigidBodyNoVelocities::arc_new(0.003),
 //!             PlayerBoost::arc_new(),
 //!             PlayerAnyJump::arc_new(),
-//!             PlayerDemolishedBy::arc_new(),
+//!             PlayerDemolishedBy::arc_new(DEFAULT_UNKNOWN_DEMOLISHER_VALUE),
 //!         ],
 //!         Some(30.0),
 //!     )
@@ -133,7 +133,9 @@
 //! [`crate::collector::ndarray`] module. It is also possible to access these
 //! feature adders by name with strings, which can be useful when implementing
 //! bindings for other languages since those languages may not be able to access
-//! rust structs an instantiate them easily or at all.
+//! rust structs an instantiate them easily or at all. The same names can be
+//! used with `NDArrayCollector::<f64>::from_strings` to collect the features
+//! at double precision.
 //!
 //! ```
 //! pub static DEFAULT_GLOBAL_FEATURE_ADDERS: [&str; 1] = ["BallRigidBody"];
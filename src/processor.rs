@@ -1,6 +1,6 @@
 use crate::*;
 use boxcars;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Attempts to match an attribute value with the given type.
 ///
@@ -110,6 +110,76 @@ fn use_update_actor<T>(id: boxcars::ActorId, _: T) -> boxcars::ActorId {
     id
 }
 
+/// A network frame yielded by a [`FrameIterator`], see
+/// [`ReplayProcessor::frames_iter`].
+#[derive(Debug, Clone, Copy)]
+pub struct FrameContext<'a> {
+    /// The frame, borrowed from the replay rather than the processor, so it
+    /// remains usable while the processor advances.
+    pub frame: &'a boxcars::Frame,
+    /// The index of the frame in the replay's network frames.
+    pub frame_number: usize,
+    /// The time of the frame.
+    pub current_time: f32,
+}
+
+/// An [`Iterator`] over the network frames of a replay, created by
+/// [`ReplayProcessor::frames_iter`].
+///
+/// Each call to [`Iterator::next`] updates the state of the underlying
+/// [`ReplayProcessor`] with the next frame before yielding it, so the values
+/// returned by [`Self::processor`] always describe the most recently yielded
+/// frame. Once an error has been yielded the iterator is exhausted.
+pub struct FrameIterator<'a, 'b> {
+    processor: &'b mut ReplayProcessor<'a>,
+    frames: Option<std::iter::Enumerate<std::slice::Iter<'a, boxcars::Frame>>>,
+    done: bool,
+}
+
+impl<'a, 'b> FrameIterator<'a, 'b> {
+    /// Returns the [`ReplayProcessor`], with its state as of the most
+    /// recently yielded frame.
+    pub fn processor(&self) -> &ReplayProcessor<'a> {
+        self.processor
+    }
+}
+
+impl<'a, 'b> Iterator for FrameIterator<'a, 'b> {
+    type Item = SubtrActorResult<FrameContext<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let frames = match self.frames.as_mut() {
+            Some(frames) => frames,
+            None => {
+                self.done = true;
+                return Some(SubtrActorError::new_result(
+                    SubtrActorErrorVariant::NoNetworkFrames,
+                ));
+            }
+        };
+        for (index, frame) in frames.by_ref() {
+            if let Err(error) = self.processor.update_for_frame(frame, index) {
+                self.done = true;
+                return Some(Err(error));
+            }
+            if self.processor.skip_warmup && self.processor.is_warmup_frame(frame) {
+                continue;
+            }
+            return Some(Ok(FrameContext {
+                frame,
+                frame_number: index,
+                current_time: frame.time,
+            }));
+        }
+        self.done = true;
+        self.processor.finish_active_dribble();
+        None
+    }
+}
+
 /// The [`ReplayProcessor`] struct is a pivotal component in `subtr-actor`'s
 /// replay parsing pipeline. It is designed to process and traverse an actor
 /// graph of a Rocket League replay, and expose methods for collectors to gather
@@ -147,8 +217,10 @@ pub struct ReplayProcessor<'a> {
     pub object_id_to_name: HashMap<boxcars::ObjectId, String>,
     pub name_to_object_id: HashMap<String, boxcars::ObjectId>,
     pub ball_actor_id: Option<boxcars::ActorId>,
+    pub ball_actor_ids: Vec<boxcars::ActorId>,
     pub team_zero: Vec<PlayerId>,
     pub team_one: Vec<PlayerId>,
+    pub spectators: Vec<PlayerId>,
     pub player_to_actor_id: HashMap<PlayerId, boxcars::ActorId>,
     pub player_to_car: HashMap<boxcars::ActorId, boxcars::ActorId>,
     pub player_to_team: HashMap<boxcars::ActorId, boxcars::ActorId>,
@@ -156,11 +228,69 @@ pub struct ReplayProcessor<'a> {
     pub car_to_jump: HashMap<boxcars::ActorId, boxcars::ActorId>,
     pub car_to_double_jump: HashMap<boxcars::ActorId, boxcars::ActorId>,
     pub car_to_dodge: HashMap<boxcars::ActorId, boxcars::ActorId>,
+    pub player_to_camera_settings: HashMap<boxcars::ActorId, boxcars::ActorId>,
     pub demolishes: Vec<DemolishInfo>,
     known_demolishes: Vec<(boxcars::DemolishFx, usize)>,
+    pub goals: Vec<GoalInfo>,
+    team_scores: [i32; 2],
+    pub ball_touches: Vec<BallTouchInfo>,
+    pub shots: Vec<ShotInfo>,
+    pub saves: Vec<SaveInfo>,
+    pub dribbles: Vec<DribbleInfo>,
+    pub boost_pickups: Vec<BoostPickupEvent>,
+    distance_traveled: HashMap<PlayerId, f32>,
+    supersonic_players: HashSet<PlayerId>,
+    last_car_locations: HashMap<PlayerId, (boxcars::ActorId, glam::Vec3, f32)>,
+    active_dribble: Option<DribbleInfo>,
+    kickoff_results: Vec<KickoffResult>,
+    initial_formation: Option<Vec<(PlayerId, KickoffPosition)>>,
+    boost_pad_actor_to_index: HashMap<boxcars::ActorId, usize>,
+    boost_pad_pickup_times: Vec<Option<f32>>,
+    seconds_remaining_clock: Option<SecondsRemainingClock>,
+    skip_warmup: bool,
+}
+
+/// Controls when the CRC of a replay is verified while it is parsed by
+/// [`ReplayProcessor::parse_replay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrcMode {
+    /// Always verify the CRC, and fail if it doesn't match.
+    Always,
+    /// Only verify the CRC when parsing fails, to report corruption as the
+    /// cause of the failure.
+    OnError,
+    /// Never verify the CRC.
+    Never,
 }
 
 impl<'a> ReplayProcessor<'a> {
+    /// Parses the replay contained in `data`, including its network data,
+    /// verifying its CRC according to `crc_mode`.
+    ///
+    /// A [`ReplayProcessor`] borrows the replay it processes, so the returned
+    /// replay should be kept alive and passed to [`Self::new`]:
+    ///
+    /// ```no_run
+    /// use subtr_actor::*;
+    ///
+    /// # fn f(data: &[u8]) -> SubtrActorResult<()> {
+    /// let replay = ReplayProcessor::parse_replay(data, CrcMode::OnError)?;
+    /// let mut processor = ReplayProcessor::new(&replay)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_replay(data: &[u8], crc_mode: CrcMode) -> SubtrActorResult<boxcars::Replay> {
+        let builder = boxcars::ParserBuilder::new(data).must_parse_network_data();
+        let builder = match crc_mode {
+            CrcMode::Always => builder.always_check_crc(),
+            CrcMode::OnError => builder.on_error_check_crc(),
+            CrcMode::Never => builder.never_check_crc(),
+        };
+        builder.parse().map_err(|e| {
+            SubtrActorError::new(SubtrActorErrorVariant::ReplayParseError(e.to_string()))
+        })
+    }
+
     /// Constructs a new [`ReplayProcessor`] instance with the provided replay.
     ///
     /// # Arguments
@@ -175,6 +305,41 @@ impl<'a> ReplayProcessor<'a> {
     /// attribute maps. - Sets the player order from either replay headers or
     /// frames, if available.
     pub fn new(replay: &'a boxcars::Replay) -> SubtrActorResult<Self> {
+        let mut processor = Self::new_without_player_order(replay);
+        processor
+            .set_player_order_from_headers()
+            .or_else(|_| processor.set_player_order_from_frames())?;
+
+        Ok(processor)
+    }
+
+    /// Constructs a new [`ReplayProcessor`] for `replay`, a later segment of
+    /// a match that was split across several replay files, reusing the
+    /// players and player order that `previous` determined for an earlier
+    /// segment.
+    ///
+    /// Unlike [`Self::new`], this doesn't derive the player order from the
+    /// headers or frames of `replay`, so the columns of players are laid out
+    /// the same way for every segment. All other state, including
+    /// [`Self::actor_state`] and the actor ids of players, is fresh, since
+    /// actor ids are not stable across replay files. This differs from
+    /// [`Self::reset`], which keeps the replay but discards everything that
+    /// was discovered while processing it. Settings such as
+    /// [`Self::set_skip_warmup`] are carried over from `previous`.
+    ///
+    /// This assumes that the same players are present in every segment. If
+    /// they aren't, [`Self::process`] fails with
+    /// [`SubtrActorErrorVariant::InconsistentPlayerSet`].
+    pub fn resume_from(previous: &ReplayProcessor, replay: &'a boxcars::Replay) -> Self {
+        let mut processor = Self::new_without_player_order(replay);
+        processor.team_zero = previous.team_zero.clone();
+        processor.team_one = previous.team_one.clone();
+        processor.spectators = previous.spectators.clone();
+        processor.skip_warmup = previous.skip_warmup;
+        processor
+    }
+
+    fn new_without_player_order(replay: &'a boxcars::Replay) -> Self {
         let mut object_id_to_name = HashMap::new();
         let mut name_to_object_id = HashMap::new();
         for (id, name) in replay.objects.iter().enumerate() {
@@ -182,14 +347,16 @@ impl<'a> ReplayProcessor<'a> {
             object_id_to_name.insert(object_id, name.clone());
             name_to_object_id.insert(name.clone(), object_id);
         }
-        let mut processor = Self {
+        Self {
             actor_state: ActorStateModeler::new(),
             replay,
             object_id_to_name,
             name_to_object_id,
             team_zero: Vec::new(),
             team_one: Vec::new(),
+            spectators: Vec::new(),
             ball_actor_id: None,
+            ball_actor_ids: Vec::new(),
             player_to_car: HashMap::new(),
             player_to_team: HashMap::new(),
             player_to_actor_id: HashMap::new(),
@@ -197,14 +364,27 @@ impl<'a> ReplayProcessor<'a> {
             car_to_jump: HashMap::new(),
             car_to_double_jump: HashMap::new(),
             car_to_dodge: HashMap::new(),
+            player_to_camera_settings: HashMap::new(),
             demolishes: Vec::new(),
             known_demolishes: Vec::new(),
-        };
-        processor
-            .set_player_order_from_headers()
-            .or_else(|_| processor.set_player_order_from_frames())?;
-
-        Ok(processor)
+            goals: Vec::new(),
+            team_scores: [0, 0],
+            ball_touches: Vec::new(),
+            shots: Vec::new(),
+            saves: Vec::new(),
+            dribbles: Vec::new(),
+            boost_pickups: Vec::new(),
+            distance_traveled: HashMap::new(),
+            supersonic_players: HashSet::new(),
+            last_car_locations: HashMap::new(),
+            active_dribble: None,
+            kickoff_results: Vec::new(),
+            initial_formation: None,
+            boost_pad_actor_to_index: HashMap::new(),
+            boost_pad_pickup_times: vec![None; STANDARD_BOOST_PADS.len()],
+            seconds_remaining_clock: None,
+            skip_warmup: false,
+        }
     }
 
     /// [`Self::process`] takes a [`Collector`] as an argument and iterates over
@@ -229,26 +409,47 @@ impl<'a> ReplayProcessor<'a> {
     /// were encountered during the replay. If any unknown players are found, an
     /// error is returned.
     pub fn process<H: Collector>(&mut self, handler: &mut H) -> SubtrActorResult<()> {
+        self.process_between(handler, None, None)
+    }
+
+    /// Like [`Self::process`], but [`Collector::process_frame`] is only
+    /// invoked for frames whose time lies within `[start_time, end_time]`,
+    /// inclusive at both ends. A bound of `None` leaves that end of the window
+    /// open. The state of the processor is still updated with every frame of
+    /// the replay, so it is correct once the window is reached.
+    ///
+    /// Returns [`SubtrActorErrorVariant::InvalidTimeWindow`] if `start_time`
+    /// is greater than `end_time`.
+    pub fn process_between<H: Collector>(
+        &mut self,
+        handler: &mut H,
+        start_time: Option<f32>,
+        end_time: Option<f32>,
+    ) -> SubtrActorResult<()> {
+        if let (Some(start_time), Some(end_time)) = (start_time, end_time) {
+            if start_time > end_time {
+                return SubtrActorError::new_result(SubtrActorErrorVariant::InvalidTimeWindow {
+                    start_time,
+                    end_time,
+                });
+            }
+        }
+        let end_time = end_time.unwrap_or(f32::INFINITY);
         // Initially, we set target_time to NextFrame to ensure the collector
         // will process the first frame.
         let mut target_time = TimeAdvance::NextFrame;
-        for (index, frame) in self
-            .replay
-            .network_frames
-            .as_ref()
-            .ok_or(SubtrActorError::new(
-                SubtrActorErrorVariant::NoNetworkFrames,
-            ))?
-            .frames
-            .iter()
-            .enumerate()
-        {
-            // Update the internal state of the processor based on the current frame
-            self.actor_state.process_frame(frame, index)?;
-            self.update_mappings(frame)?;
-            self.update_ball_id(frame)?;
-            self.update_boost_amounts(frame, index)?;
-            self.update_demolishes(frame, index)?;
+        let mut frames = self.frames_iter();
+        while let Some(context) = frames.next() {
+            let FrameContext {
+                frame,
+                frame_number,
+                ..
+            } = context?;
+
+            if start_time.map(|start| frame.time < start).unwrap_or(false) || frame.time > end_time
+            {
+                continue;
+            }
 
             // Get the time to process for this frame. If target_time is set to
             // NextFrame, we use the time of the current frame.
@@ -257,10 +458,11 @@ impl<'a> ReplayProcessor<'a> {
                 TimeAdvance::NextFrame => frame.time,
             };
 
-            while current_time <= frame.time {
+            while current_time <= frame.time && current_time <= end_time {
                 // Call the handler to process the frame and get the time for
                 // the next frame the handler wants to process
-                target_time = handler.process_frame(&self, frame, index, current_time)?;
+                target_time =
+                    handler.process_frame(frames.processor(), frame, frame_number, current_time)?;
                 // If the handler specified a specific time, update current_time
                 // to that time. If the handler specified NextFrame, we break
                 // out of the loop to move on to the next frame in the replay.
@@ -273,6 +475,9 @@ impl<'a> ReplayProcessor<'a> {
                 }
             }
         }
+        // Record a dribble that was still running at the last frame, however
+        // the iteration ended.
+        self.finish_active_dribble();
         // TODO: This should probably not be mandatory. Also it really only
         // checks that the set is the same at the end as it was in the
         // beggining. There could still be issue in the intervening frames that
@@ -282,31 +487,221 @@ impl<'a> ReplayProcessor<'a> {
         self.check_player_id_set()
     }
 
+    /// Returns a [`FrameIterator`] which updates the state of the processor
+    /// with one network frame on each call to [`Iterator::next`], and yields a
+    /// [`FrameContext`] describing that frame. This is a pull based
+    /// alternative to [`Self::process`], which makes it possible to stop
+    /// processing at any point by simply no longer calling
+    /// [`Iterator::next`]. Warmup frames are skipped as they are by
+    /// [`Self::process`], see [`Self::set_skip_warmup`].
+    ///
+    /// The iterator holds the mutable borrow of the processor, so the state
+    /// of the processor is queried through [`FrameIterator::processor`]
+    /// between calls to [`Iterator::next`]:
+    ///
+    /// ```no_run
+    /// use subtr_actor::*;
+    ///
+    /// # fn f(replay: &boxcars::Replay) -> SubtrActorResult<()> {
+    /// let mut processor = ReplayProcessor::new(replay)?;
+    /// let mut frames = processor.frames_iter();
+    /// while let Some(context) = frames.next() {
+    ///     let context = context?;
+    ///     let ball = frames.processor().get_ball_rigid_body()?;
+    ///     if context.current_time > 60.0 {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Unlike [`Self::process`], it does not check that the set of players
+    /// was unchanged once all the frames have been yielded.
+    pub fn frames_iter(&mut self) -> FrameIterator<'a, '_> {
+        let frames = self
+            .replay
+            .network_frames
+            .as_ref()
+            .map(|network_frames| network_frames.frames.iter().enumerate());
+        FrameIterator {
+            processor: self,
+            frames,
+            done: false,
+        }
+    }
+
+    fn update_for_frame(&mut self, frame: &boxcars::Frame, index: usize) -> SubtrActorResult<()> {
+        self.actor_state.process_frame(frame, index)?;
+        self.update_mappings(frame)?;
+        self.update_ball_id(frame)?;
+        self.update_boost_amounts(frame, index)?;
+        self.update_demolishes(frame, index)?;
+        self.update_ball_touches(frame, index)?;
+        self.update_goals(frame, index);
+        self.update_kickoffs(frame, index)?;
+        self.update_initial_formation();
+        self.update_dribbles(frame, index);
+        self.update_distance_traveled(frame, index);
+        self.update_supersonic_players();
+        self.update_boost_pads(frame, index)?;
+        self.update_seconds_remaining_clock(frame);
+        Ok(())
+    }
+
+    /// Controls whether [`Self::process`] invokes its [`Collector`] on warmup
+    /// frames, i.e. frames with a negative time or that precede the creation
+    /// of the game event actor. The state of the processor is still updated
+    /// with these frames, so they remain available as anchors for
+    /// interpolation.
+    pub fn set_skip_warmup(&mut self, skip_warmup: bool) {
+        self.skip_warmup = skip_warmup;
+    }
+
+    fn is_warmup_frame(&self, frame: &boxcars::Frame) -> bool {
+        frame.time < 0.0 || self.get_seconds_remaining().is_err()
+    }
+
     /// Reset the state of the [`ReplayProcessor`].
+    ///
+    /// Collections are cleared rather than reallocated, so the capacity built
+    /// up while processing a replay is retained.
     pub fn reset(&mut self) {
-        self.player_to_car = HashMap::new();
-        self.player_to_team = HashMap::new();
-        self.player_to_actor_id = HashMap::new();
-        self.car_to_boost = HashMap::new();
-        self.car_to_jump = HashMap::new();
-        self.car_to_double_jump = HashMap::new();
-        self.car_to_dodge = HashMap::new();
-        self.actor_state = ActorStateModeler::new();
-        self.demolishes = Vec::new();
-        self.known_demolishes = Vec::new();
+        self.player_to_car.clear();
+        self.player_to_team.clear();
+        self.player_to_actor_id.clear();
+        self.car_to_boost.clear();
+        self.car_to_jump.clear();
+        self.car_to_double_jump.clear();
+        self.car_to_dodge.clear();
+        self.player_to_camera_settings.clear();
+        self.actor_state.clear();
+        self.ball_actor_id = None;
+        self.ball_actor_ids.clear();
+        self.demolishes.clear();
+        self.known_demolishes.clear();
+        self.goals.clear();
+        self.team_scores = [0, 0];
+        self.ball_touches.clear();
+        self.shots.clear();
+        self.saves.clear();
+        self.dribbles.clear();
+        self.boost_pickups.clear();
+        self.distance_traveled.clear();
+        self.supersonic_players.clear();
+        self.last_car_locations.clear();
+        self.active_dribble = None;
+        self.kickoff_results.clear();
+        self.initial_formation = None;
+        self.boost_pad_actor_to_index.clear();
+        self.boost_pad_pickup_times.clear();
+        self.boost_pad_pickup_times
+            .resize(STANDARD_BOOST_PADS.len(), None);
+        self.seconds_remaining_clock = None;
+    }
+
+    /// Rebinds the [`ReplayProcessor`] to `replay`, discarding all state from
+    /// the replay it was previously bound to.
+    ///
+    /// This produces a processor equivalent to one returned by
+    /// [`Self::new`], but reuses the maps and vectors that have already been
+    /// allocated, which avoids repeated allocation when processing many
+    /// replays in a row. Settings such as [`Self::set_skip_warmup`] are kept.
+    ///
+    /// Every replay the processor is bound to is borrowed for the lifetime
+    /// `'a` of the processor, so all of them must outlive it. A replay that is
+    /// parsed and dropped within each iteration of a loop can't be rebound
+    /// this way; the replays have to be parsed up front instead:
+    ///
+    /// ```no_run
+    /// use subtr_actor::*;
+    ///
+    /// # fn f(files: &[Vec<u8>]) -> SubtrActorResult<()> {
+    /// let replays = files
+    ///     .iter()
+    ///     .map(|data| ReplayProcessor::parse_replay(data, CrcMode::OnError))
+    ///     .collect::<SubtrActorResult<Vec<_>>>()?;
+    /// let mut processor = ReplayProcessor::new(&replays[0])?;
+    /// for replay in replays.iter() {
+    ///     processor.reuse_for(replay)?;
+    ///     let mut collector = NDArrayCollector::<f32>::default();
+    ///     processor.process(&mut collector)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reuse_for(&mut self, replay: &'a boxcars::Replay) -> SubtrActorResult<()> {
+        self.replay = replay;
+        self.object_id_to_name.clear();
+        self.name_to_object_id.clear();
+        for (id, name) in replay.objects.iter().enumerate() {
+            let object_id = boxcars::ObjectId(id as i32);
+            self.object_id_to_name.insert(object_id, name.clone());
+            self.name_to_object_id.insert(name.clone(), object_id);
+        }
+        self.team_zero.clear();
+        self.team_one.clear();
+        self.spectators.clear();
+        self.reset();
+        self.set_player_order_from_headers()
+            .or_else(|_| self.set_player_order_from_frames())
     }
 
+    /// Determines the players of the replay, and the team each of them
+    /// belongs to, from the `PlayerStats` header, which avoids processing any
+    /// frames. See [`get_player_order_from_headers`] for when the header is
+    /// considered complete enough to be used.
+    ///
+    /// The header only lists the participants that were present at the end of
+    /// the match, so it is checked against the participants whose unique ids
+    /// appear in the frames, see [`Self::get_player_ids_in_frames`]. If a
+    /// player left early, or there were spectators, the two disagree and
+    /// [`SubtrActorErrorVariant::InconsistentPlayerSet`] is returned, so that
+    /// the order is determined from the frames instead.
     fn set_player_order_from_headers(&mut self) -> SubtrActorResult<()> {
-        let _player_stats = self
-            .replay
-            .properties
-            .iter()
-            .find(|(key, _)| key == "PlayerStats")
+        get_player_stats_header(&self.replay.properties).ok_or_else(|| {
+            SubtrActorError::new(SubtrActorErrorVariant::PlayerStatsHeaderNotFound)
+        })?;
+        let (team_zero, team_one) = get_player_order_from_headers(&self.replay.properties)
             .ok_or_else(|| {
-                SubtrActorError::new(SubtrActorErrorVariant::PlayerStatsHeaderNotFound)
+                SubtrActorError::new(SubtrActorErrorVariant::IncompletePlayerStatsHeader)
             })?;
-        // XXX: implementation incomplete
-        SubtrActorError::new_result(SubtrActorErrorVariant::PlayerStatsHeaderNotFound)
+        let header_players: std::collections::HashSet<PlayerId> =
+            team_zero.iter().chain(team_one.iter()).cloned().collect();
+        let frame_players = self.get_player_ids_in_frames();
+        if header_players != frame_players {
+            return SubtrActorError::new_result(SubtrActorErrorVariant::InconsistentPlayerSet {
+                found: frame_players,
+                original: header_players,
+            });
+        }
+        self.team_zero = team_zero;
+        self.team_one = team_one;
+        Ok(())
+    }
+
+    /// Returns the ids of every participant, including spectators, whose
+    /// unique id is replicated anywhere in the network frames. Unlike
+    /// [`Self::process_long_enough_to_get_actor_ids`], this only scans the
+    /// attribute updates of the frames, without processing them.
+    fn get_player_ids_in_frames(&self) -> std::collections::HashSet<PlayerId> {
+        let unique_id_object_id = match self.name_to_object_id.get(UNIQUE_ID_KEY) {
+            Some(object_id) => *object_id,
+            None => return std::collections::HashSet::new(),
+        };
+        self.replay
+            .network_frames
+            .iter()
+            .flat_map(|network_frames| network_frames.frames.iter())
+            .flat_map(|frame| frame.updated_actors.iter())
+            .filter(|update| update.object_id == unique_id_object_id)
+            .filter_map(|update| match &update.attribute {
+                boxcars::Attribute::UniqueId(unique_id) => {
+                    Some(get_player_id_from_unique_id(update.actor_id, unique_id))
+                }
+                _ => None,
+            })
+            .collect()
     }
 
     /// Processes the replay until it has gathered enough information to map
@@ -315,8 +710,8 @@ impl<'a> ReplayProcessor<'a> {
     /// This function is designed to ensure that each player that participated
     /// in the game is associated with a corresponding actor ID. It runs the
     /// processing operation for approximately the first 10 seconds of the
-    /// replay (10 * 30 frames), as this time span is generally sufficient to
-    /// identify all players.
+    /// replay ([`ACTOR_ID_DISCOVERY_FRAMES`] frames), as this time span is
+    /// generally sufficient to identify all players.
     ///
     /// Note that this function is particularly necessary because the headers of
     /// replays sometimes omit some players.
@@ -328,7 +723,7 @@ impl<'a> ReplayProcessor<'a> {
     pub fn process_long_enough_to_get_actor_ids(&mut self) -> SubtrActorResult<()> {
         let mut handler = |_p: &ReplayProcessor, _f: &boxcars::Frame, n: usize, _current_time| {
             // XXX: 10 seconds should be enough to find everyone, right?
-            if n > 10 * 30 {
+            if n > ACTOR_ID_DISCOVERY_FRAMES {
                 SubtrActorError::new_result(SubtrActorErrorVariant::FinishProcessingEarly)
             } else {
                 Ok(TimeAdvance::NextFrame)
@@ -344,12 +739,38 @@ impl<'a> ReplayProcessor<'a> {
         }
     }
 
+    /// Determines the players of the replay, and the team each of them
+    /// belongs to, by processing its first frames.
+    ///
+    /// Only player replication actors that have both been assigned to a team
+    /// and been linked to a car within those frames are considered players.
+    /// Any others, such as spectators and observers in private matches and
+    /// tournaments, are recorded in [`Self::spectators`] and are excluded from
+    /// [`Self::iter_player_ids_in_order`].
+    ///
+    /// Only the first [`ACTOR_ID_DISCOVERY_FRAMES`] frames are considered, so
+    /// a player who joins a team, or first gets a car, after them is counted
+    /// as a spectator for the whole replay.
     fn set_player_order_from_frames(&mut self) -> SubtrActorResult<()> {
         self.process_long_enough_to_get_actor_ids()?;
-        let result: Result<HashMap<PlayerId, bool>, _> = self
+        let (players, spectators): (Vec<_>, Vec<_>) = self
             .player_to_actor_id
-            .keys()
-            .map(|player_id| Ok((player_id.clone(), self.get_player_is_team_0(player_id)?)))
+            .iter()
+            .map(|(player_id, actor_id)| (player_id.clone(), *actor_id))
+            .partition(|(player_id, actor_id)| {
+                self.player_to_car.contains_key(actor_id)
+                    && self.get_player_is_team_0(player_id).is_ok()
+            });
+        self.spectators = spectators
+            .into_iter()
+            .map(|(player_id, _)| player_id)
+            .collect();
+        self.spectators
+            .sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+
+        let result: Result<HashMap<PlayerId, bool>, _> = players
+            .iter()
+            .map(|(player_id, _)| Ok((player_id.clone(), self.get_player_is_team_0(player_id)?)))
             .collect();
 
         let player_to_team_0 = result?;
@@ -373,8 +794,11 @@ impl<'a> ReplayProcessor<'a> {
     }
 
     pub fn check_player_id_set(&self) -> SubtrActorResult<()> {
-        let known_players =
-            std::collections::HashSet::<_>::from_iter(self.player_to_actor_id.keys());
+        let known_players = std::collections::HashSet::<_>::from_iter(
+            self.player_to_actor_id
+                .keys()
+                .filter(|player_id| !self.spectators.contains(player_id)),
+        );
         let original_players =
             std::collections::HashSet::<_>::from_iter(self.iter_player_ids_in_order());
 
@@ -410,32 +834,37 @@ impl<'a> ReplayProcessor<'a> {
     /// statistics. All this information is then wrapped into a [`ReplayMeta`]
     /// object along with the properties from the replay.
     pub fn get_replay_meta(&self) -> SubtrActorResult<ReplayMeta> {
-        let empty_player_stats = Vec::new();
-        let player_stats = if let Some((_, boxcars::HeaderProp::Array(per_player))) = self
-            .replay
-            .properties
-            .iter()
-            .find(|(key, _)| key == "PlayerStats")
-        {
-            per_player
-        } else {
-            &empty_player_stats
-        };
-        let known_count = self.iter_player_ids_in_order().count();
-        if player_stats.len() != known_count {
-            log::warn!(
-                "Replay does not have player stats for all players. encountered {:?} {:?}",
-                known_count,
-                player_stats.len()
-            )
+        // Custom and private matches frequently lack the PlayerStats header
+        // entirely. That is an expected case, so stats are simply left as
+        // None and names are taken from the actor state as usual.
+        let player_stats = get_player_stats_header(&self.replay.properties);
+        if let Some(player_stats) = player_stats {
+            let known_count = self.iter_player_ids_in_order().count();
+            if player_stats.len() != known_count {
+                log::warn!(
+                    "Replay does not have player stats for all players. encountered {:?} {:?}",
+                    known_count,
+                    player_stats.len()
+                )
+            }
         }
         let get_player_info = |player_id| {
             let name = self.get_player_name(player_id)?;
-            let stats = find_player_stats(player_id, &name, player_stats).ok();
+            let stats = player_stats
+                .and_then(|player_stats| find_player_stats(player_id, &name, player_stats).ok());
+            let is_bot = stats
+                .as_ref()
+                .and_then(get_stats_is_bot)
+                .or_else(|| self.get_player_is_bot(player_id).ok())
+                .unwrap_or(false);
+            let platform = get_platform_name(player_id, stats.as_ref());
             Ok(PlayerInfo {
                 name,
                 stats,
                 remote_id: player_id.clone(),
+                is_bot,
+                camera_settings: self.get_player_camera_settings(player_id).ok(),
+                platform,
             })
         };
         let team_zero: SubtrActorResult<Vec<PlayerInfo>> =
@@ -446,9 +875,59 @@ impl<'a> ReplayProcessor<'a> {
             team_zero: team_zero?,
             team_one: team_one?,
             all_headers: self.replay.properties.clone(),
+            engine_version: EngineVersion::from_replay(self.replay),
+            spectator_count: self.spectators.len(),
+            team_zero_name: self.get_team_name(true)?,
+            team_one_name: self.get_team_name(false)?,
         })
     }
 
+    /// Returns a representative duration, in seconds, between consecutive
+    /// network frames of the replay. The median of the deltas is used so that
+    /// occasional large gaps, such as those caused by pauses or lag, don't
+    /// skew the result. Returns `0.0` if the replay has fewer than two frames.
+    pub fn average_frame_delta(&self) -> f32 {
+        let mut deltas: Vec<f32> = self
+            .replay
+            .network_frames
+            .as_ref()
+            .map(|network_frames| {
+                network_frames
+                    .frames
+                    .windows(2)
+                    .map(|pair| pair[1].time - pair[0].time)
+                    .collect()
+            })
+            .unwrap_or_default();
+        median(&mut deltas).unwrap_or(0.0)
+    }
+
+    /// Returns the frame rate declared by the `RecordFPS` header, if present.
+    pub fn get_record_fps(&self) -> Option<f32> {
+        self.replay
+            .properties
+            .iter()
+            .find_map(|(key, prop)| match prop {
+                boxcars::HeaderProp::Float(fps) if key == "RecordFPS" => Some(*fps),
+                _ => None,
+            })
+    }
+
+    /// Returns whether the frame rate implied by [`Self::average_frame_delta`]
+    /// differs from [`Self::get_record_fps`] by more than `tolerance`,
+    /// expressed as a fraction of the declared frame rate (see
+    /// [`DEFAULT_FRAME_RATE_MISMATCH_TOLERANCE`]). A mismatch usually
+    /// indicates a laggy or corrupted recording. Returns `None` when the
+    /// replay has no usable `RecordFPS` header or too few frames to compare.
+    pub fn has_frame_rate_mismatch(&self, tolerance: f32) -> Option<bool> {
+        let record_fps = self.get_record_fps().filter(|fps| *fps > 0.0)?;
+        let frame_delta = self.average_frame_delta();
+        if frame_delta <= 0.0 {
+            return None;
+        }
+        Some(((1.0 / frame_delta) - record_fps).abs() / record_fps > tolerance)
+    }
+
     /// Searches for the next or previous update for a specified actor and
     /// object in the replay's network frames.
     ///
@@ -518,6 +997,113 @@ impl<'a> ReplayProcessor<'a> {
         }
     }
 
+    /// Returns every update to the attribute or object named `object_name`
+    /// (e.g. `"TAGame.Ball_TA:HitTeamNum"`) across all of the replay's network
+    /// frames, in frame order, along with the index of the frame that
+    /// contains each update and the actor that was updated. This scans the
+    /// whole replay, independently of the current state of the processor, and
+    /// is intended as a debugging aid for understanding how an attribute
+    /// behaves over time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubtrActorErrorVariant::UnknownObjectName`] if `object_name`
+    /// is not one of the replay's objects.
+    pub fn dump_updates_for_object(
+        &self,
+        object_name: &str,
+    ) -> SubtrActorResult<Vec<(usize, boxcars::ActorId, boxcars::Attribute)>> {
+        let object_id = self.name_to_object_id.get(object_name).ok_or_else(|| {
+            SubtrActorError::new(SubtrActorErrorVariant::UnknownObjectName {
+                name: object_name.to_string(),
+            })
+        })?;
+        let frames = &self
+            .replay
+            .network_frames
+            .as_ref()
+            .ok_or(SubtrActorError::new(
+                SubtrActorErrorVariant::NoNetworkFrames,
+            ))?
+            .frames;
+        Ok(frames
+            .iter()
+            .enumerate()
+            .flat_map(|(index, frame)| {
+                frame
+                    .updated_actors
+                    .iter()
+                    .filter(|update| &update.object_id == object_id)
+                    .map(move |update| (index, update.actor_id, update.attribute.clone()))
+            })
+            .collect())
+    }
+
+    /// Returns every [`RigidBody`](boxcars::RigidBody) update of the given
+    /// actor across all of the replay's network frames, in frame order, along
+    /// with the index of the frame that contains each update. This scans the
+    /// whole replay, independently of the current state of the processor, and
+    /// is useful for exporting trajectories without resampling.
+    ///
+    /// Actor ids are reused once an actor is deleted, so the updates are
+    /// grouped by the actor that the id referred to at the time. Each group is
+    /// paired with the index of the frame in which that actor was spawned, and
+    /// the groups are in the order in which the actors were spawned. Spawns
+    /// without any rigid body update yield an empty group, and an id that is
+    /// never used yields an empty vector.
+    pub fn collect_actor_rigid_bodies(
+        &self,
+        actor_id: &boxcars::ActorId,
+    ) -> SubtrActorResult<Vec<(usize, RigidBodyUpdates)>> {
+        let frames = &self
+            .replay
+            .network_frames
+            .as_ref()
+            .ok_or(SubtrActorError::new(
+                SubtrActorErrorVariant::NoNetworkFrames,
+            ))?
+            .frames;
+        let object_id = self.get_object_id_for_key(RIGID_BODY_STATE_KEY).ok();
+        let mut spawns: Vec<(usize, RigidBodyUpdates)> = Vec::new();
+        let mut is_alive = false;
+        for (index, frame) in frames.iter().enumerate() {
+            // Deletions are applied before creations within a frame.
+            if frame.deleted_actors.contains(actor_id) {
+                is_alive = false;
+            }
+            if frame
+                .new_actors
+                .iter()
+                .any(|new_actor| &new_actor.actor_id == actor_id)
+            {
+                spawns.push((index, Vec::new()));
+                is_alive = true;
+            }
+            let rigid_bodies = frame
+                .updated_actors
+                .iter()
+                .filter(|update| {
+                    &update.actor_id == actor_id && Some(&update.object_id) == object_id
+                })
+                .filter_map(|update| match &update.attribute {
+                    boxcars::Attribute::RigidBody(rigid_body) => Some((index, *rigid_body)),
+                    _ => None,
+                });
+            for rigid_body in rigid_bodies {
+                // Updates of an actor whose spawn wasn't seen start a group of
+                // their own.
+                if !is_alive {
+                    spawns.push((index, Vec::new()));
+                    is_alive = true;
+                }
+                // The unwrap here is fine because a group was just pushed if
+                // there was none.
+                spawns.last_mut().unwrap().1.push(rigid_body);
+            }
+        }
+        Ok(spawns)
+    }
+
     // Update functions
 
     /// This method is responsible for updating various mappings that are used
@@ -537,13 +1123,17 @@ impl<'a> ReplayProcessor<'a> {
     /// frame.
     ///
     /// The mappings updated are:
-    /// - `player_to_actor_id`: maps a player's [`boxcars::UniqueId`] to their actor ID.
+    /// - `player_to_actor_id`: maps a player's [`PlayerId`], as determined by
+    ///   [`get_player_id_from_unique_id`], to their actor ID.
     /// - `player_to_team`: maps a player's actor ID to their team actor ID.
     /// - `player_to_car`: maps a player's actor ID to their car actor ID.
     /// - `car_to_boost`: maps a car's actor ID to its associated boost actor ID.
     /// - `car_to_dodge`: maps a car's actor ID to its associated dodge actor ID.
     /// - `car_to_jump`: maps a car's actor ID to its associated jump actor ID.
     /// - `car_to_double_jump`: maps a car's actor ID to its associated double jump actor ID.
+    /// - `player_to_camera_settings`: maps a player's actor ID to their camera
+    ///   settings actor ID. It is only maintained for replays that contain
+    ///   camera settings actors.
     ///
     /// The function also handles the deletion of actors. When an actor is
     /// deleted, the function removes the actor's ID from the `player_to_car`
@@ -597,7 +1187,9 @@ impl<'a> ReplayProcessor<'a> {
                 self.player_to_actor_id,
                 PLAYER_TYPE,
                 UNIQUE_ID_KEY,
-                |_, unique_id: &Box<boxcars::UniqueId>| unique_id.remote_id.clone(),
+                |actor_id, unique_id: &Box<boxcars::UniqueId>| {
+                    get_player_id_from_unique_id(actor_id, unique_id)
+                },
                 use_update_actor,
                 boxcars::Attribute::UniqueId
             );
@@ -615,6 +1207,16 @@ impl<'a> ReplayProcessor<'a> {
             maintain_vehicle_key_link!(self.car_to_dodge, DODGE_TYPE);
             maintain_vehicle_key_link!(self.car_to_jump, JUMP_TYPE);
             maintain_vehicle_key_link!(self.car_to_double_jump, DOUBLE_JUMP_TYPE);
+            if self
+                .name_to_object_id
+                .contains_key(CAMERA_SETTINGS_PLAYER_KEY)
+            {
+                maintain_actor_link!(
+                    self.player_to_camera_settings,
+                    CAMERA_SETTINGS_TYPE,
+                    CAMERA_SETTINGS_PLAYER_KEY
+                );
+            }
         }
 
         for actor_id in frame.deleted_actors.iter() {
@@ -626,17 +1228,21 @@ impl<'a> ReplayProcessor<'a> {
         Ok(())
     }
 
+    /// Updates the actor ids of all the balls in the game, and of the primary
+    /// ball. See [`Self::get_ball_actor_id`]. Balls can only appear or
+    /// disappear in frames that create or delete actors, so other frames are
+    /// skipped.
     fn update_ball_id(&mut self, frame: &boxcars::Frame) -> SubtrActorResult<()> {
-        // XXX: This assumes there is only ever one ball, which is safe (I think?)
-        if let Some(actor_id) = self.ball_actor_id {
-            if frame.deleted_actors.contains(&actor_id) {
-                self.ball_actor_id = None;
-            }
-        } else {
-            self.ball_actor_id = self.find_ball_actor();
-            if self.ball_actor_id.is_some() {
-                return self.update_ball_id(frame);
-            }
+        if frame.new_actors.is_empty() && frame.deleted_actors.is_empty() {
+            return Ok(());
+        }
+        self.ball_actor_ids = self.find_ball_actors();
+        let primary_exists = self
+            .ball_actor_id
+            .map(|actor_id| self.ball_actor_ids.contains(&actor_id))
+            .unwrap_or(false);
+        if !primary_exists {
+            self.ball_actor_id = self.ball_actor_ids.first().copied();
         }
         Ok(())
     }
@@ -645,10 +1251,11 @@ impl<'a> ReplayProcessor<'a> {
     ///
     /// This function works by iterating over all the actors of a particular
     /// boost type. For each actor, it retrieves the current boost value. If the
-    /// actor's boost value hasn't been updated, it continues using the derived
-    /// boost value from the last frame. If the actor's boost is active, it
-    /// subtracts from the current boost value according to the frame delta and
-    /// the constant `BOOST_USED_PER_SECOND`.
+    /// actor's boost value was updated, that value is used as is. Otherwise it
+    /// continues from the derived boost value of the last frame, subtracting
+    /// from it according to the frame delta and the constant
+    /// `BOOST_USED_PER_SECOND` if the actor's boost is active. See
+    /// [`util::advance_boost_amount`].
     ///
     /// The updated boost values are then stored in the actor's derived
     /// attributes.
@@ -668,18 +1275,13 @@ impl<'a> ReplayProcessor<'a> {
             .map(|(actor_id, actor_state)| {
                 let (actor_amount_value, last_value, _, derived_value, is_active) =
                     self.get_current_boost_values(actor_state);
-                let mut current_value = if actor_amount_value == last_value {
-                    // If we don't have an update in the actor, just continue
-                    // using our derived value
-                    derived_value
-                } else {
-                    // If we do have an update in the actor, use that value.
-                    actor_amount_value.into()
-                };
-                if is_active {
-                    current_value -= frame.delta * BOOST_USED_PER_SECOND;
-                }
-                (actor_id.clone(), current_value.max(0.0), actor_amount_value)
+                // Only a changed amount counts as an update, otherwise we
+                // continue from our derived value.
+                let replicated_value =
+                    (actor_amount_value != last_value).then(|| actor_amount_value.into());
+                let current_value =
+                    advance_boost_amount(derived_value, replicated_value, is_active, frame.delta);
+                (actor_id.clone(), current_value, actor_amount_value)
             })
             .collect();
 
@@ -811,61 +1413,646 @@ impl<'a> ReplayProcessor<'a> {
         })
     }
 
-    // ID Mapping functions
-
-    fn get_player_id_from_car_id(&self, actor_id: &boxcars::ActorId) -> SubtrActorResult<PlayerId> {
-        self.get_player_id_from_actor_id(&self.get_player_actor_id_from_car_actor_id(actor_id)?)
-    }
+    /// Records a [`GoalInfo`] for every team whose score increased in the
+    /// given frame. Scores are replicated in the `Score` attribute of the team
+    /// actors, and the team is determined from the archetype of the actor.
+    /// The goal is attributed to the player who last touched the ball, see
+    /// [`Self::ball_touches`]. Only touches since the start of the most recent
+    /// kickoff are considered, so a goal scored without a detected touch in
+    /// the current round is not credited to anyone.
+    fn update_goals(&mut self, frame: &boxcars::Frame, index: usize) {
+        let score_object_id = match self.name_to_object_id.get(TEAM_SCORE_KEY) {
+            Some(object_id) => object_id,
+            None => return,
+        };
+        let score_updates: Vec<_> = frame
+            .updated_actors
+            .iter()
+            .filter(|update| &update.object_id == score_object_id)
+            .filter_map(|update| {
+                let score = attribute_match!(&update.attribute, boxcars::Attribute::Int).ok()?;
+                let team_object_id = self.get_actor_state(&update.actor_id).ok()?.object_id;
+                let team_name = self.object_id_to_name.get(&team_object_id)?;
+                let is_team_0 = if team_name == TEAM_ZERO_TYPE {
+                    true
+                } else if team_name == TEAM_ONE_TYPE {
+                    false
+                } else {
+                    return None;
+                };
+                Some((is_team_0, *score))
+            })
+            .collect();
 
-    fn get_player_id_from_actor_id(
-        &self,
-        actor_id: &boxcars::ActorId,
-    ) -> SubtrActorResult<PlayerId> {
-        for (player_id, player_actor_id) in self.player_to_actor_id.iter() {
-            if actor_id == player_actor_id {
-                return Ok(player_id.clone());
+        let kickoff_frame = self
+            .kickoff_results
+            .last()
+            .map(|kickoff| kickoff.frame)
+            .unwrap_or(0);
+        for (is_team_0, score) in score_updates {
+            let team_index = if is_team_0 { 0 } else { 1 };
+            let previous_score = std::mem::replace(&mut self.team_scores[team_index], score);
+            if score <= previous_score {
+                continue;
             }
+            let last_touch = self
+                .ball_touches
+                .iter()
+                .rev()
+                .take_while(|touch| touch.frame >= kickoff_frame)
+                .find(|touch| touch.frame <= index);
+            self.goals.push(GoalInfo {
+                frame: index,
+                time: frame.time,
+                seconds_remaining: self.get_seconds_remaining().unwrap_or(0),
+                scorer: last_touch.map(|touch| touch.player.clone()),
+                is_team_0,
+                is_own_goal: is_own_goal(last_touch, is_team_0),
+            });
         }
-        return SubtrActorError::new_result(SubtrActorErrorVariant::NoMatchingPlayerId {
-            actor_id: actor_id.clone(),
-        });
     }
 
-    fn get_player_actor_id_from_car_actor_id(
-        &self,
-        actor_id: &boxcars::ActorId,
-    ) -> SubtrActorResult<boxcars::ActorId> {
-        for (player_id, car_id) in self.player_to_car.iter() {
-            if actor_id == car_id {
-                return Ok(player_id.clone());
+    /// Records a [`BallTouchInfo`] if the ball was touched in the given frame.
+    ///
+    /// Touches are only looked for in frames where the ball's rigid body was
+    /// updated. A touch is considered to have happened when either the ball's
+    /// `HitTeamNum` attribute was replicated in this frame, or the ball's
+    /// velocity changed by more than [`BALL_TOUCH_MIN_VELOCITY_CHANGE`] since
+    /// its previous update. The touch is credited to the nearest car (from the
+    /// hitting team, when it is known) within [`BALL_TOUCH_MAX_DISTANCE`].
+    /// Repeated touches by the same player within
+    /// [`MIN_FRAMES_BETWEEN_BALL_TOUCHES`] frames are ignored.
+    fn update_ball_touches(
+        &mut self,
+        frame: &boxcars::Frame,
+        index: usize,
+    ) -> SubtrActorResult<()> {
+        if let Some(touch) = self.detect_ball_touch(frame, index) {
+            let is_repeat = self
+                .ball_touches
+                .last()
+                .map(|last| {
+                    last.player == touch.player
+                        && index - last.frame < MIN_FRAMES_BETWEEN_BALL_TOUCHES
+                })
+                .unwrap_or(false);
+            if !is_repeat {
+                self.update_shots_and_saves(&touch, index);
+                self.ball_touches.push(touch);
             }
         }
-        return SubtrActorError::new_result(SubtrActorErrorVariant::NoMatchingPlayerId {
-            actor_id: actor_id.clone(),
-        });
+        Ok(())
     }
 
-    fn demolish_is_known(&self, demolish_fx: &boxcars::DemolishFx, frame_index: usize) -> bool {
-        self.known_demolishes.iter().any(|(existing, index)| {
-            existing == demolish_fx
-                && frame_index
-                    .checked_sub(*index)
-                    .or_else(|| index.checked_sub(frame_index))
-                    .unwrap()
-                    < MAX_DEMOLISH_KNOWN_FRAMES_PASSED
-        })
+    /// Returns the player who most recently touched the ball, along with the
+    /// index of the frame of the touch, or `None` if the ball hasn't been
+    /// touched yet. See [`Self::ball_touches`] for the full touch history.
+    pub fn get_last_ball_touch(&self) -> SubtrActorResult<Option<(PlayerId, usize)>> {
+        Ok(self
+            .ball_touches
+            .last()
+            .map(|touch| (touch.player.clone(), touch.frame)))
+    }
+
+    /// Records the touch as a [`ShotInfo`] and/or [`SaveInfo`] according to
+    /// the rules documented on those types.
+    fn update_shots_and_saves(&mut self, touch: &BallTouchInfo, index: usize) {
+        let shot_seconds = get_seconds_to_goal(
+            &touch.ball_location,
+            &touch.ball_velocity,
+            !touch.is_team_0,
+            SHOT_MAX_SECONDS_TO_GOAL_LINE,
+        );
+        let own_goal_seconds = |location: &boxcars::Vector3f, velocity: &boxcars::Vector3f| {
+            get_seconds_to_goal(
+                location,
+                velocity,
+                touch.is_team_0,
+                SAVE_MAX_SECONDS_TO_GOAL_LINE,
+            )
+        };
+        let save_seconds = self
+            .get_ball_rigid_body_previous(index)
+            .ok()
+            .and_then(|(previous, _)| {
+                previous
+                    .linear_velocity
+                    .and_then(|velocity| own_goal_seconds(&previous.location, &velocity))
+            })
+            .filter(|_| own_goal_seconds(&touch.ball_location, &touch.ball_velocity).is_none());
+        if let Some(seconds_to_goal_line) = shot_seconds {
+            self.shots.push(ShotInfo {
+                frame: touch.frame,
+                time: touch.time,
+                player: touch.player.clone(),
+                is_team_0: touch.is_team_0,
+                seconds_to_goal_line,
+            });
+        }
+        if let Some(seconds_to_goal_line) = save_seconds {
+            self.saves.push(SaveInfo {
+                frame: touch.frame,
+                time: touch.time,
+                player: touch.player.clone(),
+                is_team_0: touch.is_team_0,
+                seconds_to_goal_line,
+            });
+        }
     }
 
-    /// Provides an iterator over the active demolition effects,
-    /// [`boxcars::DemolishFx`], in the current frame.
-    pub fn get_active_demolish_fx(
-        &self,
-    ) -> SubtrActorResult<impl Iterator<Item = &Box<boxcars::DemolishFx>>> {
-        Ok(self
-            .iter_actors_by_type_err(CAR_TYPE)?
-            .flat_map(|(_actor_id, state)| {
-                get_attribute_errors_expected!(
-                    self,
+    fn detect_ball_touch(&self, frame: &boxcars::Frame, index: usize) -> Option<BallTouchInfo> {
+        let ball_actor_id = self.ball_actor_id?;
+        let (ball_body, updated) = self.get_ball_rigid_body_and_updated().ok()?;
+        if *updated != index || ball_body.sleeping {
+            return None;
+        }
+
+        let hit_team_is_team_0 = self
+            .name_to_object_id
+            .get(BALL_HIT_TEAM_NUM_KEY)
+            .and_then(|object_id| {
+                frame.updated_actors.iter().find(|update| {
+                    update.actor_id == ball_actor_id && &update.object_id == object_id
+                })
+            })
+            .and_then(|update| match update.attribute {
+                boxcars::Attribute::Byte(team) => Some(team == 0),
+                _ => None,
+            });
+
+        let velocity = ball_body
+            .linear_velocity
+            .map(|v| vec_to_glam(&v))
+            .unwrap_or(glam::Vec3::ZERO);
+        if hit_team_is_team_0.is_none() {
+            let rigid_body_object_id = self.get_object_id_for_key(RIGID_BODY_STATE_KEY).ok()?;
+            let previous_velocity = self
+                .find_update_in_direction(
+                    index,
+                    &ball_actor_id,
+                    rigid_body_object_id,
+                    SearchDirection::Backward,
+                )
+                .ok()
+                .and_then(|(attribute, _)| match attribute {
+                    boxcars::Attribute::RigidBody(rigid_body) => rigid_body.linear_velocity,
+                    _ => None,
+                })
+                .map(|v| vec_to_glam(&v))
+                .unwrap_or(velocity);
+            if (velocity - previous_velocity).length() < BALL_TOUCH_MIN_VELOCITY_CHANGE {
+                return None;
+            }
+        }
+
+        let ball_location = vec_to_glam(&ball_body.location);
+        let (player_id, distance) = self
+            .iter_player_ids_in_order()
+            .filter(|player_id| {
+                hit_team_is_team_0
+                    .map(|is_team_0| is_team_0 == self.team_zero.contains(player_id))
+                    .unwrap_or(true)
+            })
+            .filter_map(|player_id| {
+                let rigid_body = self.get_player_rigid_body(player_id).ok()?;
+                Some((
+                    player_id,
+                    vec_to_glam(&rigid_body.location).distance(ball_location),
+                ))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        if distance > BALL_TOUCH_MAX_DISTANCE {
+            return None;
+        }
+
+        Some(BallTouchInfo {
+            time: frame.time,
+            frame: index,
+            player: player_id.clone(),
+            is_team_0: self.team_zero.contains(player_id),
+            ball_location: ball_body.location,
+            ball_velocity: glam_to_vec(&velocity),
+        })
+    }
+
+    /// Tracks the start of each kickoff and pairs it with the first ball touch
+    /// that follows it.
+    ///
+    /// A kickoff begins when the round countdown is replicated with a positive
+    /// value while no earlier kickoff is still waiting for its first touch.
+    /// Once the first touch has happened, the ball's velocity is sampled
+    /// [`KICKOFF_OUTCOME_DELAY_SECONDS`] later to determine the
+    /// [`KickoffOutcome`].
+    fn update_kickoffs(&mut self, frame: &boxcars::Frame, index: usize) -> SubtrActorResult<()> {
+        let countdown_updated = self
+            .name_to_object_id
+            .get(ROUND_COUNTDOWN_KEY)
+            .map(|object_id| {
+                frame.updated_actors.iter().any(|update| {
+                    &update.object_id == object_id
+                        && matches!(update.attribute, boxcars::Attribute::Int(n) if n > 0)
+                })
+            })
+            .unwrap_or(false);
+        let awaiting_touch = self
+            .kickoff_results
+            .last()
+            .map(|kickoff| kickoff.first_touch_frame.is_none())
+            .unwrap_or(false);
+        if countdown_updated && !awaiting_touch {
+            self.kickoff_results
+                .push(KickoffResult::new(index, frame.time));
+        }
+
+        let ball_velocity_y = self
+            .get_ball_rigid_body()
+            .ok()
+            .and_then(|rigid_body| rigid_body.linear_velocity)
+            .map(|velocity| velocity.y);
+        let first_touch_time = self
+            .kickoff_results
+            .last()
+            .and_then(|kickoff| kickoff.first_touch_frame)
+            .and_then(|first_touch_frame| self.get_frame(first_touch_frame).ok())
+            .map(|first_touch_frame| first_touch_frame.time);
+        let latest_touch = self.ball_touches.last();
+        if let Some(kickoff) = self.kickoff_results.last_mut() {
+            match (kickoff.first_touch_frame, latest_touch) {
+                (None, Some(touch)) if touch.frame >= kickoff.frame => {
+                    kickoff.first_touch_frame = Some(touch.frame);
+                    kickoff.winner = Some(touch.player.clone());
+                    kickoff.winner_is_team_0 = Some(touch.is_team_0);
+                }
+                (Some(_), _) if kickoff.outcome.is_none() => {
+                    let first_touch_time = first_touch_time.unwrap_or(frame.time);
+                    if frame.time - first_touch_time >= KICKOFF_OUTCOME_DELAY_SECONDS {
+                        kickoff.outcome = Some(match ball_velocity_y {
+                            Some(y) if y <= -KICKOFF_OUTCOME_MIN_SPEED => {
+                                KickoffOutcome::TowardTeamZeroGoal
+                            }
+                            Some(y) if y >= KICKOFF_OUTCOME_MIN_SPEED => {
+                                KickoffOutcome::TowardTeamOneGoal
+                            }
+                            _ => KickoffOutcome::Neutral,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the set of supersonic players from the current velocities of
+    /// their cars, see [`is_supersonic`]. Players whose car has no rigid body,
+    /// or whose rigid body is sleeping, are not supersonic.
+    fn update_supersonic_players(&mut self) {
+        let supersonic_players = self
+            .iter_player_ids_in_order()
+            .filter(|player_id| {
+                let speed = self
+                    .get_player_rigid_body(player_id)
+                    .ok()
+                    .filter(|rigid_body| !rigid_body.sleeping)
+                    .and_then(|rigid_body| rigid_body.linear_velocity)
+                    .map(|velocity| vec_to_glam(&velocity).length());
+                speed
+                    .map(|speed| is_supersonic(self.supersonic_players.contains(player_id), speed))
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .collect();
+        self.supersonic_players = supersonic_players;
+    }
+
+    /// Accumulates the distance traveled by each player's car, using only the
+    /// rigid body updates received in the given frame. Movement between
+    /// different car actors, as happens when a car respawns after a demolish,
+    /// and movement faster than [`MAX_CAR_TRAVEL_SPEED`] are not counted.
+    fn update_distance_traveled(&mut self, frame: &boxcars::Frame, index: usize) {
+        let updates: Vec<_> = self
+            .iter_player_ids_in_order()
+            .filter_map(|player_id| {
+                let car_actor_id = self.get_car_actor_id(player_id).ok()?;
+                let (rigid_body, updated) = self.get_actor_rigid_body(&car_actor_id).ok()?;
+                (*updated == index).then(|| {
+                    (
+                        player_id.clone(),
+                        car_actor_id,
+                        vec_to_glam(&rigid_body.location),
+                    )
+                })
+            })
+            .collect();
+        for (player_id, car_actor_id, location) in updates {
+            if let Some((last_car_actor_id, last_location, last_time)) =
+                self.last_car_locations.get(&player_id)
+            {
+                let distance = location.distance(*last_location);
+                let elapsed = frame.time - last_time;
+                if *last_car_actor_id == car_actor_id && distance <= MAX_CAR_TRAVEL_SPEED * elapsed
+                {
+                    *self
+                        .distance_traveled
+                        .entry(player_id.clone())
+                        .or_insert(0.0) += distance;
+                }
+            }
+            self.last_car_locations
+                .insert(player_id, (car_actor_id, location, frame.time));
+        }
+    }
+
+    /// Tracks which player, if any, is carrying the ball on the roof of their
+    /// car, and records a [`DribbleInfo`] for each carry that lasts at least
+    /// [`MIN_DRIBBLE_SECONDS`]. A carry ends as soon as the ball leaves the
+    /// carry position, or another player starts carrying it.
+    fn update_dribbles(&mut self, frame: &boxcars::Frame, index: usize) {
+        let carrier = self.find_ball_carrier().cloned();
+        match (&mut self.active_dribble, carrier) {
+            (Some(dribble), Some(player_id)) if dribble.player == player_id => {
+                dribble.end_frame = index;
+                dribble.duration = frame.time - dribble.start_time;
+            }
+            (_, carrier) => {
+                let new_dribble = carrier.map(|player_id| DribbleInfo {
+                    is_team_0: self.team_zero.contains(&player_id),
+                    player: player_id,
+                    start_frame: index,
+                    end_frame: index,
+                    start_time: frame.time,
+                    duration: 0.0,
+                });
+                self.finish_active_dribble();
+                self.active_dribble = new_dribble;
+            }
+        }
+    }
+
+    fn finish_active_dribble(&mut self) {
+        if let Some(dribble) = self.active_dribble.take() {
+            if dribble.duration >= MIN_DRIBBLE_SECONDS {
+                self.dribbles.push(dribble);
+            }
+        }
+    }
+
+    fn find_ball_carrier(&self) -> Option<&PlayerId> {
+        let ball_body = self.get_ball_rigid_body().ok()?;
+        if ball_body.sleeping {
+            return None;
+        }
+        let ball_location = vec_to_glam(&ball_body.location);
+        let ball_velocity = ball_body
+            .linear_velocity
+            .map(|v| vec_to_glam(&v))
+            .unwrap_or(glam::Vec3::ZERO);
+        self.iter_player_ids_in_order()
+            .filter_map(|player_id| {
+                let car_body = self.get_player_rigid_body(player_id).ok()?;
+                let offset = ball_location - vec_to_glam(&car_body.location);
+                let horizontal_distance = offset.truncate().length();
+                let car_velocity = car_body
+                    .linear_velocity
+                    .map(|v| vec_to_glam(&v))
+                    .unwrap_or(glam::Vec3::ZERO);
+                let is_carrying = offset.z >= DRIBBLE_MIN_HEIGHT_ABOVE_CAR
+                    && offset.z <= DRIBBLE_MAX_HEIGHT_ABOVE_CAR
+                    && horizontal_distance <= DRIBBLE_MAX_HORIZONTAL_DISTANCE
+                    && (ball_velocity - car_velocity).length() <= DRIBBLE_MAX_RELATIVE_SPEED;
+                is_carrying.then_some((player_id, horizontal_distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(player_id, _)| player_id)
+    }
+
+    /// Records the [`KickoffPosition`] of every player during the opening
+    /// kickoff, once all players have spawned and before the ball has been
+    /// touched.
+    fn update_initial_formation(&mut self) {
+        let is_opening_kickoff_countdown =
+            self.kickoff_results.len() == 1 && self.kickoff_results[0].first_touch_frame.is_none();
+        if self.initial_formation.is_some() || !is_opening_kickoff_countdown {
+            return;
+        }
+        self.initial_formation = self
+            .iter_player_ids_in_order()
+            .map(|player_id| {
+                let location = self.get_player_rigid_body(player_id).ok()?.location;
+                let is_team_0 = self.team_zero.contains(player_id);
+                KickoffPosition::classify(
+                    location.x,
+                    location.y,
+                    is_team_0,
+                    KICKOFF_SPAWN_MAX_DISTANCE,
+                )
+                .map(|position| (player_id.clone(), position))
+            })
+            .collect();
+    }
+
+    /// Advances [`Self::seconds_remaining_clock`] to the time of the given
+    /// frame, starting it from the replicated seconds remaining the first time
+    /// they are known. See [`SecondsRemainingClock`].
+    fn update_seconds_remaining_clock(&mut self, frame: &boxcars::Frame) {
+        let running = self.is_ball_in_play();
+        self.seconds_remaining_clock = self.get_seconds_remaining().ok().map(|seconds_remaining| {
+            match self.seconds_remaining_clock {
+                Some(clock) => clock.advance(seconds_remaining, frame.time, running),
+                None => SecondsRemainingClock::new(seconds_remaining, frame.time, running),
+            }
+        });
+    }
+
+    /// Records the time of every boost pad pickup in the given frame, along
+    /// with a [`BoostPickupEvent`] for it, and marks pads as available again
+    /// when their respawn is replicated or a kickoff resets the field.
+    ///
+    /// Pad actors don't carry their location, so the first time a pad actor is
+    /// picked up it is matched to the closest of the [`STANDARD_BOOST_PADS`]
+    /// to the car that picked it up, provided that pad is within
+    /// [`BOOST_PAD_MAX_MATCH_DISTANCE`]. That match is remembered for later
+    /// pickups of the same actor. Pickups of pads that are still consumed and
+    /// within their respawn time are repeated replications of the same pickup,
+    /// and are ignored, so that each transition of a pad from available to
+    /// consumed is recorded once.
+    fn update_boost_pads(&mut self, frame: &boxcars::Frame, index: usize) -> SubtrActorResult<()> {
+        let kickoff_started = self
+            .kickoff_results
+            .last()
+            .map(|kickoff| kickoff.frame == index)
+            .unwrap_or(false);
+        if kickoff_started {
+            self.boost_pad_pickup_times.fill(None);
+        }
+
+        let updates: Vec<_> = frame
+            .updated_actors
+            .iter()
+            .filter_map(|update| {
+                let instigator = match update.attribute {
+                    boxcars::Attribute::Pickup(pickup) => {
+                        Some(pickup.instigator.filter(|_| pickup.picked_up))
+                    }
+                    boxcars::Attribute::PickupNew(pickup) => Some(pickup.instigator),
+                    _ => None,
+                }?;
+                Some((update.actor_id, instigator))
+            })
+            .collect();
+
+        for (pad_actor_id, instigator) in updates {
+            if !self.is_boost_pad_actor(&pad_actor_id) {
+                continue;
+            }
+            let car_actor_id = match instigator {
+                Some(car_actor_id) => car_actor_id,
+                None => {
+                    // The pad has respawned.
+                    if let Some(pad_index) = self.boost_pad_actor_to_index.get(&pad_actor_id) {
+                        self.boost_pad_pickup_times[*pad_index] = None;
+                    }
+                    continue;
+                }
+            };
+            let pad_index = match self.boost_pad_actor_to_index.get(&pad_actor_id) {
+                Some(pad_index) => Some(*pad_index),
+                None => self.find_nearest_boost_pad(&car_actor_id),
+            };
+            if let Some(pad_index) = pad_index {
+                self.boost_pad_actor_to_index
+                    .insert(pad_actor_id, pad_index);
+                if !self.boost_pad_is_available(pad_index, frame.time) {
+                    continue;
+                }
+                self.boost_pad_pickup_times[pad_index] = Some(frame.time);
+                match self.build_boost_pickup_event(pad_index, &car_actor_id, frame, index) {
+                    Ok(event) => self.boost_pickups.push(event),
+                    Err(e) => log::warn!("Error building boost pickup event: {:?}", e),
+                }
+            } else {
+                log::warn!("Could not match boost pad actor {:?}", pad_actor_id);
+            }
+        }
+        Ok(())
+    }
+
+    fn boost_pad_is_available(&self, pad_index: usize, time: f32) -> bool {
+        let kind = BoostPadKind::from_is_big(STANDARD_BOOST_PADS[pad_index].2);
+        self.boost_pad_pickup_times[pad_index]
+            .map(|pickup_time| time - pickup_time >= kind.respawn_seconds())
+            .unwrap_or(true)
+    }
+
+    /// Builds a [`BoostPickupEvent`], attributing the pickup to the player
+    /// driving the car that picked up the pad or, if that car can't be linked
+    /// to a player, to the player closest to the pad within its pickup radius.
+    fn build_boost_pickup_event(
+        &self,
+        pad_index: usize,
+        car_actor_id: &boxcars::ActorId,
+        frame: &boxcars::Frame,
+        index: usize,
+    ) -> SubtrActorResult<BoostPickupEvent> {
+        let (pad_x, pad_y, is_big) = STANDARD_BOOST_PADS[pad_index];
+        let pad_kind = BoostPadKind::from_is_big(is_big);
+        let player = self.get_player_id_from_car_id(car_actor_id).or_else(|e| {
+            let pad_location = glam::Vec2::new(pad_x, pad_y);
+            self.iter_player_ids_in_order()
+                .filter_map(|player_id| {
+                    let location = self.get_player_rigid_body(player_id).ok()?.location;
+                    let distance = glam::Vec2::new(location.x, location.y).distance(pad_location);
+                    (distance <= pad_kind.pickup_radius()).then_some((player_id, distance))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(player_id, _)| player_id.clone())
+                .ok_or(e)
+        })?;
+        Ok(BoostPickupEvent {
+            frame: index,
+            time: frame.time,
+            player,
+            pad_index,
+            pad_kind,
+            amount: pad_kind.amount(),
+        })
+    }
+
+    fn is_boost_pad_actor(&self, actor_id: &boxcars::ActorId) -> bool {
+        self.get_actor_state(actor_id)
+            .ok()
+            .and_then(|state| self.object_id_to_name.get(&state.object_id))
+            .map(|name| name.contains(BOOST_PAD_TYPE_NAME))
+            .unwrap_or(false)
+    }
+
+    fn find_nearest_boost_pad(&self, car_actor_id: &boxcars::ActorId) -> Option<usize> {
+        let (rigid_body, _) = self.get_actor_rigid_body(car_actor_id).ok()?;
+        let car_location = glam::Vec2::new(rigid_body.location.x, rigid_body.location.y);
+        STANDARD_BOOST_PADS
+            .iter()
+            .enumerate()
+            .map(|(index, (x, y, _))| (index, glam::Vec2::new(*x, *y).distance(car_location)))
+            .filter(|(_, distance)| *distance <= BOOST_PAD_MAX_MATCH_DISTANCE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    // ID Mapping functions
+
+    fn get_player_id_from_car_id(&self, actor_id: &boxcars::ActorId) -> SubtrActorResult<PlayerId> {
+        self.get_player_id_from_actor_id(&self.get_player_actor_id_from_car_actor_id(actor_id)?)
+    }
+
+    fn get_player_id_from_actor_id(
+        &self,
+        actor_id: &boxcars::ActorId,
+    ) -> SubtrActorResult<PlayerId> {
+        for (player_id, player_actor_id) in self.player_to_actor_id.iter() {
+            if actor_id == player_actor_id {
+                return Ok(player_id.clone());
+            }
+        }
+        return SubtrActorError::new_result(SubtrActorErrorVariant::NoMatchingPlayerId {
+            actor_id: actor_id.clone(),
+        });
+    }
+
+    fn get_player_actor_id_from_car_actor_id(
+        &self,
+        actor_id: &boxcars::ActorId,
+    ) -> SubtrActorResult<boxcars::ActorId> {
+        for (player_id, car_id) in self.player_to_car.iter() {
+            if actor_id == car_id {
+                return Ok(player_id.clone());
+            }
+        }
+        return SubtrActorError::new_result(SubtrActorErrorVariant::NoMatchingPlayerId {
+            actor_id: actor_id.clone(),
+        });
+    }
+
+    fn demolish_is_known(&self, demolish_fx: &boxcars::DemolishFx, frame_index: usize) -> bool {
+        self.known_demolishes.iter().any(|(existing, index)| {
+            existing == demolish_fx
+                && frame_index
+                    .checked_sub(*index)
+                    .or_else(|| index.checked_sub(frame_index))
+                    .unwrap()
+                    < MAX_DEMOLISH_KNOWN_FRAMES_PASSED
+        })
+    }
+
+    /// Provides an iterator over the active demolition effects,
+    /// [`boxcars::DemolishFx`], in the current frame.
+    pub fn get_active_demolish_fx(
+        &self,
+    ) -> SubtrActorResult<impl Iterator<Item = &Box<boxcars::DemolishFx>>> {
+        Ok(self
+            .iter_actors_by_type_err(CAR_TYPE)?
+            .flat_map(|(_actor_id, state)| {
+                get_attribute_errors_expected!(
+                    self,
                     &state.attributes,
                     DEMOLISH_GOAL_EXPLOSION_KEY,
                     boxcars::Attribute::DemolishFx
@@ -874,6 +2061,109 @@ impl<'a> ReplayProcessor<'a> {
             }))
     }
 
+    /// Returns the [`KickoffPosition`] from which each player started the
+    /// opening kickoff, in the order of [`Self::iter_player_ids_in_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubtrActorErrorVariant::InitialFormationNotFound`] if the
+    /// opening kickoff has not been processed yet, or if not every player
+    /// could be found at a standard kickoff spawn before the first touch.
+    pub fn get_initial_formation(&self) -> SubtrActorResult<Vec<(PlayerId, KickoffPosition)>> {
+        self.initial_formation
+            .clone()
+            .ok_or_else(|| SubtrActorError::new(SubtrActorErrorVariant::InitialFormationNotFound))
+    }
+
+    /// Expresses `rotation` from the perspective of the given team. See
+    /// [`to_team_relative_rotation`] for the exact transform.
+    pub fn to_team_relative_rotation(
+        &self,
+        rotation: &boxcars::Quaternion,
+        is_team_0: bool,
+    ) -> boxcars::Quaternion {
+        to_team_relative_rotation(rotation, is_team_0)
+    }
+
+    /// Returns the total distance, in unreal units (1 uu is 1 cm), traveled by
+    /// each player's car so far, in the order of
+    /// [`Self::iter_player_ids_in_order`]. Only actual rigid body updates are
+    /// used, and jumps between car actors (as when respawning after a
+    /// demolish) or faster than [`MAX_CAR_TRAVEL_SPEED`] are not counted.
+    pub fn get_distance_traveled(&self) -> Vec<(PlayerId, f32)> {
+        self.iter_player_ids_in_order()
+            .map(|player_id| {
+                (
+                    player_id.clone(),
+                    self.distance_traveled
+                        .get(player_id)
+                        .copied()
+                        .unwrap_or(0.0),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the result of every kickoff seen so far, in the order in which
+    /// they occurred. See [`KickoffResult`] for how winners are determined.
+    pub fn get_kickoff_results(&self) -> Vec<KickoffResult> {
+        self.kickoff_results.clone()
+    }
+
+    /// Returns the dribble that is still in progress as of the most recently
+    /// processed frame, if any. It is only added to [`Self::dribbles`] once it
+    /// ends, so callers that stop processing early should check here for a
+    /// carry that was cut off. Unlike the entries of [`Self::dribbles`], it
+    /// may be shorter than [`MIN_DRIBBLE_SECONDS`].
+    pub fn get_active_dribble(&self) -> Option<&DribbleInfo> {
+        self.active_dribble.as_ref()
+    }
+
+    /// Returns the number of seconds until each of the
+    /// [`STANDARD_BOOST_PADS`] becomes available again, in that order,
+    /// estimated from the time at which it was picked up and
+    /// [`BoostPadKind::respawn_seconds`]. Pads that are currently available
+    /// have a value of `0.0`, as do pads whose respawn is overdue but has not
+    /// been replicated yet.
+    pub fn get_boost_pad_respawn_times(&self, current_time: f32) -> Vec<f32> {
+        self.boost_pad_pickup_times
+            .iter()
+            .zip(STANDARD_BOOST_PADS.iter())
+            .map(|(pickup_time, (_, _, is_big))| {
+                let respawn_seconds = BoostPadKind::from_is_big(*is_big).respawn_seconds();
+                pickup_time
+                    .map(|pickup_time| (pickup_time + respawn_seconds - current_time).max(0.0))
+                    .unwrap_or(0.0)
+            })
+            .collect()
+    }
+
+    /// Returns the state of each of the [`STANDARD_BOOST_PADS`] at
+    /// `current_time`, in that order. A pad is inactive from the
+    /// `Pickup`/`PickupNew` update of its `ReplicatedPickupData` that names
+    /// the car that picked it up, until the update without an instigator that
+    /// marks its respawn, or until the next kickoff, which resets every pad.
+    /// See [`Self::get_boost_pad_respawn_times`] for `respawn_seconds`.
+    pub fn get_boost_pad_states(&self, current_time: f32) -> SubtrActorResult<Vec<BoostPadState>> {
+        Ok(self
+            .get_boost_pad_respawn_times(current_time)
+            .into_iter()
+            .zip(self.boost_pad_pickup_times.iter())
+            .zip(STANDARD_BOOST_PADS.iter())
+            .enumerate()
+            .map(
+                |(pad_index, ((respawn_seconds, pickup_time), (x, y, is_big)))| BoostPadState {
+                    pad_index,
+                    pad_kind: BoostPadKind::from_is_big(*is_big),
+                    x: *x,
+                    y: *y,
+                    is_active: pickup_time.is_none(),
+                    respawn_seconds,
+                },
+            )
+            .collect())
+    }
+
     // Interpolation Support functions
 
     fn get_frame(&self, frame_index: usize) -> SubtrActorResult<&boxcars::Frame> {
@@ -974,6 +2264,101 @@ impl<'a> ReplayProcessor<'a> {
         util::get_interpolated_rigid_body(start_body, start_time, end_body, end_time, time)
     }
 
+    /// Returns the [`RigidBody`](boxcars::RigidBody) of the actor at `time`,
+    /// which, unlike with [`Self::get_interpolated_actor_rigid_body`], doesn't
+    /// need to be near the current frame, and may be before or after it. The
+    /// rigid body is interpolated between the updates of the actor that
+    /// surround `time`, see [`util::get_actor_rigid_body_at_time`].
+    ///
+    /// The updates are looked up in the network frames of the replay rather
+    /// than in the current state, so actor ids that are reused by the game
+    /// after their actor is deleted may refer to a different actor at `time`.
+    pub fn get_actor_rigid_body_at(
+        &self,
+        actor_id: &boxcars::ActorId,
+        time: f32,
+        close_enough: f32,
+    ) -> SubtrActorResult<boxcars::RigidBody> {
+        let frames = &self
+            .replay
+            .network_frames
+            .as_ref()
+            .ok_or(SubtrActorError::new(
+                SubtrActorErrorVariant::NoNetworkFrames,
+            ))?
+            .frames;
+        let object_id = self.get_object_id_for_key(RIGID_BODY_STATE_KEY)?;
+        util::get_actor_rigid_body_at_time(frames, actor_id, object_id, time, close_enough)
+    }
+
+    /// Returns the time weighted mean of the actor's
+    /// [`RigidBody`](boxcars::RigidBody) over the window from `start_time` to
+    /// `end_time`, see [`util::get_time_weighted_mean_rigid_body`].
+    ///
+    /// Only the rigid body updates up to the current frame are known, so the
+    /// most recent update is assumed to hold until `end_time`, which should
+    /// therefore not be later than the time of the next frame. Updates from
+    /// before the actor was spawned, which may belong to an earlier actor that
+    /// had the same id, are not included.
+    pub fn get_averaged_actor_rigid_body(
+        &self,
+        actor_id: &boxcars::ActorId,
+        start_time: f32,
+        end_time: f32,
+    ) -> SubtrActorResult<boxcars::RigidBody> {
+        let frames = &self
+            .replay
+            .network_frames
+            .as_ref()
+            .ok_or(SubtrActorError::new(
+                SubtrActorErrorVariant::NoNetworkFrames,
+            ))?
+            .frames;
+        let object_id = self.get_object_id_for_key(RIGID_BODY_STATE_KEY)?;
+        let (rigid_body, frame_index) = self.get_actor_rigid_body(actor_id)?;
+        let mut frame_index = *frame_index;
+        // Deletions and creations are applied before updates within a frame,
+        // so the updates of the spawn frame itself belong to this actor.
+        let spawn_frame = util::find_in_direction(
+            frames,
+            frame_index + 1,
+            util::SearchDirection::Backward,
+            |frame| {
+                (frame.deleted_actors.contains(actor_id)
+                    || frame
+                        .new_actors
+                        .iter()
+                        .any(|new_actor| &new_actor.actor_id == actor_id))
+                .then_some(())
+            },
+        )
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+        let mut updates = vec![(self.get_frame(frame_index)?.time, *rigid_body)];
+        while updates
+            .last()
+            .map(|(time, _)| *time > start_time)
+            .unwrap_or(false)
+        {
+            let (attribute, found_frame) = match self.find_update_in_direction(
+                frame_index,
+                actor_id,
+                object_id,
+                util::SearchDirection::Backward,
+            ) {
+                Ok((_, found_frame)) if found_frame < spawn_frame => break,
+                Ok(found) => found,
+                Err(_) => break,
+            };
+            let found_body = attribute_match!(attribute, boxcars::Attribute::RigidBody)?;
+            updates.push((self.get_frame(found_frame)?.time, found_body));
+            frame_index = found_frame;
+        }
+        updates.reverse();
+        // The unwrap here is fine because updates is never empty
+        Ok(util::get_time_weighted_mean_rigid_body(&updates, start_time, end_time).unwrap())
+    }
+
     // Actor functions
 
     fn get_object_id_for_key(&self, name: &'static str) -> SubtrActorResult<&boxcars::ObjectId> {
@@ -1030,28 +2415,166 @@ impl<'a> ReplayProcessor<'a> {
         })
     }
 
-    fn find_ball_actor(&self) -> Option<boxcars::ActorId> {
+    fn find_ball_actors(&self) -> Vec<boxcars::ActorId> {
         BALL_TYPES
             .iter()
             .filter_map(|ball_type| self.iter_actors_by_type(ball_type))
             .flat_map(|i| i)
             .map(|(actor_id, _)| actor_id.clone())
-            .next()
+            .collect()
     }
 
+    /// Returns the actor id of the primary ball. Most game modes only ever
+    /// have one ball, but some (e.g. heatseeker variants and other limited
+    /// time modes) can have several. The first ball found becomes the primary
+    /// ball, and remains so for as long as its actor exists, after which the
+    /// first of the remaining balls takes over. All the single ball methods of
+    /// the processor, like [`Self::get_ball_rigid_body`], refer to this ball.
+    /// See [`Self::get_ball_actor_ids`].
     pub fn get_ball_actor_id(&self) -> SubtrActorResult<boxcars::ActorId> {
         self.ball_actor_id.ok_or(SubtrActorError::new(
             SubtrActorErrorVariant::BallActorNotFound,
         ))
     }
 
+    /// Returns the actor ids of all the balls that currently exist, grouped by
+    /// their [`BALL_TYPES`] and then in the order in which they were created.
+    pub fn get_ball_actor_ids(&self) -> &[boxcars::ActorId] {
+        &self.ball_actor_ids
+    }
+
+    /// Returns the actor id of the game event actor from which match metadata
+    /// (e.g. [`Self::get_seconds_remaining`]) should be read.
+    ///
+    /// Some replays contain more than one game event actor. When that happens,
+    /// the actor whose `SecondsRemaining` was most recently updated is
+    /// preferred, falling back to the actor with the most recently updated
+    /// attribute of any kind, and finally to the one with the lowest actor id.
+    ///
+    /// Every actor whose archetype starts with [`GAME_EVENT_TYPE_PREFIX`] is
+    /// considered, so that modes without a [`GameMode`] of their own are
+    /// still supported. See [`Self::get_all_game_event_actors`].
     pub fn get_metadata_actor_id(&self) -> SubtrActorResult<&boxcars::ActorId> {
-        self.get_actor_ids_by_type(GAME_TYPE)?
+        let seconds_remaining_object_id = self.get_object_id_for_key(SECONDS_REMAINING_KEY).ok();
+        let mut actor_ids: Vec<_> = self
+            .actor_state
+            .actor_ids_by_type
             .iter()
-            .next()
+            .filter(|(object_id, _)| {
+                self.object_id_to_name
+                    .get(object_id)
+                    .map(|name| name.starts_with(GAME_EVENT_TYPE_PREFIX))
+                    .unwrap_or(false)
+            })
+            .flat_map(|(_, actor_ids)| actor_ids.iter())
+            .collect();
+        actor_ids.sort();
+        actor_ids
+            .into_iter()
+            .rev()
+            .max_by_key(|actor_id| {
+                self.get_game_event_activity(actor_id, seconds_remaining_object_id)
+            })
             .ok_or_else(|| SubtrActorError::new(SubtrActorErrorVariant::NoGameActor))
     }
 
+    /// Returns the [`GameMode`] of the replay, based on the archetype of the
+    /// actor returned by [`Self::get_metadata_actor_id`].
+    pub fn get_game_mode(&self) -> SubtrActorResult<GameMode> {
+        let state = self.get_actor_state(self.get_metadata_actor_id()?)?;
+        self.object_id_to_name
+            .get(&state.object_id)
+            .and_then(|name| GameMode::from_game_event_type(name))
+            .ok_or_else(|| SubtrActorError::new(SubtrActorErrorVariant::NoGameActor))
+    }
+
+    /// Returns the raw state code replicated in the `ReplicatedStateName`
+    /// attribute of the game event. See [`Self::get_game_state`] for a
+    /// readable version.
+    pub fn get_game_state_name(&self) -> SubtrActorResult<i32> {
+        get_actor_attribute_matching!(
+            self,
+            self.get_metadata_actor_id()?,
+            REPLICATED_STATE_NAME_KEY,
+            boxcars::Attribute::Int
+        )
+        .cloned()
+    }
+
+    /// Returns the number shown in the kickoff countdown, which is positive
+    /// while the countdown is in progress and `0` otherwise.
+    pub fn get_round_countdown(&self) -> SubtrActorResult<i32> {
+        get_actor_attribute_matching!(
+            self,
+            self.get_metadata_actor_id()?,
+            ROUND_COUNTDOWN_KEY,
+            boxcars::Attribute::Int
+        )
+        .cloned()
+    }
+
+    /// Returns whether the ball is in play, meaning that real gameplay is
+    /// happening, as opposed to e.g. a kickoff countdown or a goal replay.
+    ///
+    /// This combines whether the ball has a rigid body, whether ball syncing is
+    /// ignored ([`Self::get_ignore_ball_syncing`]), the game state
+    /// ([`Self::get_game_state`]) and the kickoff countdown
+    /// ([`Self::get_round_countdown`]). See
+    /// [`util::is_ball_in_play_from_signals`] for exactly how they are
+    /// combined.
+    pub fn is_ball_in_play(&self) -> bool {
+        is_ball_in_play_from_signals(
+            self.get_ball_rigid_body().is_ok(),
+            self.get_ignore_ball_syncing().unwrap_or(false),
+            self.get_game_state().ok(),
+            self.get_round_countdown().ok(),
+        )
+    }
+
+    /// Returns the current [`GameState`] of the game event, e.g. whether a
+    /// kickoff countdown is in progress or a goal was just scored.
+    pub fn get_game_state(&self) -> SubtrActorResult<GameState> {
+        self.get_game_state_name().map(GameState::from_code)
+    }
+
+    fn get_game_event_activity(
+        &self,
+        actor_id: &boxcars::ActorId,
+        seconds_remaining_object_id: Option<&boxcars::ObjectId>,
+    ) -> (Option<usize>, Option<usize>) {
+        self.get_actor_state(actor_id)
+            .map(|state| {
+                (
+                    seconds_remaining_object_id
+                        .and_then(|object_id| state.attributes.get(object_id))
+                        .map(|(_, updated)| *updated),
+                    state.attributes.values().map(|(_, updated)| *updated).max(),
+                )
+            })
+            .unwrap_or((None, None))
+    }
+
+    /// Returns every live actor whose object name identifies it as a game
+    /// event, along with that name. This is mostly useful for debugging
+    /// replays in which [`Self::get_metadata_actor_id`] picks an unexpected
+    /// actor.
+    pub fn get_all_game_event_actors(&self) -> Vec<(&String, boxcars::ActorId)> {
+        let mut actors: Vec<_> = self
+            .actor_state
+            .actor_ids_by_type
+            .iter()
+            .filter_map(|(object_id, actor_ids)| {
+                self.object_id_to_name
+                    .get(object_id)
+                    .filter(|name| name.starts_with(GAME_EVENT_TYPE_PREFIX))
+                    .map(|name| (name, actor_ids))
+            })
+            .flat_map(|(name, actor_ids)| actor_ids.iter().map(move |actor_id| (name, *actor_id)))
+            .collect();
+        actors.sort();
+        actors
+    }
+
     pub fn get_player_actor_id(&self, player_id: &PlayerId) -> SubtrActorResult<boxcars::ActorId> {
         self.player_to_actor_id
             .get(&player_id)
@@ -1076,6 +2599,30 @@ impl<'a> ReplayProcessor<'a> {
             .cloned()
     }
 
+    /// Returns the camera settings of the player, read from the
+    /// `ProfileSettings` of the camera settings actor linked to the player.
+    pub fn get_player_camera_settings(
+        &self,
+        player_id: &PlayerId,
+    ) -> SubtrActorResult<CameraSettings> {
+        let camera_settings_actor_id = self
+            .player_to_camera_settings
+            .get(&self.get_player_actor_id(player_id)?)
+            .ok_or_else(|| {
+                SubtrActorError::new(SubtrActorErrorVariant::ActorNotFound {
+                    name: "CameraSettings",
+                    player_id: player_id.clone(),
+                })
+            })?;
+        get_actor_attribute_matching!(
+            self,
+            camera_settings_actor_id,
+            CAMERA_SETTINGS_PROFILE_KEY,
+            boxcars::Attribute::CamSettings
+        )
+        .map(|settings| CameraSettings::from(settings.as_ref()))
+    }
+
     pub fn get_car_connected_actor_id(
         &self,
         player_id: &PlayerId,
@@ -1123,6 +2670,48 @@ impl<'a> ReplayProcessor<'a> {
         )
     }
 
+    /// Reads the current value of an arbitrary attribute of an actor.
+    ///
+    /// Unlike the other accessors of [`ReplayProcessor`], the attribute is
+    /// identified by a key that need not be known at compile time, which makes
+    /// this suitable for implementing [`FeatureAdder`] or
+    /// [`PlayerFeatureAdder`] instances for attributes that this crate does
+    /// not provide accessors for.
+    ///
+    /// # Arguments
+    ///
+    /// * `actor_id` - The id of the actor whose attribute should be read.
+    /// * `key` - The object name of the attribute, e.g.
+    ///   `"TAGame.Vehicle_TA:ReplicatedThrottle"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubtrActorErrorVariant::UnknownObjectName`] if `key` is not
+    /// the name of any object in the replay, and
+    /// [`SubtrActorErrorVariant::ActorAttributeNotFound`] if the actor has not
+    /// (yet) received a value for it.
+    pub fn read_actor_attribute(
+        &self,
+        actor_id: &boxcars::ActorId,
+        key: &str,
+    ) -> SubtrActorResult<boxcars::Attribute> {
+        let object_id = self.name_to_object_id.get(key).ok_or_else(|| {
+            SubtrActorError::new(SubtrActorErrorVariant::UnknownObjectName {
+                name: key.to_string(),
+            })
+        })?;
+        self.get_actor_state(actor_id)?
+            .attributes
+            .get(object_id)
+            .map(|(attribute, _)| attribute.clone())
+            .ok_or_else(|| {
+                SubtrActorError::new(SubtrActorErrorVariant::ActorAttributeNotFound {
+                    actor_id: *actor_id,
+                    name: key.to_string(),
+                })
+            })
+    }
+
     // Actor iteration functions
 
     pub fn iter_player_ids_in_order(&self) -> impl Iterator<Item = &PlayerId> {
@@ -1178,6 +2767,50 @@ impl<'a> ReplayProcessor<'a> {
         .cloned()
     }
 
+    /// Returns the seconds remaining in the game as a continuous value, by
+    /// subtracting the game time that has elapsed since the last integer tick
+    /// of [`Self::get_seconds_remaining`]. The value decreases monotonically
+    /// while the ball is in play and is held steady while the clock is
+    /// stopped, e.g. during kickoff countdowns and goal replays. See
+    /// [`SecondsRemainingClock`].
+    ///
+    /// The clock counts up in overtime, which replays don't replicate, so the
+    /// integer seconds remaining are returned as is in overtime.
+    pub fn get_continuous_seconds_remaining(&self, current_time: f32) -> SubtrActorResult<f32> {
+        let seconds_remaining = self.get_seconds_remaining()?;
+        if self.get_is_overtime()? {
+            return Ok(seconds_remaining as f32);
+        }
+        Ok(self
+            .seconds_remaining_clock
+            .filter(|clock| clock.seconds_remaining == seconds_remaining)
+            .map(|clock| clock.seconds_remaining_at(current_time))
+            .unwrap_or(seconds_remaining as f32))
+    }
+
+    /// Returns the scores of team 0 and team 1, in that order, as of the
+    /// current frame. Scores are read from the last replicated `Score` of each
+    /// team actor, and are `0` until one has been replicated.
+    pub fn get_team_scores(&self) -> SubtrActorResult<(i32, i32)> {
+        Ok((self.team_scores[0], self.team_scores[1]))
+    }
+
+    /// Returns whether the game is currently in overtime. Replays only
+    /// replicate the overtime flag once overtime begins, so `false` is returned
+    /// when it has not been set.
+    pub fn get_is_overtime(&self) -> SubtrActorResult<bool> {
+        let metadata_actor_id = self.get_metadata_actor_id()?;
+        Ok(get_actor_attribute_matching!(
+            self,
+            metadata_actor_id,
+            OVERTIME_KEY,
+            boxcars::Attribute::Boolean
+        )
+        .ok()
+        .cloned()
+        .unwrap_or(false))
+    }
+
     /// Returns a boolean indicating whether ball syncing is ignored.
     pub fn get_ignore_ball_syncing(&self) -> SubtrActorResult<bool> {
         let actor_id = self.get_ball_actor_id()?;
@@ -1199,6 +2832,19 @@ impl<'a> ReplayProcessor<'a> {
             .and_then(|actor_id| self.get_actor_rigid_body(&actor_id).map(|v| v.0))
     }
 
+    /// Returns a reference to the [`RigidBody`](boxcars::RigidBody) of the
+    /// ball with the given actor id, which should be one of
+    /// [`Self::get_ball_actor_ids`].
+    pub fn get_ball_rigid_body_for(
+        &self,
+        actor_id: &boxcars::ActorId,
+    ) -> SubtrActorResult<&boxcars::RigidBody> {
+        if !self.ball_actor_ids.contains(actor_id) {
+            return SubtrActorError::new_result(SubtrActorErrorVariant::BallActorNotFound);
+        }
+        self.get_actor_rigid_body(actor_id).map(|v| v.0)
+    }
+
     /// Returns a boolean indicating whether the ball's
     /// [`RigidBody`](boxcars::RigidBody) exists and is not sleeping.
     pub fn ball_rigid_body_exists(&self) -> SubtrActorResult<bool> {
@@ -1227,6 +2873,61 @@ impl<'a> ReplayProcessor<'a> {
             })
     }
 
+    /// Returns the ball's [`RigidBody`](boxcars::RigidBody) from the most
+    /// recent rigid body update strictly before the frame at `current_index`,
+    /// along with the index of the frame that contains that update. Comparing
+    /// it with the current rigid body is useful for detecting impulses, such
+    /// as touches, applied to the ball.
+    ///
+    /// An error is returned when there is no earlier update of the ball, e.g.
+    /// at the start of the replay.
+    pub fn get_ball_rigid_body_previous(
+        &self,
+        current_index: usize,
+    ) -> SubtrActorResult<(&boxcars::RigidBody, usize)> {
+        let ball_actor_id = self.get_ball_actor_id()?;
+        let object_id = self.get_object_id_for_key(RIGID_BODY_STATE_KEY)?;
+        let frames = &self
+            .replay
+            .network_frames
+            .as_ref()
+            .ok_or(SubtrActorError::new(
+                SubtrActorErrorVariant::NoNetworkFrames,
+            ))?
+            .frames;
+        fn find_rigid_body<'f>(
+            frame: &'f boxcars::Frame,
+            actor_id: boxcars::ActorId,
+            object_id: &boxcars::ObjectId,
+        ) -> Option<&'f boxcars::RigidBody> {
+            frame
+                .updated_actors
+                .iter()
+                .find(|update| update.actor_id == actor_id && &update.object_id == object_id)
+                .and_then(|update| match &update.attribute {
+                    boxcars::Attribute::RigidBody(rigid_body) => Some(rigid_body),
+                    _ => None,
+                })
+        }
+        util::find_in_direction(
+            frames,
+            current_index.min(frames.len()),
+            SearchDirection::Backward,
+            |frame| find_rigid_body(frame, ball_actor_id, object_id).map(|_| ()),
+        )
+        .and_then(|(index, _)| {
+            find_rigid_body(&frames[index], ball_actor_id, object_id)
+                .map(|rigid_body| (rigid_body, index))
+        })
+        .ok_or_else(|| {
+            SubtrActorError::new(SubtrActorErrorVariant::NoUpdateAfterFrame {
+                actor_id: ball_actor_id,
+                object_id: *object_id,
+                frame_index: current_index,
+            })
+        })
+    }
+
     /// Returns a [`RigidBody`](boxcars::RigidBody) of the ball with applied
     /// velocity at the target time.
     pub fn get_velocity_applied_ball_rigid_body(
@@ -1247,6 +2948,34 @@ impl<'a> ReplayProcessor<'a> {
         self.get_interpolated_actor_rigid_body(&self.get_ball_actor_id()?, time, close_enough)
     }
 
+    /// Returns the time weighted mean of the ball's
+    /// [`RigidBody`](boxcars::RigidBody) over the window from `start_time` to
+    /// `end_time`, see [`Self::get_averaged_actor_rigid_body`].
+    pub fn get_averaged_ball_rigid_body(
+        &self,
+        start_time: f32,
+        end_time: f32,
+    ) -> SubtrActorResult<boxcars::RigidBody> {
+        self.get_averaged_actor_rigid_body(&self.get_ball_actor_id()?, start_time, end_time)
+    }
+
+    /// Returns the distances from the center of the ball to the centers of the
+    /// team zero (blue) and team one (orange) goals, in that order, see
+    /// [`get_goal_distances`].
+    pub fn get_ball_distance_to_goals(&self) -> SubtrActorResult<(f32, f32)> {
+        Ok(get_goal_distances(&self.get_ball_rigid_body()?.location))
+    }
+
+    /// Returns which third of the field the player's car is in, relative to
+    /// the player's team, see [`get_field_third`].
+    pub fn get_player_field_third(&self, player_id: &PlayerId) -> SubtrActorResult<usize> {
+        let rigid_body = self.get_player_rigid_body(player_id)?;
+        Ok(get_field_third(
+            rigid_body.location.y,
+            self.get_player_is_team_0(player_id)?,
+        ))
+    }
+
     /// Returns the name of the specified player.
     pub fn get_player_name(&self, player_id: &PlayerId) -> SubtrActorResult<String> {
         get_actor_attribute_matching!(
@@ -1258,6 +2987,38 @@ impl<'a> ReplayProcessor<'a> {
         .cloned()
     }
 
+    /// Returns the value of the `bBot` attribute of the specified player's
+    /// replication info, which is set for players controlled by the game's AI.
+    /// Replays don't always replicate this attribute, in which case an error
+    /// is returned.
+    pub fn get_player_is_bot(&self, player_id: &PlayerId) -> SubtrActorResult<bool> {
+        get_actor_attribute_matching!(
+            self,
+            &self.get_player_actor_id(player_id)?,
+            PLAYER_BOT_KEY,
+            boxcars::Attribute::Boolean
+        )
+        .cloned()
+    }
+
+    /// Returns the ping of the player, in milliseconds, as replicated on their
+    /// replication info. The engine replicates ping as a single byte holding
+    /// the ping divided by [`PING_BYTE_MILLISECONDS`], so the returned value
+    /// has that resolution. Ping is only replicated sporadically, so the most
+    /// recently replicated value is returned, and `0` is returned if it has
+    /// not been replicated yet.
+    pub fn get_player_ping(&self, player_id: &PlayerId) -> SubtrActorResult<u16> {
+        let player_state = self.get_actor_state(&self.get_player_actor_id(player_id)?)?;
+        Ok(get_attribute_errors_expected!(
+            self,
+            &player_state.attributes,
+            PLAYER_PING_KEY,
+            boxcars::Attribute::Byte
+        )
+        .map(|ping| u16::from(*ping) * PING_BYTE_MILLISECONDS)
+        .unwrap_or(0))
+    }
+
     /// Returns the team key for the specified player.
     pub fn get_player_team_key(&self, player_id: &PlayerId) -> SubtrActorResult<String> {
         let team_actor_id = self
@@ -1279,6 +3040,61 @@ impl<'a> ReplayProcessor<'a> {
             .cloned()
     }
 
+    /// Returns the custom name of the given team, such as a club or
+    /// tournament team name, or `None` if the team was not given one, as is
+    /// the case in most online matches. All of the replay's network frames are
+    /// searched, so the result does not depend on how much of the replay has
+    /// been processed.
+    pub fn get_team_name(&self, is_team_0: bool) -> SubtrActorResult<Option<String>> {
+        let team_type = if is_team_0 {
+            TEAM_ZERO_TYPE
+        } else {
+            TEAM_ONE_TYPE
+        };
+        let (team_object_id, name_object_id) = match (
+            self.get_object_id_for_key(team_type),
+            self.get_object_id_for_key(CUSTOM_TEAM_NAME_KEY),
+        ) {
+            (Ok(team_object_id), Ok(name_object_id)) => (team_object_id, name_object_id),
+            _ => return Ok(None),
+        };
+        let frames = &self
+            .replay
+            .network_frames
+            .as_ref()
+            .ok_or(SubtrActorError::new(
+                SubtrActorErrorVariant::NoNetworkFrames,
+            ))?
+            .frames;
+
+        let mut team_actor_ids = Vec::new();
+        for frame in frames {
+            team_actor_ids.extend(
+                frame
+                    .new_actors
+                    .iter()
+                    .filter(|new_actor| &new_actor.object_id == team_object_id)
+                    .map(|new_actor| new_actor.actor_id),
+            );
+            let name = frame
+                .updated_actors
+                .iter()
+                .filter(|update| {
+                    &update.object_id == name_object_id && team_actor_ids.contains(&update.actor_id)
+                })
+                .find_map(|update| match &update.attribute {
+                    boxcars::Attribute::String(name) if !name.trim().is_empty() => {
+                        Some(name.clone())
+                    }
+                    _ => None,
+                });
+            if name.is_some() {
+                return Ok(name);
+            }
+        }
+        Ok(None)
+    }
+
     /// Determines if the player is on team 0.
     pub fn get_player_is_team_0(&self, player_id: &PlayerId) -> SubtrActorResult<bool> {
         Ok(self
@@ -1329,6 +3145,18 @@ impl<'a> ReplayProcessor<'a> {
         self.velocities_applied_rigid_body(&current_rigid_body, *frame_index, target_time)
     }
 
+    /// Returns the time weighted mean of the [`RigidBody`](boxcars::RigidBody)
+    /// of the player's car over the window from `start_time` to `end_time`,
+    /// see [`Self::get_averaged_actor_rigid_body`].
+    pub fn get_averaged_player_rigid_body(
+        &self,
+        player_id: &PlayerId,
+        start_time: f32,
+        end_time: f32,
+    ) -> SubtrActorResult<boxcars::RigidBody> {
+        self.get_averaged_actor_rigid_body(&self.get_car_actor_id(player_id)?, start_time, end_time)
+    }
+
     pub fn get_interpolated_player_rigid_body(
         &self,
         player_id: &PlayerId,
@@ -1343,15 +3171,163 @@ impl<'a> ReplayProcessor<'a> {
     }
 
     pub fn get_player_boost_level(&self, player_id: &PlayerId) -> SubtrActorResult<f32> {
-        self.get_boost_actor_id(player_id).and_then(|actor_id| {
-            let boost_state = self.get_actor_state(&actor_id)?;
-            get_derived_attribute!(
-                boost_state.derived_attributes,
-                BOOST_AMOUNT_KEY,
-                boxcars::Attribute::Float
-            )
-            .cloned()
-        })
+        self.get_boost_actor_id(player_id)
+            .and_then(|actor_id| self.get_boost_level(&actor_id))
+    }
+
+    fn get_boost_level(&self, boost_actor_id: &boxcars::ActorId) -> SubtrActorResult<f32> {
+        let boost_state = self.get_actor_state(boost_actor_id)?;
+        get_derived_attribute!(
+            boost_state.derived_attributes,
+            BOOST_AMOUNT_KEY,
+            boxcars::Attribute::Float
+        )
+        .cloned()
+    }
+
+    /// Returns the boost level of the player's car at `time`, linearly
+    /// interpolated between its value at the frame the processor is currently
+    /// on and its value at the following network frame.
+    ///
+    /// The value at the following frame is derived in the same way as
+    /// [`Self::get_player_boost_level`], from the replicated boost amount in
+    /// that frame if there is one, and otherwise from the boost consumed while
+    /// boosting. If `time` is not after the current frame, or there is no
+    /// following frame, the current boost level is returned unchanged.
+    pub fn get_interpolated_player_boost_level(
+        &self,
+        player_id: &PlayerId,
+        time: f32,
+    ) -> SubtrActorResult<f32> {
+        let actor_id = self.get_boost_actor_id(player_id)?;
+        let boost_state = self.get_actor_state(&actor_id)?;
+        let (_, last_value, _, current_value, is_active) =
+            self.get_current_boost_values(boost_state);
+        let frame_index = match boost_state.derived_attributes.get(BOOST_AMOUNT_KEY) {
+            Some((_, frame_index)) => *frame_index,
+            None => return Ok(current_value),
+        };
+        let frame = self.get_frame(frame_index)?;
+        let next_frame = match self.get_frame(frame_index + 1) {
+            Ok(next_frame) if time > frame.time && next_frame.time > frame.time => next_frame,
+            _ => return Ok(current_value),
+        };
+
+        let object_id = self.get_object_id_for_key(BOOST_AMOUNT_KEY)?;
+        let replicated_value = next_frame
+            .updated_actors
+            .iter()
+            .find(|update| update.actor_id == actor_id && &update.object_id == object_id)
+            .and_then(|update| attribute_match!(&update.attribute, boxcars::Attribute::Byte).ok())
+            .filter(|value| **value != last_value)
+            .map(|value| f32::from(*value));
+        let next_value =
+            advance_boost_amount(current_value, replicated_value, is_active, next_frame.delta);
+
+        let fraction = ((time - frame.time) / (next_frame.time - frame.time)).min(1.0);
+        Ok(current_value + (next_value - current_value) * fraction)
+    }
+
+    /// Returns whether the player's car is supersonic, i.e. whether it reached
+    /// [`SUPERSONIC_SPEED`] and has not since dropped below
+    /// [`SUPERSONIC_EXIT_SPEED`]. Players whose car has no rigid body, or
+    /// whose rigid body is sleeping, are not supersonic.
+    pub fn get_player_is_supersonic(&self, player_id: &PlayerId) -> bool {
+        self.supersonic_players.contains(player_id)
+    }
+
+    /// Returns the distance between the center of the player's car and the
+    /// center of the ball.
+    pub fn get_player_ball_distance(&self, player_id: &PlayerId) -> SubtrActorResult<f32> {
+        let car_body = self.get_player_rigid_body(player_id)?;
+        let ball_body = self.get_ball_rigid_body()?;
+        Ok(vec_to_glam(&car_body.location).distance(vec_to_glam(&ball_body.location)))
+    }
+
+    /// Returns whether the player currently has a car, i.e. whether they are
+    /// on the field rather than demolished, or not yet or no longer in the
+    /// game. Features of players without a car describe a stale or default
+    /// state.
+    pub fn get_player_is_active(&self, player_id: &PlayerId) -> bool {
+        self.get_car_actor_id(player_id)
+            .map(|car_actor_id| self.actor_state.actor_states.contains_key(&car_actor_id))
+            .unwrap_or(false)
+    }
+
+    /// Returns the number of demolitions the player has inflicted at or before
+    /// `frame_number`, see [`Self::demolishes`].
+    pub fn get_player_demolishes_inflicted(
+        &self,
+        player_id: &PlayerId,
+        frame_number: usize,
+    ) -> usize {
+        count_player_demolishes(&self.demolishes, player_id, frame_number).0
+    }
+
+    /// Returns the number of times the player has been demolished at or before
+    /// `frame_number`, see [`Self::demolishes`].
+    pub fn get_player_demolishes_taken(&self, player_id: &PlayerId, frame_number: usize) -> usize {
+        count_player_demolishes(&self.demolishes, player_id, frame_number).1
+    }
+
+    /// Returns the magnitude of the angular velocity of the player's car, in
+    /// the units in which it is replicated. `0.0` is returned when the rigid
+    /// body of the car has no angular velocity, e.g. while it is sleeping.
+    pub fn get_player_angular_speed(&self, player_id: &PlayerId) -> SubtrActorResult<f32> {
+        Ok(self
+            .get_player_rigid_body(player_id)?
+            .angular_velocity
+            .map(|angular_velocity| vec_to_glam(&angular_velocity).length())
+            .unwrap_or(0.0))
+    }
+
+    /// Returns whether the player's car appears to have its wheels on the
+    /// floor of the field. This is a heuristic based on the location,
+    /// rotation and velocity of the car, see [`is_car_on_ground`] for the
+    /// thresholds it uses.
+    pub fn get_player_on_ground(&self, player_id: &PlayerId) -> SubtrActorResult<bool> {
+        Ok(is_car_on_ground(self.get_player_rigid_body(player_id)?))
+    }
+
+    /// Returns whether the handbrake (powerslide) of the player's car is
+    /// engaged. The attribute is only replicated when it changes, so the last
+    /// known value is returned, and `false` is returned if it has never been
+    /// replicated for the car.
+    pub fn get_player_handbrake(&self, player_id: &PlayerId) -> SubtrActorResult<bool> {
+        let car_actor_id = self.get_car_actor_id(player_id)?;
+        let car_state = self.get_actor_state(&car_actor_id)?;
+        Ok(get_attribute_errors_expected!(
+            self,
+            &car_state.attributes,
+            HANDBRAKE_KEY,
+            boxcars::Attribute::Boolean
+        )
+        .cloned()
+        .unwrap_or(false))
+    }
+
+    /// Returns the raw throttle input of the player's car, which is centered
+    /// at 128. See [`util::normalize_replicated_input`].
+    pub fn get_player_throttle(&self, player_id: &PlayerId) -> SubtrActorResult<u8> {
+        get_actor_attribute_matching!(
+            self,
+            &self.get_car_actor_id(player_id)?,
+            THROTTLE_KEY,
+            boxcars::Attribute::Byte
+        )
+        .cloned()
+    }
+
+    /// Returns the raw steering input of the player's car, which is centered
+    /// at 128. See [`util::normalize_replicated_input`].
+    pub fn get_player_steer(&self, player_id: &PlayerId) -> SubtrActorResult<u8> {
+        get_actor_attribute_matching!(
+            self,
+            &self.get_car_actor_id(player_id)?,
+            STEER_KEY,
+            boxcars::Attribute::Byte
+        )
+        .cloned()
     }
 
     pub fn get_component_active(&self, actor_id: &boxcars::ActorId) -> SubtrActorResult<u8> {
@@ -1384,6 +3360,38 @@ impl<'a> ReplayProcessor<'a> {
             .and_then(|actor_id| self.get_component_active(&actor_id))
     }
 
+    /// Returns the boost level of the player's car along with the states of
+    /// its boost, jump, double jump and dodge components, resolving the car
+    /// only once. As with [`Self::get_boost_active`] and the other component
+    /// accessors, a component is active when its replicated value is odd. A
+    /// component that can't be found is reported as inactive, but an error is
+    /// returned if the car or its boost level can't be found.
+    pub fn get_player_component_states(
+        &self,
+        player_id: &PlayerId,
+    ) -> SubtrActorResult<ComponentStates> {
+        let car_actor_id = self.get_car_actor_id(player_id)?;
+        let is_active = |map: &HashMap<boxcars::ActorId, boxcars::ActorId>| {
+            map.get(&car_actor_id)
+                .and_then(|actor_id| self.get_component_active(actor_id).ok())
+                .map(|value| value % 2 == 1)
+                .unwrap_or(false)
+        };
+        let boost_actor_id = self.car_to_boost.get(&car_actor_id).ok_or_else(|| {
+            SubtrActorError::new(SubtrActorErrorVariant::ActorNotFound {
+                name: "Boost",
+                player_id: player_id.clone(),
+            })
+        })?;
+        Ok(ComponentStates {
+            boost_amount: self.get_boost_level(boost_actor_id)?,
+            boost_active: is_active(&self.car_to_boost),
+            jump_active: is_active(&self.car_to_jump),
+            double_jump_active: is_active(&self.car_to_double_jump),
+            dodge_active: is_active(&self.car_to_dodge),
+        })
+    }
+
     // Debugging
 
     pub fn map_attribute_keys(
@@ -1401,6 +3409,48 @@ impl<'a> ReplayProcessor<'a> {
             .collect()
     }
 
+    /// Returns an [`ActorGraphSnapshot`] describing every current actor and
+    /// its attributes, with object ids resolved to their names. Unlike the
+    /// `print_*` methods, the snapshot can be serialized (e.g. to JSON) for
+    /// offline inspection of replays on which feature extraction misbehaves.
+    pub fn snapshot_actor_graph(&self) -> ActorGraphSnapshot {
+        let object_name = |object_id: &boxcars::ObjectId| {
+            self.object_id_to_name
+                .get(object_id)
+                .cloned()
+                .unwrap_or_else(|| format!("{:?}", object_id))
+        };
+        let mut actors: Vec<_> = self
+            .actor_state
+            .actor_states
+            .iter()
+            .map(|(actor_id, state)| {
+                let mut attributes: Vec<_> = state
+                    .attributes
+                    .iter()
+                    .map(|(object_id, (value, updated_frame))| AttributeSnapshot {
+                        name: object_name(object_id),
+                        value: value.clone(),
+                        updated_frame: *updated_frame,
+                    })
+                    .collect();
+                attributes.sort_by(|a, b| a.name.cmp(&b.name));
+                ActorSnapshot {
+                    actor_id: *actor_id,
+                    object_name: object_name(&state.object_id),
+                    name: state
+                        .name_id
+                        .and_then(|name_id| usize::try_from(name_id).ok())
+                        .and_then(|name_id| self.replay.names.get(name_id))
+                        .cloned(),
+                    attributes,
+                }
+            })
+            .collect();
+        actors.sort_by_key(|actor| actor.actor_id);
+        ActorGraphSnapshot { actors }
+    }
+
     pub fn all_mappings_string(&self) -> String {
         let pairs = [
             ("player_to_car", &self.player_to_car),
@@ -1409,6 +3459,7 @@ impl<'a> ReplayProcessor<'a> {
             ("car_to_jump", &self.car_to_jump),
             ("car_to_double_jump", &self.car_to_double_jump),
             ("car_to_dodge", &self.car_to_dodge),
+            ("player_to_camera_settings", &self.player_to_camera_settings),
         ];
         let strings: Vec<_> = pairs
             .iter()
@@ -11,6 +11,10 @@ macro_rules! fmt_err {
 
 pub type PlayerId = boxcars::RemoteId;
 
+/// The rigid body updates of an actor, each paired with the index of the frame
+/// that contains it. See [`ReplayProcessor::collect_actor_rigid_bodies`].
+pub type RigidBodyUpdates = Vec<(usize, boxcars::RigidBody)>;
+
 /// [`DemolishInfo`] struct represents data related to a demolition event in the game.
 ///
 /// Demolition events occur when one player 'demolishes' or 'destroys' another by
@@ -34,6 +38,568 @@ pub struct DemolishInfo {
     pub victim_velocity: boxcars::Vector3f,
 }
 
+/// Returns the number of demolitions in `demolishes` that `player_id` inflicted
+/// and the number it received, in that order, counting only those that
+/// happened at or before `frame_number`.
+pub fn count_player_demolishes(
+    demolishes: &[DemolishInfo],
+    player_id: &PlayerId,
+    frame_number: usize,
+) -> (usize, usize) {
+    demolishes
+        .iter()
+        .filter(|demolish| demolish.frame <= frame_number)
+        .fold((0, 0), |(inflicted, taken), demolish| {
+            (
+                inflicted + (&demolish.attacker == player_id) as usize,
+                taken + (&demolish.victim == player_id) as usize,
+            )
+        })
+}
+
+/// A [`DemolishInfo`] together with the names and teams of the players
+/// involved, resolved from a [`ReplayMeta`]. When serialized, the fields of
+/// the [`DemolishInfo`] appear alongside the resolved fields, so the raw
+/// [`PlayerId`]s remain available for exact matching. Names and teams that
+/// can't be resolved are `None`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DemolishInfoWithNames {
+    #[serde(flatten)]
+    pub info: DemolishInfo,
+    /// The name of the player who initiated the demolition.
+    pub attacker_name: Option<String>,
+    /// The name of the player who was demolished.
+    pub victim_name: Option<String>,
+    /// Whether the attacker was on team zero.
+    pub attacker_is_team_0: Option<bool>,
+    /// Whether the victim was on team zero.
+    pub victim_is_team_0: Option<bool>,
+}
+
+impl DemolishInfoWithNames {
+    /// Resolves the names and teams of the players involved in `info` using
+    /// the players listed in `meta`.
+    pub fn from_meta(info: DemolishInfo, meta: &ReplayMeta) -> Self {
+        let find_player = |player_id: &PlayerId| {
+            meta.team_zero
+                .iter()
+                .map(|player| (player, true))
+                .chain(meta.team_one.iter().map(|player| (player, false)))
+                .find(|(player, _)| &player.remote_id == player_id)
+        };
+        let attacker = find_player(&info.attacker);
+        let victim = find_player(&info.victim);
+        Self {
+            attacker_name: attacker.map(|(player, _)| player.name.clone()),
+            victim_name: victim.map(|(player, _)| player.name.clone()),
+            attacker_is_team_0: attacker.map(|(_, is_team_0)| is_team_0),
+            victim_is_team_0: victim.map(|(_, is_team_0)| is_team_0),
+            info,
+        }
+    }
+}
+
+/// [`BallTouchInfo`] represents a single touch of the ball by a player, as
+/// detected by the [`ReplayProcessor`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BallTouchInfo {
+    /// The exact game time (in seconds) at which the touch occurred.
+    pub time: f32,
+    /// The frame number at which the touch occurred.
+    pub frame: usize,
+    /// The [`PlayerId`] of the player who touched the ball.
+    pub player: PlayerId,
+    /// Whether the player who touched the ball is on team 0.
+    pub is_team_0: bool,
+    /// The location of the ball at the time of the touch.
+    pub ball_location: boxcars::Vector3f,
+    /// The velocity of the ball immediately after the touch.
+    pub ball_velocity: boxcars::Vector3f,
+}
+
+/// Returns whether a goal scored by the team given by
+/// `scoring_team_is_team_0` is an own goal, given the last touch of the ball
+/// before the goal.
+///
+/// A goal is an own goal when the last player to touch the ball is on the
+/// team that was scored against. When the last touch was by the scoring team,
+/// the goal is not an own goal, even if a defender deflected the ball earlier.
+/// A goal with no known last touch is not considered an own goal.
+pub fn is_own_goal(last_touch: Option<&BallTouchInfo>, scoring_team_is_team_0: bool) -> bool {
+    last_touch
+        .map(|touch| touch.is_team_0 != scoring_team_is_team_0)
+        .unwrap_or(false)
+}
+
+/// [`GoalInfo`] describes a goal, as detected by the [`ReplayProcessor`] from
+/// increases in the replicated score of a team.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GoalInfo {
+    /// The frame in which the scoring team's score increased.
+    pub frame: usize,
+    /// The time (in seconds) of that frame.
+    pub time: f32,
+    /// The number of seconds remaining in the game when the goal was scored.
+    pub seconds_remaining: i32,
+    /// The [`PlayerId`] of the player who last touched the ball before the
+    /// goal, if any touch was detected. This is a best-effort attribution of
+    /// the goal and, for an own goal, is a player of the conceding team.
+    pub scorer: Option<PlayerId>,
+    /// Whether the goal was scored for team 0.
+    pub is_team_0: bool,
+    /// Whether the goal was an own goal, see [`is_own_goal`].
+    pub is_own_goal: bool,
+}
+
+/// [`ShotInfo`] describes a touch that sent the ball toward the opposing
+/// goal, as detected by the [`ReplayProcessor`].
+///
+/// A touch is considered a shot when, extrapolating the ball's motion right
+/// after the touch in a straight line, the ball crosses the goal line of the
+/// opposing team within the goal mouth in at most
+/// [`SHOT_MAX_SECONDS_TO_GOAL_LINE`](crate::SHOT_MAX_SECONDS_TO_GOAL_LINE).
+/// Gravity, bounces and other players are ignored, so this is a heuristic.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShotInfo {
+    /// The frame number at which the shot was taken.
+    pub frame: usize,
+    /// The exact game time (in seconds) at which the shot was taken.
+    pub time: f32,
+    /// The [`PlayerId`] of the player who took the shot.
+    pub player: PlayerId,
+    /// Whether the player who took the shot is on team 0.
+    pub is_team_0: bool,
+    /// The extrapolated number of seconds until the ball would have crossed
+    /// the goal line.
+    pub seconds_to_goal_line: f32,
+}
+
+/// [`SaveInfo`] describes a touch that stopped a ball that was headed into
+/// the toucher's own goal, as detected by the [`ReplayProcessor`].
+///
+/// A touch is considered a save when, extrapolating the ball's motion in a
+/// straight line, the ball was going to cross the goal line of the toucher's
+/// team within the goal mouth in at most
+/// [`SAVE_MAX_SECONDS_TO_GOAL_LINE`](crate::SAVE_MAX_SECONDS_TO_GOAL_LINE)
+/// before the touch, and no longer is after it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SaveInfo {
+    /// The frame number at which the save was made.
+    pub frame: usize,
+    /// The exact game time (in seconds) at which the save was made.
+    pub time: f32,
+    /// The [`PlayerId`] of the player who made the save.
+    pub player: PlayerId,
+    /// Whether the player who made the save is on team 0.
+    pub is_team_0: bool,
+    /// The extrapolated number of seconds the ball was from crossing the goal
+    /// line before the save.
+    pub seconds_to_goal_line: f32,
+}
+
+/// The two kinds of boost pads found on a standard field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum BoostPadKind {
+    Big,
+    Small,
+}
+
+impl BoostPadKind {
+    pub fn from_is_big(is_big: bool) -> Self {
+        if is_big {
+            BoostPadKind::Big
+        } else {
+            BoostPadKind::Small
+        }
+    }
+
+    /// The amount of boost, as displayed in game (0 to 100), granted by a pad
+    /// of this kind.
+    pub fn amount(&self) -> f32 {
+        match self {
+            BoostPadKind::Big => 100.0,
+            BoostPadKind::Small => 12.0,
+        }
+    }
+
+    /// How long it takes a pad of this kind to respawn after being picked up.
+    pub fn respawn_seconds(&self) -> f32 {
+        match self {
+            BoostPadKind::Big => BIG_BOOST_PAD_RESPAWN_SECONDS,
+            BoostPadKind::Small => SMALL_BOOST_PAD_RESPAWN_SECONDS,
+        }
+    }
+
+    /// The radius around a pad of this kind within which a car picks it up.
+    pub fn pickup_radius(&self) -> f32 {
+        match self {
+            BoostPadKind::Big => BIG_BOOST_PAD_PICKUP_RADIUS,
+            BoostPadKind::Small => SMALL_BOOST_PAD_PICKUP_RADIUS,
+        }
+    }
+}
+
+/// [`BoostPadState`] describes whether one of the
+/// [`STANDARD_BOOST_PADS`](crate::STANDARD_BOOST_PADS) can currently be picked
+/// up. See [`ReplayProcessor::get_boost_pad_states`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BoostPadState {
+    /// The index of the pad in
+    /// [`STANDARD_BOOST_PADS`](crate::STANDARD_BOOST_PADS).
+    pub pad_index: usize,
+    pub pad_kind: BoostPadKind,
+    /// The x coordinate of the pad.
+    pub x: f32,
+    /// The y coordinate of the pad.
+    pub y: f32,
+    /// Whether the pad is available to be picked up.
+    pub is_active: bool,
+    /// The number of seconds until the pad respawns, or `0.0` if it is
+    /// active.
+    pub respawn_seconds: f32,
+}
+
+/// [`BoostPickupEvent`] describes a player picking up a boost pad.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BoostPickupEvent {
+    /// The frame in which the pad was picked up.
+    pub frame: usize,
+    /// The time (in seconds) at which the pad was picked up.
+    pub time: f32,
+    /// The [`PlayerId`] of the player that picked up the pad.
+    pub player: PlayerId,
+    /// The index of the pad in
+    /// [`STANDARD_BOOST_PADS`](crate::STANDARD_BOOST_PADS).
+    pub pad_index: usize,
+    pub pad_kind: BoostPadKind,
+    /// The amount of boost granted by the pad, as displayed in game (0 to
+    /// 100). This is the nominal amount of the pad, regardless of how much
+    /// boost the player actually gained.
+    pub amount: f32,
+}
+
+/// [`DribbleInfo`] describes a period during which a player carried the ball
+/// on the roof of their car.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DribbleInfo {
+    /// The [`PlayerId`] of the player carrying the ball.
+    pub player: PlayerId,
+    /// Whether the player carrying the ball is on team 0.
+    pub is_team_0: bool,
+    /// The frame in which the carry started.
+    pub start_frame: usize,
+    /// The last frame in which the ball was in the carry position.
+    pub end_frame: usize,
+    /// The time (in seconds) at which the carry started.
+    pub start_time: f32,
+    /// The duration of the carry in seconds.
+    pub duration: f32,
+}
+
+/// Combines the signals that indicate whether the ball is in play, i.e.
+/// whether real gameplay is happening. See [`ReplayProcessor::is_ball_in_play`]
+/// for where each signal comes from. The ball is in play exactly when all of
+/// the following hold:
+///
+/// * `ball_exists`: the ball has a rigid body, which it lacks before the first
+///   kickoff and while it is respawning after a goal.
+/// * `!ignore_syncing`: the ball's rigid body is not flagged to be ignored,
+///   which happens while it is hidden, e.g. during goal explosions.
+/// * `game_state` is [`GameState::Active`]. A `game_state` of `None`, for
+///   replays that don't replicate it, doesn't rule out play, but any other
+///   state, such as [`GameState::Countdown`] or [`GameState::PostGoal`] (which
+///   includes goal replays), does.
+/// * `round_countdown` is not positive, i.e. no kickoff countdown is showing.
+///   A `round_countdown` of `None` doesn't rule out play.
+pub fn is_ball_in_play_from_signals(
+    ball_exists: bool,
+    ignore_syncing: bool,
+    game_state: Option<GameState>,
+    round_countdown: Option<i32>,
+) -> bool {
+    ball_exists
+        && !ignore_syncing
+        && game_state
+            .map(|state| state == GameState::Active)
+            .unwrap_or(true)
+        && round_countdown
+            .map(|countdown| countdown <= 0)
+            .unwrap_or(true)
+}
+
+/// [`ComponentStates`] bundles the boost level of a player's car with the
+/// states of its components. See [`ReplayProcessor::get_player_component_states`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ComponentStates {
+    /// The boost level, in the replicated units that range from 0 to 255. See
+    /// [`ReplayProcessor::get_player_boost_level`].
+    pub boost_amount: f32,
+    /// Whether the car is boosting.
+    pub boost_active: bool,
+    /// Whether the car's jump component is active.
+    pub jump_active: bool,
+    /// Whether the car's double jump component is active.
+    pub double_jump_active: bool,
+    /// Whether the car's dodge component is active.
+    pub dodge_active: bool,
+}
+
+/// The game mode of a replay, as determined by the archetype of its game event
+/// actor. See [`ReplayProcessor::get_game_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum GameMode {
+    /// Standard soccar, including any variants that share its game event
+    /// archetype.
+    Soccar,
+    /// Snowday, which is played with a puck (`Ball_Puck`) instead of a ball.
+    Hockey,
+    /// Hoops, which is played in the Dunk House arena with a basketball
+    /// (`Ball_Basketball`) and hoops instead of goals.
+    Hoops,
+    /// Dropshot, which is played in the Core 707 arena with a
+    /// `Ball_Breakout` ball and breakable floor tiles instead of goals.
+    Dropshot,
+    /// Gridiron, a limited time mode played with an American football.
+    Gridiron,
+    /// Rumble, soccar with power-ups, which has a game event archetype of its
+    /// own (`GameEvent_Items`).
+    Rumble,
+}
+
+impl GameMode {
+    /// Returns the [`GameMode`] whose game event actors have the object name
+    /// `game_event_type`, if any.
+    pub fn from_game_event_type(game_event_type: &str) -> Option<Self> {
+        if game_event_type == GAME_TYPE {
+            Some(Self::Soccar)
+        } else if game_event_type == HOCKEY_GAME_TYPE {
+            Some(Self::Hockey)
+        } else if game_event_type == HOOPS_GAME_TYPE {
+            Some(Self::Hoops)
+        } else if game_event_type == DROPSHOT_GAME_TYPE {
+            Some(Self::Dropshot)
+        } else if game_event_type == GRIDIRON_GAME_TYPE {
+            Some(Self::Gridiron)
+        } else if game_event_type == RUMBLE_GAME_TYPE {
+            Some(Self::Rumble)
+        } else {
+            None
+        }
+    }
+}
+
+/// The state of the game event, as replicated in its `ReplicatedStateName`
+/// attribute. See [`ReplayProcessor::get_game_state`].
+///
+/// The replicated value is a numeric code rather than a name. The codes of
+/// the states listed here are the ones observed in replays; any other code is
+/// preserved in [`GameState::Other`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum GameState {
+    /// No state has been replicated yet (code `0`), e.g. before the first
+    /// kickoff.
+    Inactive,
+    /// The countdown before a kickoff (code `55`).
+    Countdown,
+    /// Regular play, with the clock running (code `58`).
+    Active,
+    /// The time after a goal has been scored, while the goal replay is shown
+    /// (code `86`).
+    PostGoal,
+    /// A state whose code is not one of the above.
+    Other(i32),
+}
+
+impl GameState {
+    /// Returns the [`GameState`] corresponding to a replicated state code.
+    pub fn from_code(code: i32) -> Self {
+        match code {
+            0 => Self::Inactive,
+            55 => Self::Countdown,
+            58 => Self::Active,
+            86 => Self::PostGoal,
+            code => Self::Other(code),
+        }
+    }
+
+    /// Returns the replicated state code of this [`GameState`].
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::Inactive => 0,
+            Self::Countdown => 55,
+            Self::Active => 58,
+            Self::PostGoal => 86,
+            Self::Other(code) => *code,
+        }
+    }
+}
+
+/// Returns whether a ball at `ball_location` is above the rim of either hoop
+/// of the Hoops arena and horizontally within the rim, i.e. whether it is in
+/// position to drop into the basket. The dimensions of the hoops are only
+/// approximate, see [`HOOPS_RIM_Y`], so balls close to the edge of the zone
+/// may be misclassified.
+pub fn is_in_hoop_zone(ball_location: &boxcars::Vector3f) -> bool {
+    ball_location.z >= HOOPS_RIM_HEIGHT
+        && [-HOOPS_RIM_Y, HOOPS_RIM_Y].iter().any(|rim_y| {
+            let horizontal = glam::Vec2::new(ball_location.x, ball_location.y - rim_y);
+            horizontal.length() <= HOOPS_RIM_RADIUS
+        })
+}
+
+/// The direction the ball was headed shortly after the first touch of a
+/// kickoff.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum KickoffOutcome {
+    /// The ball was moving toward the goal defended by team 0 (negative y).
+    TowardTeamZeroGoal,
+    /// The ball was moving toward the goal defended by team 1 (positive y).
+    TowardTeamOneGoal,
+    /// The ball was not moving decisively toward either goal.
+    Neutral,
+}
+
+/// The spawn location of a player at the start of a kickoff on a standard
+/// soccar field. Left and right are from the perspective of the player's own
+/// team, facing the opposing goal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum KickoffPosition {
+    DiagonalLeft,
+    DiagonalRight,
+    OffCenterLeft,
+    OffCenterRight,
+    /// The spawn directly in front of the player's own goal.
+    Goalie,
+}
+
+impl KickoffPosition {
+    pub const ALL: [KickoffPosition; 5] = [
+        KickoffPosition::DiagonalLeft,
+        KickoffPosition::DiagonalRight,
+        KickoffPosition::OffCenterLeft,
+        KickoffPosition::OffCenterRight,
+        KickoffPosition::Goalie,
+    ];
+
+    /// Returns the `(x, y)` location of this spawn for team zero. The
+    /// locations for team one are the same with both coordinates negated.
+    pub fn team_zero_location(&self) -> (f32, f32) {
+        match self {
+            KickoffPosition::DiagonalLeft => (2048.0, -2560.0),
+            KickoffPosition::DiagonalRight => (-2048.0, -2560.0),
+            KickoffPosition::OffCenterLeft => (256.0, -3840.0),
+            KickoffPosition::OffCenterRight => (-256.0, -3840.0),
+            KickoffPosition::Goalie => (0.0, -4608.0),
+        }
+    }
+
+    /// Returns the spawn closest to `(x, y)` for a player of the given team,
+    /// if it is within `max_distance` of that location.
+    pub fn classify(x: f32, y: f32, is_team_0: bool, max_distance: f32) -> Option<Self> {
+        let (x, y) = if is_team_0 { (x, y) } else { (-x, -y) };
+        Self::ALL
+            .iter()
+            .map(|position| {
+                let (spawn_x, spawn_y) = position.team_zero_location();
+                (*position, (spawn_x - x).hypot(spawn_y - y))
+            })
+            .filter(|(_, distance)| *distance <= max_distance)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(position, _)| position)
+    }
+}
+
+/// [`KickoffResult`] pairs the start of a kickoff with the first touch of the
+/// ball that followed it.
+///
+/// The winner of a kickoff is the player who touched the ball first after the
+/// countdown. When two teammates both go for the ball (a double commit), only
+/// the first of them is credited; later touches never change the result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KickoffResult {
+    /// The frame number at which the kickoff countdown began.
+    pub frame: usize,
+    /// The game time (in seconds) at which the kickoff countdown began.
+    pub time: f32,
+    /// The frame number of the first touch after the kickoff, if any.
+    pub first_touch_frame: Option<usize>,
+    /// The [`PlayerId`] of the player who touched the ball first.
+    pub winner: Option<PlayerId>,
+    /// Whether the player who touched the ball first is on team 0.
+    pub winner_is_team_0: Option<bool>,
+    /// Where the ball was headed [`KICKOFF_OUTCOME_DELAY_SECONDS`] after the
+    /// first touch.
+    pub outcome: Option<KickoffOutcome>,
+}
+
+impl KickoffResult {
+    pub fn new(frame: usize, time: f32) -> Self {
+        Self {
+            frame,
+            time,
+            first_touch_frame: None,
+            winner: None,
+            winner_is_team_0: None,
+            outcome: None,
+        }
+    }
+}
+
+/// A serializable snapshot of every actor known to a [`ReplayProcessor`] at a
+/// given moment, as produced by [`ReplayProcessor::snapshot_actor_graph`].
+/// Actors reference one another through the [`boxcars::ActorId`] values that
+/// appear in their attributes.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ActorGraphSnapshot {
+    /// The snapshots of all current actors, ordered by actor id.
+    pub actors: Vec<ActorSnapshot>,
+}
+
+/// A serializable snapshot of a single actor. See [`ActorGraphSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ActorSnapshot {
+    pub actor_id: boxcars::ActorId,
+    /// The name of the object (i.e. the type) of the actor.
+    pub object_name: String,
+    /// The name of the actor instance, when the replay provides one.
+    pub name: Option<String>,
+    /// The current value of each attribute of the actor, ordered by name.
+    pub attributes: Vec<AttributeSnapshot>,
+}
+
+/// A serializable snapshot of one attribute of an actor. See
+/// [`ActorSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AttributeSnapshot {
+    pub name: String,
+    pub value: boxcars::Attribute,
+    /// The index of the frame in which the attribute was last updated.
+    pub updated_frame: usize,
+}
+
+/// The engine and network versions with which a replay was recorded. These
+/// can be used to gate behavior that depends on the replay format, such as the
+/// encoding of boost amounts or boost pad pickups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct EngineVersion {
+    /// The engine version of the replay.
+    pub major_version: i32,
+    /// The licensee version of the replay.
+    pub minor_version: i32,
+    /// The network version of the replay, which is absent in older replays.
+    pub net_version: Option<i32>,
+}
+
+impl EngineVersion {
+    pub fn from_replay(replay: &boxcars::Replay) -> Self {
+        Self {
+            major_version: replay.major_version,
+            minor_version: replay.minor_version,
+            net_version: replay.net_version,
+        }
+    }
+}
+
 /// [`ReplayMeta`] struct represents metadata about the replay being processed.
 ///
 /// This includes information about the players in the match and all replay headers.
@@ -45,6 +611,16 @@ pub struct ReplayMeta {
     pub team_one: Vec<PlayerInfo>,
     /// A vector of tuples containing the names and properties of all the headers in the replay.
     pub all_headers: Vec<(String, HeaderProp)>,
+    /// The [`EngineVersion`] with which the replay was recorded.
+    pub engine_version: EngineVersion,
+    /// The number of participants, such as spectators, that were not treated
+    /// as players. See [`ReplayProcessor::spectators`].
+    pub spectator_count: usize,
+    /// The custom name of team zero, such as a club or tournament team name,
+    /// if it had one. See [`Self::team_name`].
+    pub team_zero_name: Option<String>,
+    /// The custom name of team one, if it had one. See [`Self::team_name`].
+    pub team_one_name: Option<String>,
 }
 
 impl ReplayMeta {
@@ -58,6 +634,159 @@ impl ReplayMeta {
     pub fn player_order(&self) -> impl Iterator<Item = &PlayerInfo> {
         self.team_zero.iter().chain(self.team_one.iter())
     }
+
+    /// Returns the custom name of the given team, such as a club or
+    /// tournament team name. Returns `None` if the team had no custom name, as
+    /// is the case in most online matches.
+    pub fn team_name(&self, is_team_0: bool) -> Option<String> {
+        if is_team_0 {
+            self.team_zero_name.clone()
+        } else {
+            self.team_one_name.clone()
+        }
+    }
+
+    /// Returns whether the replay included a `PlayerStats` header. When it did
+    /// not, the `stats` of every [`PlayerInfo`] will be `None`.
+    pub fn has_player_stats(&self) -> bool {
+        get_player_stats_header(&self.all_headers).is_some()
+    }
+
+    /// Returns the final score of the match as `(team zero goals, team one
+    /// goals)`, read from the `Team0Score` and `Team1Score` headers.
+    ///
+    /// The game omits the header of a team that did not score, so a missing
+    /// header is treated as zero goals as long as the other one is present.
+    /// Returns `None` if neither header is present or if either can't be
+    /// parsed as a non-negative goal count. The headers are written by the
+    /// game, so they reflect the score as it was recorded rather than anything
+    /// derived while processing the network frames.
+    pub fn final_score(&self) -> Option<(u32, u32)> {
+        let get_score = |header: &str| {
+            self.all_headers
+                .iter()
+                .find(|(key, _)| key == header)
+                .map(|(_, prop)| match prop {
+                    HeaderProp::Int(value) => u32::try_from(*value).ok(),
+                    HeaderProp::QWord(value) => u32::try_from(*value).ok(),
+                    HeaderProp::Str(value) => value.trim().parse().ok(),
+                    _ => None,
+                })
+        };
+        match (get_score("Team0Score"), get_score("Team1Score")) {
+            (None, None) => None,
+            (team_zero, team_one) => {
+                Some((team_zero.unwrap_or(Some(0))?, team_one.unwrap_or(Some(0))?))
+            }
+        }
+    }
+
+    /// Returns the number of goals by which team zero won the match, which is
+    /// negative if team one won. See [`Self::final_score`].
+    pub fn score_differential(&self) -> Option<i32> {
+        self.final_score()
+            .map(|(team_zero, team_one)| team_zero as i32 - team_one as i32)
+    }
+
+    /// Returns the index of the team that won the match, `0` or `1`, as
+    /// determined by [`Self::final_score`]. Returns `None` if the score is
+    /// unknown or tied.
+    pub fn winning_team(&self) -> Option<usize> {
+        let (team_zero, team_one) = self.final_score()?;
+        match team_zero.cmp(&team_one) {
+            std::cmp::Ordering::Greater => Some(0),
+            std::cmp::Ordering::Less => Some(1),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Returns the most valuable player of the match. If the replay has an
+    /// `MVP` header naming a player, that player is returned. Otherwise the
+    /// MVP is the player of the [`Self::winning_team`] with the highest
+    /// `Score` in their [`PlayerInfo::stats`], as awarded by the game.
+    ///
+    /// Returns `None` if there is no winner, e.g. because the match was tied
+    /// or abandoned, or if there are no player stats, as is common for private
+    /// matches.
+    pub fn mvp(&self) -> Option<&PlayerInfo> {
+        let mvp_name = self.all_headers.iter().find_map(|(key, prop)| match prop {
+            HeaderProp::Str(value) if key == "MVP" => Some(value),
+            _ => None,
+        });
+        if let Some(mvp_name) = mvp_name {
+            if let Some(info) = self.player_order().find(|info| &info.name == mvp_name) {
+                return Some(info);
+            }
+        }
+        let winners = match self.winning_team()? {
+            0 => &self.team_zero,
+            _ => &self.team_one,
+        };
+        winners
+            .iter()
+            .filter_map(|info| match info.stats.as_ref()?.get("Score") {
+                Some(HeaderProp::Int(score)) => Some((info, *score)),
+                _ => None,
+            })
+            .max_by_key(|(_, score)| *score)
+            .map(|(info, _)| info)
+    }
+
+    /// Returns the value of the `MatchType` header, e.g. `"Online"` or
+    /// `"Private"`, or `None` if it is missing.
+    pub fn match_type(&self) -> Option<String> {
+        self.all_headers.iter().find_map(|(key, prop)| match prop {
+            HeaderProp::Str(value) if key == "MatchType" => Some(value.clone()),
+            _ => None,
+        })
+    }
+
+    /// Returns the value of the `Date` header, which records when the match
+    /// was played, as a [`chrono::NaiveDateTime`].
+    ///
+    /// The header uses dashes to separate the components of the time, e.g.
+    /// `"2021-05-16 19-20-30"`. Older replays that use colons instead are also
+    /// accepted. Returns `None` if the header is missing or can't be parsed.
+    #[cfg(feature = "chrono")]
+    pub fn recorded_at(&self) -> Option<chrono::NaiveDateTime> {
+        let date = self.all_headers.iter().find_map(|(key, prop)| match prop {
+            HeaderProp::Str(value) if key == "Date" => Some(value.trim()),
+            _ => None,
+        })?;
+        ["%Y-%m-%d %H-%M-%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d:%H-%M"]
+            .iter()
+            .find_map(|format| chrono::NaiveDateTime::parse_from_str(date, format).ok())
+    }
+}
+
+/// The camera settings of a player, mirroring [`boxcars::CamSettings`]. See
+/// [`ReplayProcessor::get_player_camera_settings`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CameraSettings {
+    /// The field of view, in degrees.
+    pub fov: f32,
+    pub height: f32,
+    /// The pitch of the camera, in degrees.
+    pub angle: f32,
+    pub distance: f32,
+    pub stiffness: f32,
+    pub swivel_speed: f32,
+    /// Only present in replays recorded after the setting was introduced.
+    pub transition_speed: Option<f32>,
+}
+
+impl From<&boxcars::CamSettings> for CameraSettings {
+    fn from(settings: &boxcars::CamSettings) -> Self {
+        Self {
+            fov: settings.fov,
+            height: settings.height,
+            angle: settings.angle,
+            distance: settings.distance,
+            stiffness: settings.stiffness,
+            swivel_speed: settings.swivel,
+            transition_speed: settings.transition,
+        }
+    }
 }
 
 /// [`PlayerInfo`] struct provides detailed information about a specific player in the replay.
@@ -71,8 +800,156 @@ pub struct PlayerInfo {
     /// The keys of this HashMap are the names of the stats,
     /// and the values are the corresponding `HeaderProp` instances.
     pub stats: Option<std::collections::HashMap<String, HeaderProp>>,
-    /// The name of the player as represented in the replay.
+    /// The name of the player as represented in the replay. This is kept
+    /// verbatim so that it can be used for matching, see
+    /// [`Self::display_name`] for a version that is safe to display.
     pub name: String,
+    /// Whether the player is controlled by the game's AI. This is taken from
+    /// the `bBot` entry of the player's stats when present, and otherwise from
+    /// the `bBot` attribute of the player's replication info. It defaults to
+    /// `false` when neither is available.
+    pub is_bot: bool,
+    /// The player's camera settings, if they were replicated, see
+    /// [`ReplayProcessor::get_player_camera_settings`].
+    pub camera_settings: Option<CameraSettings>,
+    /// A readable name for the platform the player played on, such as
+    /// `"Steam"`, `"Epic"` or `"PlayStation"`, see [`get_platform_name`].
+    /// `None` for local players, such as bots.
+    pub platform: Option<String>,
+}
+
+impl PlayerInfo {
+    /// Returns a version of [`Self::name`] that is safe to use in headers and
+    /// other output. Control and invisible formatting characters are removed,
+    /// double quotes and commas are replaced so that the name can't break CSV
+    /// columns, and surrounding whitespace is trimmed. If nothing is left, the
+    /// fallback `"Player {player_index}"` is returned instead.
+    pub fn display_name(&self, player_index: usize) -> String {
+        let sanitized: String = self
+            .name
+            .chars()
+            .filter(|c| !c.is_control() && !is_invisible_format_char(*c))
+            .map(|c| match c {
+                '"' => '\'',
+                ',' => ' ',
+                c => c,
+            })
+            .collect();
+        let trimmed = sanitized.trim();
+        if trimmed.is_empty() {
+            format!("Player {}", player_index)
+        } else {
+            trimmed.to_string()
+        }
+    }
+}
+
+/// Returns the value of the `bBot` entry of a player's `PlayerStats` header
+/// entry, if it has one.
+pub fn get_stats_is_bot(stats: &std::collections::HashMap<String, HeaderProp>) -> Option<bool> {
+    match stats.get("bBot") {
+        Some(HeaderProp::Bool(is_bot)) => Some(*is_bot),
+        _ => None,
+    }
+}
+
+fn is_invisible_format_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200F}' | '\u{2028}'..='\u{202E}' | '\u{2060}'..='\u{2064}' | '\u{FEFF}'
+    )
+}
+
+/// Returns the [`PlayerId`] of the player whose replication info actor,
+/// `actor_id`, has the given [`boxcars::UniqueId`].
+///
+/// Players are normally identified by their remote id. Local players, such as
+/// bots and players sharing a screen, don't have a remote id of their own:
+/// they either have a [`RemoteId::SplitScreen`] id, which is frequently shared
+/// between several of them, or, for the additional players of a split screen
+/// setup, the remote id of the primary player along with a non zero
+/// `local_id`. Such players are given a [`RemoteId::SplitScreen`] id derived
+/// from `actor_id`, so that each of them is tracked as a distinct player.
+pub fn get_player_id_from_unique_id(
+    actor_id: boxcars::ActorId,
+    unique_id: &boxcars::UniqueId,
+) -> PlayerId {
+    match &unique_id.remote_id {
+        RemoteId::SplitScreen(_) => RemoteId::SplitScreen(actor_id.0 as u32),
+        _ if unique_id.local_id != 0 => RemoteId::SplitScreen(actor_id.0 as u32),
+        remote_id => remote_id.clone(),
+    }
+}
+
+/// Returns the per player entries of the `PlayerStats` header, if the replay
+/// has one.
+pub fn get_player_stats_header(
+    headers: &[(String, HeaderProp)],
+) -> Option<&Vec<Vec<(String, HeaderProp)>>> {
+    headers
+        .iter()
+        .find(|(key, _)| key == "PlayerStats")
+        .and_then(|(_, prop)| match prop {
+            HeaderProp::Array(per_player) => Some(per_player),
+            _ => None,
+        })
+}
+
+/// Returns the [`PlayerId`]s of the players of team zero and team one, in that
+/// order, as listed in the `PlayerStats` header, or `None` if the header is
+/// missing or does not fully identify every player.
+///
+/// Each entry must have a `Team` of `0` or `1` and an `OnlineID` from which
+/// its [`PlayerId`] can be rebuilt. That is only the case for steam and xbox
+/// players, since the remote ids of the other platforms carry data the header
+/// doesn't include. Bots, local players and entries sharing an id are also
+/// rejected, since their ids are derived from their actors (see
+/// [`get_player_id_from_unique_id`]). The players of each team are sorted by
+/// the debug representation of their ids, like
+/// [`ReplayProcessor::iter_player_ids_in_order`] expects.
+pub fn get_player_order_from_headers(
+    headers: &[(String, HeaderProp)],
+) -> Option<(Vec<PlayerId>, Vec<PlayerId>)> {
+    let player_stats = get_player_stats_header(headers)?;
+    if player_stats.is_empty() {
+        return None;
+    }
+    let mut team_zero = Vec::new();
+    let mut team_one = Vec::new();
+    for props in player_stats.iter() {
+        if let Ok((_, HeaderProp::Bool(true))) = get_prop("bBot", props) {
+            return None;
+        }
+        let online_id = match get_prop("OnlineID", props) {
+            Ok((_, HeaderProp::QWord(online_id))) if online_id != 0 => online_id,
+            _ => return None,
+        };
+        let player_id = match get_prop("Platform", props) {
+            Ok((
+                _,
+                HeaderProp::Byte {
+                    kind: _,
+                    value: Some(value),
+                },
+            )) => match value.as_ref() {
+                "OnlinePlatform_Steam" => RemoteId::Steam(online_id),
+                "OnlinePlatform_Dingo" => RemoteId::Xbox(online_id),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if team_zero.contains(&player_id) || team_one.contains(&player_id) {
+            return None;
+        }
+        match get_prop("Team", props) {
+            Ok((_, HeaderProp::Int(0))) => team_zero.push(player_id),
+            Ok((_, HeaderProp::Int(1))) => team_one.push(player_id),
+            _ => return None,
+        }
+    }
+    team_zero.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+    team_one.sort_by(|a, b| format!("{:?}", a).cmp(&format!("{:?}", b)));
+    Some((team_zero, team_one))
 }
 
 pub fn find_player_stats(
@@ -93,6 +970,12 @@ pub fn find_player_stats(
 }
 
 fn matches_stats(player_id: &RemoteId, name: &String, props: &Vec<(String, HeaderProp)>) -> bool {
+    // Local players have no online identity, so the platform and online id of
+    // their stats can't be matched against their id. See
+    // get_player_id_from_unique_id.
+    if let RemoteId::SplitScreen(_) = player_id {
+        return name_matches(name, props);
+    }
     if platform_matches(player_id, props) != Ok(true) {
         return false;
     }
@@ -123,6 +1006,43 @@ fn online_id_matches(id: u64, props: &Vec<(String, HeaderProp)>) -> bool {
     }
 }
 
+/// Returns a readable name for the platform a player played on, such as
+/// `"Steam"`, `"Epic"` or `"PlayStation"`. The `Platform` entry of the player's
+/// `PlayerStats` header entry is used when `stats` has one, and the platform
+/// is otherwise inferred from the variant of `remote_id`. Returns `None` for
+/// local players, whose ids carry no platform.
+pub fn get_platform_name(
+    remote_id: &RemoteId,
+    stats: Option<&std::collections::HashMap<String, HeaderProp>>,
+) -> Option<String> {
+    let from_stats = stats
+        .and_then(|stats| match stats.get("Platform") {
+            Some(HeaderProp::Byte {
+                kind: _,
+                value: Some(value),
+            }) => Some(value.as_str()),
+            _ => None,
+        })
+        .and_then(|value| match value {
+            "OnlinePlatform_Steam" => Some("Steam"),
+            "OnlinePlatform_Epic" => Some("Epic"),
+            "OnlinePlatform_PS4" | "OnlinePlatform_PS3" => Some("PlayStation"),
+            "OnlinePlatform_Dingo" => Some("Xbox"),
+            "OnlinePlatform_Switch" => Some("Switch"),
+            _ => None,
+        });
+    let from_remote_id = || match remote_id {
+        RemoteId::Steam(_) => Some("Steam"),
+        RemoteId::Epic(_) => Some("Epic"),
+        RemoteId::PlayStation(_) | RemoteId::PsyNet(_) => Some("PlayStation"),
+        RemoteId::Xbox(_) => Some("Xbox"),
+        RemoteId::Switch(_) => Some("Switch"),
+        RemoteId::QQ(_) => Some("QQ"),
+        RemoteId::SplitScreen(_) => None,
+    };
+    from_stats.or_else(from_remote_id).map(str::to_string)
+}
+
 fn platform_matches(
     player_id: &RemoteId,
     props: &Vec<(String, HeaderProp)>,
@@ -200,6 +1120,47 @@ impl<K: PartialEq + Clone, V> VecMapEntry<K, V> for Vec<(K, V)> {
     }
 }
 
+/// Linearly extrapolates the motion of a ball at `location` moving with
+/// `velocity` to the goal line at `goal_y`. Returns the number of seconds it
+/// takes to reach the goal line, along with whether it crosses the line within
+/// the mouth of the goal, or `None` if the ball is not moving toward that goal
+/// line. Gravity, drag and bounces are ignored, so a ball that would pass
+/// below the floor is still considered to be within the goal mouth.
+pub fn extrapolate_to_goal_line(
+    location: &boxcars::Vector3f,
+    velocity: &boxcars::Vector3f,
+    goal_y: f32,
+) -> Option<(f32, bool)> {
+    let time_to_goal_line = (goal_y - location.y) / velocity.y;
+    if !time_to_goal_line.is_finite() || time_to_goal_line < 0.0 {
+        return None;
+    }
+    let x_at_goal_line = location.x + velocity.x * time_to_goal_line;
+    let z_at_goal_line = location.z + velocity.z * time_to_goal_line;
+    let on_target = x_at_goal_line.abs() <= GOAL_HALF_WIDTH && z_at_goal_line <= GOAL_HEIGHT;
+    Some((time_to_goal_line, on_target))
+}
+
+/// Returns the number of seconds until a ball at `location` moving with
+/// `velocity` enters the goal of the given team, according to
+/// [`extrapolate_to_goal_line`], if it is on target for that goal and gets
+/// there within `max_seconds`.
+pub fn get_seconds_to_goal(
+    location: &boxcars::Vector3f,
+    velocity: &boxcars::Vector3f,
+    goal_is_team_0: bool,
+    max_seconds: f32,
+) -> Option<f32> {
+    let goal_y = if goal_is_team_0 {
+        -GOAL_LINE_Y
+    } else {
+        GOAL_LINE_Y
+    };
+    extrapolate_to_goal_line(location, velocity, goal_y)
+        .filter(|(seconds, on_target)| *on_target && *seconds <= max_seconds)
+        .map(|(seconds, _)| seconds)
+}
+
 pub fn vec_to_glam(v: &boxcars::Vector3f) -> glam::f32::Vec3 {
     glam::f32::Vec3::new(v.x, v.y, v.z)
 }
@@ -212,10 +1173,206 @@ pub fn glam_to_vec(v: &glam::f32::Vec3) -> boxcars::Vector3f {
     }
 }
 
+/// Returns the boost amount, in the replicated units that range from 0 to 255,
+/// of a car one frame after it had `previous_amount`.
+///
+/// A `replicated_amount` received in the frame is the server's value at that
+/// frame, and so already accounts for any boost used while boosting. It is
+/// therefore returned as is, and consumption at [`BOOST_USED_PER_SECOND`] is
+/// only applied over `delta` seconds when no new amount was replicated.
+pub fn advance_boost_amount(
+    previous_amount: f32,
+    replicated_amount: Option<f32>,
+    is_active: bool,
+    delta: f32,
+) -> f32 {
+    let amount = match replicated_amount {
+        Some(amount) => amount,
+        None if is_active => previous_amount - delta * BOOST_USED_PER_SECOND,
+        None => previous_amount,
+    };
+    amount.max(0.0)
+}
+
+/// Converts a replicated control input byte, such as throttle or steer, which
+/// is centered at 128, to a float in the range `[-1.0, 1.0]`.
+pub fn normalize_replicated_input(value: u8) -> f32 {
+    ((value as f32 - 128.0) / 127.0).clamp(-1.0, 1.0)
+}
+
+/// Returns whether a car moving at `speed` is supersonic, given whether it was
+/// supersonic before. Cars become supersonic at [`SUPERSONIC_SPEED`] and only
+/// stop being supersonic below [`SUPERSONIC_EXIT_SPEED`].
+pub fn is_supersonic(was_supersonic: bool, speed: f32) -> bool {
+    if was_supersonic {
+        speed >= SUPERSONIC_EXIT_SPEED
+    } else {
+        speed >= SUPERSONIC_SPEED
+    }
+}
+
+/// Approximates whether a car with the given rigid body has its wheels on the
+/// floor of the field. The car is considered on the ground when its center is
+/// within [`CAR_ON_GROUND_MAX_HEIGHT`] of [`FIELD_FLOOR_Z`], its up vector has a
+/// z component of at least [`CAR_ON_GROUND_MIN_UP_Z`], and its vertical speed
+/// is at most [`CAR_ON_GROUND_MAX_VERTICAL_SPEED`].
+///
+/// Cars driving on walls or the ceiling are not considered on the ground, and
+/// neither is a car resting on its roof.
+pub fn is_car_on_ground(rigid_body: &boxcars::RigidBody) -> bool {
+    let height = rigid_body.location.z - FIELD_FLOOR_Z;
+    let up = quat_to_glam(&rigid_body.rotation).normalize() * glam::Vec3::Z;
+    let vertical_speed = rigid_body
+        .linear_velocity
+        .map(|velocity| velocity.z.abs())
+        .unwrap_or(0.0);
+    height <= CAR_ON_GROUND_MAX_HEIGHT
+        && up.z >= CAR_ON_GROUND_MIN_UP_Z
+        && vertical_speed <= CAR_ON_GROUND_MAX_VERTICAL_SPEED
+}
+
+/// Tracks the game clock between the integer ticks of the replicated seconds
+/// remaining, so that it can be read as a continuous value.
+///
+/// The clock only advances while it is `running`, i.e. while the ball is in
+/// play. While it is stopped (during kickoff countdowns, goal replays, etc.)
+/// the value is held steady. The time elapsed since the last tick is capped at
+/// one second, so the value never drops below the next integer tick before
+/// that tick is replicated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecondsRemainingClock {
+    /// The last replicated seconds remaining.
+    pub seconds_remaining: i32,
+    /// The running time elapsed since `seconds_remaining` was replicated, as
+    /// of `time`.
+    pub elapsed: f32,
+    /// The replay time at which the clock was last updated.
+    pub time: f32,
+    /// Whether the clock was running when it was last updated.
+    pub running: bool,
+}
+
+impl SecondsRemainingClock {
+    /// Creates a clock that has just ticked to `seconds_remaining` at `time`.
+    pub fn new(seconds_remaining: i32, time: f32, running: bool) -> Self {
+        Self {
+            seconds_remaining,
+            elapsed: 0.0,
+            time,
+            running,
+        }
+    }
+
+    /// Returns the clock as of `time`, given the seconds remaining replicated
+    /// at that time and whether the clock is running from then on.
+    pub fn advance(self, seconds_remaining: i32, time: f32, running: bool) -> Self {
+        if seconds_remaining != self.seconds_remaining {
+            return Self::new(seconds_remaining, time, running);
+        }
+        Self {
+            seconds_remaining,
+            elapsed: self.elapsed_at(time),
+            time,
+            running,
+        }
+    }
+
+    /// Returns the continuous seconds remaining at `current_time`.
+    pub fn seconds_remaining_at(&self, current_time: f32) -> f32 {
+        (self.seconds_remaining as f32 - self.elapsed_at(current_time)).max(0.0)
+    }
+
+    fn elapsed_at(&self, current_time: f32) -> f32 {
+        let since_update = if self.running {
+            (current_time - self.time).max(0.0)
+        } else {
+            0.0
+        };
+        (self.elapsed + since_update).min(1.0)
+    }
+}
+
+/// Returns the distances from `location` to the centers of the team zero
+/// (blue) and team one (orange) goals, in that order. See
+/// [`TEAM_ZERO_GOAL_CENTER`] and [`TEAM_ONE_GOAL_CENTER`].
+pub fn get_goal_distances(location: &boxcars::Vector3f) -> (f32, f32) {
+    let location = vec_to_glam(location);
+    let distance_to = |(x, y, z): (f32, f32, f32)| location.distance(glam::Vec3::new(x, y, z));
+    (
+        distance_to(TEAM_ZERO_GOAL_CENTER),
+        distance_to(TEAM_ONE_GOAL_CENTER),
+    )
+}
+
+/// Returns which third of the field, relative to the team given by
+/// `is_team_0`, contains the y coordinate `y`: `0` for the third containing
+/// the team's own goal, `1` for the middle third and `2` for the third
+/// containing the opponent's goal. The thirds are divided at
+/// ±[`FIELD_THIRD_BOUNDARY_Y`], and locations behind a goal line belong to the
+/// third in front of it.
+pub fn get_field_third(y: f32, is_team_0: bool) -> usize {
+    // Team zero defends the goal at -GOAL_LINE_Y.
+    let team_relative_y = if is_team_0 { y } else { -y };
+    if team_relative_y < -FIELD_THIRD_BOUNDARY_Y {
+        0
+    } else if team_relative_y > FIELD_THIRD_BOUNDARY_Y {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the median of `values`, sorting them in the process, or `None` if
+/// `values` is empty.
+pub fn median(values: &mut [f32]) -> Option<f32> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let middle = values.len() / 2;
+    Some(if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    })
+}
+
 pub fn quat_to_glam(q: &boxcars::Quaternion) -> glam::Quat {
     glam::Quat::from_xyzw(q.x, q.y, q.z, q.w)
 }
 
+/// Returns the angle, in radians, of the smallest rotation that takes
+/// `from` to `to`. Because `q` and `-q` represent the same orientation, the
+/// absolute value of the dot product is used, so the result is always in the
+/// range `[0, PI]`.
+pub fn quaternion_angle_between(from: &boxcars::Quaternion, to: &boxcars::Quaternion) -> f32 {
+    let dot = quat_to_glam(from)
+        .normalize()
+        .dot(quat_to_glam(to).normalize())
+        .abs();
+    2.0 * dot.min(1.0).acos()
+}
+
+/// Expresses `rotation` from the perspective of the given team, so that
+/// orientations relative to a team's own goal are the same for both teams.
+///
+/// Team zero rotations are returned unchanged. Team one rotations are composed
+/// with a 180 degree rotation about the z (yaw) axis, applied in world space,
+/// i.e. `q' = q_flip * q` where `q_flip = (x: 0, y: 0, z: 1, w: 0)`. In terms
+/// of components this is `(x, y, z, w) -> (-y, x, w, -z)`. This mirrors the
+/// mapping of team one locations `(x, y, z) -> (-x, -y, z)`.
+pub fn to_team_relative_rotation(
+    rotation: &boxcars::Quaternion,
+    is_team_0: bool,
+) -> boxcars::Quaternion {
+    if is_team_0 {
+        *rotation
+    } else {
+        let flip = glam::Quat::from_rotation_z(std::f32::consts::PI);
+        glam_to_quat(&(flip * quat_to_glam(rotation)))
+    }
+}
+
 pub fn glam_to_quat(rotation: &glam::Quat) -> boxcars::Quaternion {
     boxcars::Quaternion {
         x: rotation.x,
@@ -244,39 +1401,28 @@ pub fn apply_velocities_to_rigid_body(
     interpolated
 }
 
+/// Rotates the rigid body by its angular velocity over `time_delta` seconds.
+///
+/// This assumes that the replicated angular velocity is in radians per second
+/// and expressed in world coordinates, like the angular velocity of the
+/// underlying physics engine, so the resulting rotation is applied on the left
+/// of the body's current rotation. Neither assumption has been checked against
+/// real replay data yet.
+///
+/// XXX: If extrapolated rotations drift from the next replicated rotation,
+/// the units or the frame of the angular velocity are the first suspects.
 fn apply_angular_velocity(rigid_body: &boxcars::RigidBody, time_delta: f32) -> boxcars::Quaternion {
-    // XXX: This approach seems to give some unexpected results. There may be a
-    // unit mismatch or some other type of issue.
-    let rbav = rigid_body
-        .angular_velocity
-        .unwrap_or_else(|| boxcars::Vector3f {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
-        });
-    let angular_velocity = glam::Vec3::new(rbav.x, rbav.y, rbav.z);
-    let magnitude = angular_velocity.length();
-    let angular_velocity_unit_vector = angular_velocity.normalize_or_zero();
-
-    let mut rotation = glam::Quat::from_xyzw(
-        rigid_body.rotation.x,
-        rigid_body.rotation.y,
-        rigid_body.rotation.z,
-        rigid_body.rotation.w,
-    );
-
-    if angular_velocity_unit_vector.length() != 0.0 {
-        let delta_rotation =
-            glam::Quat::from_axis_angle(angular_velocity_unit_vector, magnitude * time_delta);
-        rotation *= delta_rotation;
-    }
-
-    boxcars::Quaternion {
-        x: rotation.x,
-        y: rotation.y,
-        z: rotation.z,
-        w: rotation.w,
+    let rotation = quat_to_glam(&rigid_body.rotation);
+    let angular_velocity = match rigid_body.angular_velocity.as_ref() {
+        Some(angular_velocity) => vec_to_glam(angular_velocity),
+        None => return rigid_body.rotation,
+    };
+    let angle = angular_velocity.length() * time_delta;
+    if angle == 0.0 {
+        return rigid_body.rotation;
     }
+    let delta_rotation = glam::Quat::from_axis_angle(angular_velocity.normalize(), angle);
+    glam_to_quat(&(delta_rotation * rotation).normalize())
 }
 
 /// Interpolates between two [`boxcars::RigidBody`] states based on the provided time.
@@ -325,6 +1471,154 @@ pub fn get_interpolated_rigid_body(
     })
 }
 
+/// Returns the time weighted mean of a sequence of rigid body states over the
+/// window from `start_time` to `end_time`.
+///
+/// `updates` are `(time, rigid body)` pairs in chronological order. Each state
+/// holds from its time until the time of the next one, and the last one holds
+/// until `end_time`. Only the part of each state's duration that falls within
+/// the window contributes to the mean, so states from before the window should
+/// be included to cover its start, while states from after it are ignored.
+///
+/// The rotation is the normalized weighted mean of the quaternions, after
+/// flipping them into the same hemisphere as the last one, which approximates
+/// the mean rotation well for the small rotations that happen within a short
+/// window. Velocities are averaged over the states that have them, and are
+/// `None` if none do. `sleeping` is taken from the last state.
+///
+/// Returns `None` if `updates` is empty, and the last state if none of the
+/// states overlap the window, e.g. when it has no duration.
+pub fn get_time_weighted_mean_rigid_body(
+    updates: &[(f32, boxcars::RigidBody)],
+    start_time: f32,
+    end_time: f32,
+) -> Option<boxcars::RigidBody> {
+    let (_, last_body) = updates.last()?;
+    let reference_rotation = quat_to_glam(&last_body.rotation);
+    let mut total_weight = 0.0;
+    let mut location = glam::Vec3::ZERO;
+    let mut rotation = glam::Vec4::ZERO;
+    let mut linear_velocity = (glam::Vec3::ZERO, 0.0);
+    let mut angular_velocity = (glam::Vec3::ZERO, 0.0);
+    for (index, (time, rigid_body)) in updates.iter().enumerate() {
+        let next_time = updates
+            .get(index + 1)
+            .map(|(next_time, _)| *next_time)
+            .unwrap_or(end_time);
+        let weight = (next_time.min(end_time) - time.max(start_time)).max(0.0);
+        if weight == 0.0 {
+            continue;
+        }
+        total_weight += weight;
+        location += vec_to_glam(&rigid_body.location) * weight;
+        let body_rotation = quat_to_glam(&rigid_body.rotation);
+        let body_rotation = if body_rotation.dot(reference_rotation) < 0.0 {
+            -body_rotation
+        } else {
+            body_rotation
+        };
+        rotation += glam::Vec4::from(body_rotation) * weight;
+        if let Some(velocity) = rigid_body.linear_velocity {
+            linear_velocity.0 += vec_to_glam(&velocity) * weight;
+            linear_velocity.1 += weight;
+        }
+        if let Some(velocity) = rigid_body.angular_velocity {
+            angular_velocity.0 += vec_to_glam(&velocity) * weight;
+            angular_velocity.1 += weight;
+        }
+    }
+    if total_weight == 0.0 {
+        return Some(*last_body);
+    }
+    let mean_velocity = |(sum, weight): (glam::Vec3, f32)| {
+        if weight > 0.0 {
+            Some(glam_to_vec(&(sum / weight)))
+        } else {
+            None
+        }
+    };
+    Some(boxcars::RigidBody {
+        sleeping: last_body.sleeping,
+        location: glam_to_vec(&(location / total_weight)),
+        rotation: glam_to_quat(&glam::Quat::from_vec4(rotation).normalize()),
+        linear_velocity: mean_velocity(linear_velocity),
+        angular_velocity: mean_velocity(angular_velocity),
+    })
+}
+
+/// Returns the [`RigidBody`](boxcars::RigidBody) of the actor at `time`, which
+/// may be any time within `frames`, by interpolating between the last rigid
+/// body update at or before `time` and the first one after it, see
+/// [`get_interpolated_rigid_body`]. `object_id` is the object id of the rigid
+/// body attribute.
+///
+/// An update within `close_enough` seconds of `time` is returned as is. When
+/// there is no update on one side of `time`, the update on the other side is
+/// only returned if it is close enough, and an error is returned otherwise.
+/// [`SubtrActorErrorVariant::TimeOutsideReplay`] is returned if `time` is
+/// before the first frame or after the last one.
+pub fn get_actor_rigid_body_at_time(
+    frames: &[boxcars::Frame],
+    actor_id: &boxcars::ActorId,
+    object_id: &boxcars::ObjectId,
+    time: f32,
+    close_enough: f32,
+) -> SubtrActorResult<boxcars::RigidBody> {
+    let is_within_replay = match (frames.first(), frames.last()) {
+        (Some(first), Some(last)) => first.time <= time && time <= last.time,
+        _ => false,
+    };
+    if !is_within_replay {
+        return SubtrActorError::new_result(SubtrActorErrorVariant::TimeOutsideReplay { time });
+    }
+    let find_rigid_body = |frame: &boxcars::Frame| {
+        frame
+            .updated_actors
+            .iter()
+            .find(|update| &update.actor_id == actor_id && &update.object_id == object_id)
+            .and_then(|update| match &update.attribute {
+                boxcars::Attribute::RigidBody(rigid_body) => Some(*rigid_body),
+                _ => None,
+            })
+    };
+    // The index of the last frame at or before time.
+    let frame_index = frames.partition_point(|frame| frame.time <= time) - 1;
+    let before = find_in_direction(
+        frames,
+        frame_index + 1,
+        SearchDirection::Backward,
+        find_rigid_body,
+    );
+    let after = find_in_direction(
+        frames,
+        frame_index,
+        SearchDirection::Forward,
+        find_rigid_body,
+    );
+    let is_close_enough = |(index, _): &(usize, boxcars::RigidBody)| {
+        (frames[*index].time - time).abs() <= close_enough
+    };
+
+    match (before, after) {
+        (Some(before), _) if is_close_enough(&before) => Ok(before.1),
+        (_, Some(after)) if is_close_enough(&after) => Ok(after.1),
+        (Some((before_index, before_body)), Some((after_index, after_body))) => {
+            get_interpolated_rigid_body(
+                &before_body,
+                frames[before_index].time,
+                &after_body,
+                frames[after_index].time,
+                time,
+            )
+        }
+        _ => SubtrActorError::new_result(SubtrActorErrorVariant::NoUpdateAfterFrame {
+            actor_id: *actor_id,
+            object_id: *object_id,
+            frame_index,
+        }),
+    }
+}
+
 /// Enum to define the direction of searching within a collection.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum SearchDirection {
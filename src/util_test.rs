@@ -2,6 +2,40 @@ use super::*;
 use boxcars::Quaternion;
 use boxcars::Vector3f;
 
+/// Returns a [`PlayerInfo`] for a human player without stats.
+fn test_player_info(name: &str) -> PlayerInfo {
+    PlayerInfo {
+        remote_id: boxcars::RemoteId::SplitScreen(0),
+        stats: None,
+        name: name.to_string(),
+        is_bot: false,
+        camera_settings: None,
+        platform: None,
+    }
+}
+
+/// Returns a [`ReplayMeta`] of a recent engine version, without spectators or
+/// custom team names.
+fn test_replay_meta(
+    team_zero: Vec<PlayerInfo>,
+    team_one: Vec<PlayerInfo>,
+    all_headers: Vec<(String, boxcars::HeaderProp)>,
+) -> ReplayMeta {
+    ReplayMeta {
+        team_zero,
+        team_one,
+        all_headers,
+        engine_version: EngineVersion {
+            major_version: 868,
+            minor_version: 32,
+            net_version: Some(10),
+        },
+        spectator_count: 0,
+        team_zero_name: None,
+        team_one_name: None,
+    }
+}
+
 #[test]
 fn test_get_interpolated_rigid_body() {
     let start_body = boxcars::RigidBody {
@@ -87,3 +121,2729 @@ fn test_find_update_in_direction() {
     // Check that the result is as expected.
     assert_eq!(result_backward, Some((3, 4))); // First even number before index 4 is 4 at index 3
 }
+
+#[test]
+fn test_has_player_stats() {
+    let mut meta = test_replay_meta(
+        Vec::new(),
+        Vec::new(),
+        vec![("TeamSize".to_string(), boxcars::HeaderProp::Int(1))],
+    );
+    assert!(!meta.has_player_stats());
+
+    meta.all_headers.push((
+        "PlayerStats".to_string(),
+        boxcars::HeaderProp::Array(Vec::new()),
+    ));
+    assert!(meta.has_player_stats());
+}
+
+#[test]
+fn test_final_score() {
+    let mut meta = test_replay_meta(Vec::new(), Vec::new(), Vec::new());
+    assert_eq!(meta.final_score(), None);
+    assert_eq!(meta.score_differential(), None);
+
+    meta.all_headers
+        .push(("Team1Score".to_string(), boxcars::HeaderProp::Int(3)));
+    assert_eq!(meta.final_score(), Some((0, 3)));
+    assert_eq!(meta.score_differential(), Some(-3));
+
+    meta.all_headers
+        .push(("Team0Score".to_string(), boxcars::HeaderProp::Int(4)));
+    assert_eq!(meta.final_score(), Some((4, 3)));
+    assert_eq!(meta.score_differential(), Some(1));
+
+    meta.all_headers[0].1 = boxcars::HeaderProp::Int(-1);
+    assert_eq!(meta.final_score(), None);
+}
+
+#[test]
+fn test_winning_team_and_mvp() {
+    let player = |name: &str, score: Option<i32>| PlayerInfo {
+        stats: score.map(|score| {
+            [("Score".to_string(), boxcars::HeaderProp::Int(score))]
+                .into_iter()
+                .collect()
+        }),
+        ..test_player_info(name)
+    };
+    let mut meta = test_replay_meta(
+        vec![player("A", Some(300)), player("B", Some(450))],
+        vec![player("C", Some(600))],
+        vec![
+            ("Team0Score".to_string(), boxcars::HeaderProp::Int(2)),
+            ("Team1Score".to_string(), boxcars::HeaderProp::Int(2)),
+            (
+                "MatchType".to_string(),
+                boxcars::HeaderProp::Str("Online".to_string()),
+            ),
+        ],
+    );
+    assert_eq!(meta.match_type(), Some("Online".to_string()));
+    assert_eq!(meta.winning_team(), None);
+    assert_eq!(meta.mvp(), None);
+
+    meta.all_headers[0].1 = boxcars::HeaderProp::Int(3);
+    assert_eq!(meta.winning_team(), Some(0));
+    assert_eq!(meta.mvp().map(|info| info.name.as_str()), Some("B"));
+
+    meta.all_headers
+        .push(("MVP".to_string(), boxcars::HeaderProp::Str("A".to_string())));
+    assert_eq!(meta.mvp().map(|info| info.name.as_str()), Some("A"));
+
+    meta.all_headers.pop();
+    meta.team_zero = vec![player("A", None)];
+    assert_eq!(meta.mvp(), None);
+}
+
+#[test]
+fn test_game_state_codes() {
+    for code in [0, 55, 58, 86, 12] {
+        assert_eq!(GameState::from_code(code).code(), code);
+    }
+    assert_eq!(GameState::from_code(55), GameState::Countdown);
+    assert_eq!(GameState::from_code(12), GameState::Other(12));
+}
+
+#[test]
+fn test_is_in_hoop_zone() {
+    let above_rim = boxcars::Vector3f {
+        x: 100.0,
+        y: HOOPS_RIM_Y - 100.0,
+        z: HOOPS_RIM_HEIGHT + 50.0,
+    };
+    assert!(is_in_hoop_zone(&above_rim));
+    assert!(is_in_hoop_zone(&boxcars::Vector3f {
+        y: -above_rim.y,
+        ..above_rim
+    }));
+    assert!(!is_in_hoop_zone(&boxcars::Vector3f {
+        z: HOOPS_RIM_HEIGHT - 50.0,
+        ..above_rim
+    }));
+    assert!(!is_in_hoop_zone(&boxcars::Vector3f {
+        y: 0.0,
+        ..above_rim
+    }));
+}
+
+#[test]
+fn test_advance_boost_amount() {
+    let delta = 0.5;
+    let used = delta * BOOST_USED_PER_SECOND;
+    assert_eq!(advance_boost_amount(100.0, None, true, delta), 100.0 - used);
+    assert_eq!(advance_boost_amount(100.0, None, false, delta), 100.0);
+    // A freshly replicated amount already includes the boost that was used.
+    assert_eq!(advance_boost_amount(100.0, Some(90.0), true, delta), 90.0);
+    assert_eq!(advance_boost_amount(10.0, None, true, delta), 0.0);
+}
+
+#[test]
+fn test_is_ball_in_play_from_signals() {
+    // Open play.
+    assert!(is_ball_in_play_from_signals(
+        true,
+        false,
+        Some(GameState::Active),
+        Some(0)
+    ));
+    // Replays that replicate neither the game state nor the countdown.
+    assert!(is_ball_in_play_from_signals(true, false, None, None));
+    // Kickoff countdown.
+    assert!(!is_ball_in_play_from_signals(
+        true,
+        false,
+        Some(GameState::Countdown),
+        Some(3)
+    ));
+    assert!(!is_ball_in_play_from_signals(true, false, None, Some(2)));
+    // Goal replay.
+    assert!(!is_ball_in_play_from_signals(
+        true,
+        false,
+        Some(GameState::PostGoal),
+        Some(0)
+    ));
+    // Hidden or missing ball.
+    assert!(!is_ball_in_play_from_signals(
+        true,
+        true,
+        Some(GameState::Active),
+        Some(0)
+    ));
+    assert!(!is_ball_in_play_from_signals(
+        false,
+        false,
+        Some(GameState::Active),
+        Some(0)
+    ));
+}
+
+#[test]
+fn test_normalize_replicated_input() {
+    assert_eq!(normalize_replicated_input(128), 0.0);
+    assert_eq!(normalize_replicated_input(255), 1.0);
+    assert_eq!(normalize_replicated_input(0), -1.0);
+    assert_eq!(normalize_replicated_input(1), -1.0);
+}
+
+#[test]
+fn test_player_display_name() {
+    let mut info = test_player_info("  \u{200B}Some, \"Name\"\n ");
+    assert_eq!(info.display_name(2), "Some  'Name'");
+    assert_eq!(info.name, "  \u{200B}Some, \"Name\"\n ");
+
+    info.name = "\u{FEFF} \t".to_string();
+    assert_eq!(info.display_name(2), "Player 2");
+}
+
+#[test]
+fn test_kickoff_position_classify() {
+    assert_eq!(
+        KickoffPosition::classify(2000.0, -2500.0, true, 300.0),
+        Some(KickoffPosition::DiagonalLeft)
+    );
+    assert_eq!(
+        KickoffPosition::classify(2048.0, 2560.0, false, 300.0),
+        Some(KickoffPosition::DiagonalRight)
+    );
+    assert_eq!(
+        KickoffPosition::classify(0.0, 4608.0, false, 300.0),
+        Some(KickoffPosition::Goalie)
+    );
+    assert_eq!(KickoffPosition::classify(0.0, 0.0, true, 300.0), None);
+}
+
+#[test]
+fn test_team_relative_rotation() {
+    // A car with a yaw of -90 degrees faces the team zero goal, and one with a
+    // yaw of 90 degrees faces the team one goal.
+    let facing_team_zero_goal =
+        glam_to_quat(&glam::Quat::from_rotation_z(-std::f32::consts::FRAC_PI_2));
+    let facing_team_one_goal =
+        glam_to_quat(&glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+
+    let team_zero_relative = to_team_relative_rotation(&facing_team_zero_goal, true);
+    let team_one_relative = to_team_relative_rotation(&facing_team_one_goal, false);
+
+    let team_zero_forward = quat_to_glam(&team_zero_relative) * glam::Vec3::X;
+    let team_one_forward = quat_to_glam(&team_one_relative) * glam::Vec3::X;
+    assert!(team_zero_forward.abs_diff_eq(team_one_forward, 1e-5));
+    assert!(team_zero_forward.abs_diff_eq(glam::Vec3::NEG_Y, 1e-5));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_recorded_at() {
+    let mut meta = test_replay_meta(
+        Vec::new(),
+        Vec::new(),
+        vec![(
+            "Date".to_string(),
+            boxcars::HeaderProp::Str("2021-05-16 19-20-30".to_string()),
+        )],
+    );
+    let expected =
+        chrono::NaiveDate::from_ymd_opt(2021, 5, 16).and_then(|date| date.and_hms_opt(19, 20, 30));
+    assert_eq!(meta.recorded_at(), expected);
+
+    meta.all_headers[0].1 = boxcars::HeaderProp::Str("2015-10-18:20-15".to_string());
+    let expected =
+        chrono::NaiveDate::from_ymd_opt(2015, 10, 18).and_then(|date| date.and_hms_opt(20, 15, 0));
+    assert_eq!(meta.recorded_at(), expected);
+
+    meta.all_headers[0].1 = boxcars::HeaderProp::Str("not a date".to_string());
+    assert_eq!(meta.recorded_at(), None);
+
+    meta.all_headers.clear();
+    assert_eq!(meta.recorded_at(), None);
+}
+
+#[test]
+fn test_quaternion_angle_between() {
+    let identity = Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        w: 1.0,
+    };
+    let half_yaw = std::f32::consts::FRAC_PI_4;
+    let quarter_turn = Quaternion {
+        x: 0.0,
+        y: 0.0,
+        z: half_yaw.sin(),
+        w: half_yaw.cos(),
+    };
+    let negated = Quaternion {
+        x: -quarter_turn.x,
+        y: -quarter_turn.y,
+        z: -quarter_turn.z,
+        w: -quarter_turn.w,
+    };
+    assert!(quaternion_angle_between(&identity, &identity).abs() < 1e-3);
+    assert!(
+        (quaternion_angle_between(&identity, &quarter_turn) - std::f32::consts::FRAC_PI_2).abs()
+            < 1e-3
+    );
+    assert!(quaternion_angle_between(&quarter_turn, &negated).abs() < 1e-3);
+}
+
+#[test]
+fn test_median() {
+    assert_eq!(median(&mut []), None);
+    assert_eq!(median(&mut [0.033, 5.0, 0.034]), Some(0.034));
+    assert_eq!(median(&mut [0.04, 0.03, 10.0, 0.02]), Some(0.035));
+}
+
+#[test]
+fn test_is_own_goal() {
+    let touch = |is_team_0| BallTouchInfo {
+        time: 10.0,
+        frame: 300,
+        player: boxcars::RemoteId::SplitScreen(0),
+        is_team_0,
+        ball_location: Vector3f {
+            x: 0.0,
+            y: 5000.0,
+            z: 93.0,
+        },
+        ball_velocity: Vector3f {
+            x: 0.0,
+            y: 1000.0,
+            z: 0.0,
+        },
+    };
+    assert!(!is_own_goal(Some(&touch(true)), true));
+    assert!(is_own_goal(Some(&touch(false)), true));
+    assert!(is_own_goal(Some(&touch(true)), false));
+    assert!(!is_own_goal(None, true));
+}
+
+#[test]
+fn test_player_demolished_by_encoding() {
+    let adder = PlayerDemolishedBy::<f32>::new(DEFAULT_UNKNOWN_DEMOLISHER_VALUE);
+    assert_eq!(adder.encode_demolisher(None), NOT_DEMOLISHED_VALUE);
+    assert_eq!(adder.encode_demolisher(Some(Some(0))), 0.0);
+    assert_eq!(adder.encode_demolisher(Some(Some(5))), 5.0);
+    assert_eq!(
+        adder.encode_demolisher(Some(None)),
+        DEFAULT_UNKNOWN_DEMOLISHER_VALUE
+    );
+    assert_ne!(DEFAULT_UNKNOWN_DEMOLISHER_VALUE, NOT_DEMOLISHED_VALUE);
+
+    let custom = PlayerDemolishedBy::<f32>::new(-100.0);
+    assert_eq!(custom.encode_demolisher(Some(None)), -100.0);
+    assert_eq!(custom.encode_demolisher(None), NOT_DEMOLISHED_VALUE);
+}
+
+#[test]
+fn test_get_player_id_from_unique_id() {
+    let unique_id = |remote_id, local_id| boxcars::UniqueId {
+        system_id: 1,
+        remote_id,
+        local_id,
+    };
+    let actor_id = boxcars::ActorId(17);
+    assert_eq!(
+        get_player_id_from_unique_id(actor_id, &unique_id(boxcars::RemoteId::Steam(42), 0)),
+        boxcars::RemoteId::Steam(42)
+    );
+    assert_eq!(
+        get_player_id_from_unique_id(actor_id, &unique_id(boxcars::RemoteId::Steam(42), 1)),
+        boxcars::RemoteId::SplitScreen(17)
+    );
+    assert_eq!(
+        get_player_id_from_unique_id(actor_id, &unique_id(boxcars::RemoteId::SplitScreen(0), 0)),
+        boxcars::RemoteId::SplitScreen(17)
+    );
+}
+
+#[test]
+fn test_get_seconds_to_goal() {
+    let location = Vector3f {
+        x: 0.0,
+        y: 3120.0,
+        z: 93.0,
+    };
+    let toward_team_one_goal = Vector3f {
+        x: 100.0,
+        y: 1000.0,
+        z: 0.0,
+    };
+    let seconds = get_seconds_to_goal(&location, &toward_team_one_goal, false, 3.0);
+    assert!((seconds.unwrap() - 2.0).abs() < 1e-3);
+    assert_eq!(
+        get_seconds_to_goal(&location, &toward_team_one_goal, false, 1.0),
+        None
+    );
+    assert_eq!(
+        get_seconds_to_goal(&location, &toward_team_one_goal, true, 100.0),
+        None
+    );
+
+    let wide = Vector3f {
+        x: 1000.0,
+        y: 1000.0,
+        z: 0.0,
+    };
+    assert_eq!(get_seconds_to_goal(&location, &wide, false, 3.0), None);
+}
+
+#[test]
+fn test_from_strings_f64_matches_f32() {
+    let global_names = ["BallRigidBody", "SecondsRemaining"];
+    let player_names = ["PlayerRigidBody", "PlayerBoost"];
+    let f32_collector =
+        NDArrayCollector::<f32>::from_strings(&global_names, &player_names).unwrap();
+    let f64_collector =
+        NDArrayCollector::<f64>::from_strings(&global_names, &player_names).unwrap();
+    let f32_headers = f32_collector.get_column_headers();
+    let f64_headers = f64_collector.get_column_headers();
+    assert_eq!(f32_headers.global_headers, f64_headers.global_headers);
+    assert_eq!(f32_headers.player_headers, f64_headers.player_headers);
+    assert!(NDArrayCollector::<f64>::from_strings(&["NotAFeatureAdder"], &[]).is_err());
+}
+
+#[test]
+fn test_write_ndarray_csv() {
+    let meta = ReplayMetaWithHeaders {
+        replay_meta: test_replay_meta(vec![test_player_info("Player")], Vec::new(), Vec::new()),
+        column_headers: NDArrayColumnHeaders::new(
+            vec!["ball pos x".to_string(), "a, b".to_string()],
+            vec!["boost level".to_string()],
+        ),
+    };
+
+    let mut output = Vec::new();
+    let empty = ::ndarray::Array2::<f32>::zeros((0, 3));
+    write_ndarray_csv(&meta, &empty, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "ball pos x,\"a, b\",Player 0 - boost level\n"
+    );
+
+    let mut output = Vec::new();
+    let array = ::ndarray::Array2::from_shape_vec(
+        (2, 3),
+        vec![1.5, f32::NAN, 3.0, 4.0, 5.0, f32::INFINITY],
+    )
+    .unwrap();
+    write_ndarray_csv(&meta, &array, &mut output).unwrap();
+    let csv = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[1], "1.5,,3");
+    assert_eq!(lines[2], "4,5,");
+    for line in &lines[1..] {
+        assert_eq!(line.split(',').count(), array.ncols());
+    }
+}
+
+#[test]
+fn test_is_supersonic() {
+    assert!(!is_supersonic(false, 2150.0));
+    assert!(is_supersonic(false, SUPERSONIC_SPEED));
+    assert!(is_supersonic(true, 2150.0));
+    assert!(!is_supersonic(true, 2000.0));
+}
+
+#[test]
+fn test_is_car_on_ground() {
+    let mut rigid_body = boxcars::RigidBody {
+        sleeping: false,
+        location: Vector3f {
+            x: 0.0,
+            y: -4608.0,
+            z: 17.0,
+        },
+        rotation: glam_to_quat(&glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+        linear_velocity: Some(Vector3f {
+            x: 0.0,
+            y: 1000.0,
+            z: 0.0,
+        }),
+        angular_velocity: None,
+    };
+    assert!(is_car_on_ground(&rigid_body));
+
+    rigid_body.linear_velocity = Some(Vector3f {
+        x: 0.0,
+        y: 1000.0,
+        z: 300.0,
+    });
+    assert!(!is_car_on_ground(&rigid_body));
+
+    rigid_body.linear_velocity = None;
+    rigid_body.rotation = glam_to_quat(&glam::Quat::from_rotation_x(std::f32::consts::PI));
+    assert!(!is_car_on_ground(&rigid_body));
+
+    rigid_body.rotation = glam_to_quat(&glam::Quat::IDENTITY);
+    rigid_body.location.z = 200.0;
+    assert!(!is_car_on_ground(&rigid_body));
+}
+
+#[test]
+fn test_seconds_remaining_clock_is_non_increasing() {
+    let frame_duration = 1.0 / 30.0;
+    let start_time = 10.0;
+    let mut clock = SecondsRemainingClock::new(300, start_time, true);
+    let mut previous = clock.seconds_remaining_at(start_time);
+    for frame in 1..300 {
+        let time = start_time + frame as f32 * frame_duration;
+        // The replicated clock ticks slightly late, as it does in replays.
+        let seconds_remaining = 300 - ((time - start_time - 0.05).max(0.0) as i32);
+        clock = clock.advance(seconds_remaining, time, true);
+        for offset in [0.0, frame_duration / 2.0] {
+            let value = clock.seconds_remaining_at(time + offset);
+            assert!(value <= previous, "{} > {} at {}", value, previous, time);
+            previous = value;
+        }
+    }
+    assert!(previous < 291.0 && previous > 289.0);
+
+    let stopped = clock.advance(clock.seconds_remaining, 30.0, false);
+    assert_eq!(
+        stopped.seconds_remaining_at(30.0),
+        stopped.seconds_remaining_at(35.0)
+    );
+}
+
+#[test]
+fn test_every_game_type_has_a_game_mode() {
+    for game_type in GAME_TYPES.iter() {
+        assert!(GameMode::from_game_event_type(game_type).is_some());
+    }
+    assert_eq!(
+        GameMode::from_game_event_type(GRIDIRON_GAME_TYPE),
+        Some(GameMode::Gridiron)
+    );
+    assert_eq!(
+        GameMode::from_game_event_type("Archetypes.GameEvent.GameEvent_Unknown"),
+        None
+    );
+}
+
+#[test]
+fn test_get_player_order_from_headers() {
+    let stats = |platform: &str, online_id: u64, team: i32| {
+        vec![
+            (
+                "Name".to_string(),
+                boxcars::HeaderProp::Str("player".to_string()),
+            ),
+            (
+                "Platform".to_string(),
+                boxcars::HeaderProp::Byte {
+                    kind: "OnlinePlatform".to_string(),
+                    value: Some(platform.to_string()),
+                },
+            ),
+            (
+                "OnlineID".to_string(),
+                boxcars::HeaderProp::QWord(online_id),
+            ),
+            ("Team".to_string(), boxcars::HeaderProp::Int(team)),
+            ("bBot".to_string(), boxcars::HeaderProp::Bool(false)),
+        ]
+    };
+    let headers = |player_stats: Vec<Vec<(String, boxcars::HeaderProp)>>| {
+        vec![(
+            "PlayerStats".to_string(),
+            boxcars::HeaderProp::Array(player_stats),
+        )]
+    };
+
+    let order = get_player_order_from_headers(&headers(vec![
+        stats("OnlinePlatform_Steam", 3, 1),
+        stats("OnlinePlatform_Dingo", 2, 0),
+        stats("OnlinePlatform_Steam", 1, 0),
+        stats("OnlinePlatform_Steam", 2, 1),
+    ]));
+    assert_eq!(
+        order,
+        Some((
+            vec![boxcars::RemoteId::Steam(1), boxcars::RemoteId::Xbox(2)],
+            vec![boxcars::RemoteId::Steam(2), boxcars::RemoteId::Steam(3)],
+        ))
+    );
+
+    assert_eq!(get_player_order_from_headers(&[]), None);
+    assert_eq!(
+        get_player_order_from_headers(&headers(vec![
+            stats("OnlinePlatform_Steam", 1, 0),
+            stats("OnlinePlatform_Epic", 0, 1),
+        ])),
+        None
+    );
+    assert_eq!(
+        get_player_order_from_headers(&headers(vec![
+            stats("OnlinePlatform_Steam", 1, 0),
+            stats("OnlinePlatform_Steam", 1, 1),
+        ])),
+        None
+    );
+}
+
+#[test]
+fn test_ndarray_collector_builder_mixes_named_and_custom_adders() {
+    let collector = NDArrayCollectorBuilder::<f32>::new()
+        .add_global_named("BallRigidBody")
+        .add_global(std::sync::Arc::new((
+            |_processor: &ReplayProcessor, _frame: &boxcars::Frame, _index: usize, _time: f32| {
+                Ok([1.0f32])
+            },
+            &["custom global"],
+        )))
+        .add_player_named("PlayerBoost")
+        .build()
+        .unwrap();
+    let headers = collector.get_column_headers();
+    let ball_headers = NDArrayCollector::<f32>::from_strings(&["BallRigidBody"], &[])
+        .unwrap()
+        .get_column_headers()
+        .global_headers;
+    assert_eq!(headers.global_headers.len(), ball_headers.len() + 1);
+    assert_eq!(
+        headers.global_headers[..ball_headers.len()],
+        ball_headers[..]
+    );
+    assert_eq!(headers.global_headers.last().unwrap(), "custom global");
+    assert_eq!(headers.player_headers, vec!["boost level"]);
+
+    let result = NDArrayCollectorBuilder::<f64>::new()
+        .add_player_named("NotAFeatureAdder")
+        .add_global_named("BallRigidBody")
+        .build();
+    assert!(matches!(
+        result.map(|_| ()).unwrap_err().variant,
+        SubtrActorErrorVariant::UnknownFeatureAdderName(name) if name == "NotAFeatureAdder"
+    ));
+}
+
+#[test]
+fn test_get_ndarray_without_frames_is_empty() {
+    let collector =
+        NDArrayCollector::<f32>::from_strings(&["BallRigidBody", "SecondsRemaining"], &[]).unwrap();
+    let global_feature_count = collector.get_column_headers().global_headers.len();
+    let array = collector.get_ndarray().unwrap();
+    assert_eq!(array.nrows(), 0);
+    assert_eq!(array.ncols(), global_feature_count);
+    assert!(global_feature_count > 0);
+}
+
+#[test]
+fn test_get_time_weighted_mean_rigid_body() {
+    let body = |x: f32, velocity_x: Option<f32>| boxcars::RigidBody {
+        sleeping: false,
+        location: Vector3f { x, y: 0.0, z: 0.0 },
+        rotation: glam_to_quat(&glam::Quat::IDENTITY),
+        linear_velocity: velocity_x.map(|x| Vector3f { x, y: 0.0, z: 0.0 }),
+        angular_velocity: None,
+    };
+    let updates = vec![
+        (0.0, body(0.0, Some(10.0))),
+        (1.0, body(100.0, None)),
+        (1.5, body(200.0, Some(30.0))),
+        (3.0, body(1000.0, Some(1000.0))),
+    ];
+
+    // The first update holds for 0.5 seconds of the window, the second for
+    // 0.5 and the third for 1.0. The last one starts after the window ends.
+    let mean = get_time_weighted_mean_rigid_body(&updates, 0.5, 2.5).unwrap();
+    assert!((mean.location.x - 125.0).abs() < 1e-4);
+    assert!((mean.linear_velocity.unwrap().x - (10.0 * 0.5 + 30.0) / 1.5).abs() < 1e-4);
+    assert_eq!(mean.angular_velocity, None);
+    assert!((quat_to_glam(&mean.rotation).w.abs() - 1.0).abs() < 1e-4);
+
+    let empty_window = get_time_weighted_mean_rigid_body(&updates, 2.0, 2.0).unwrap();
+    assert_eq!(empty_window.location.x, 1000.0);
+    assert_eq!(get_time_weighted_mean_rigid_body(&[], 0.0, 1.0), None);
+}
+
+#[test]
+fn test_count_player_demolishes() {
+    let players = [
+        boxcars::RemoteId::Steam(1),
+        boxcars::RemoteId::Steam(2),
+        boxcars::RemoteId::Steam(3),
+    ];
+    let demolish = |frame: usize, attacker: usize, victim: usize| DemolishInfo {
+        time: frame as f32 / 30.0,
+        seconds_remaining: 300,
+        frame,
+        attacker: players[attacker].clone(),
+        victim: players[victim].clone(),
+        attacker_velocity: Vector3f {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        victim_velocity: Vector3f {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+    let demolishes = vec![
+        demolish(10, 0, 1),
+        demolish(20, 0, 2),
+        demolish(30, 1, 0),
+        demolish(40, 2, 1),
+    ];
+
+    assert_eq!(count_player_demolishes(&demolishes, &players[0], 5), (0, 0));
+    assert_eq!(
+        count_player_demolishes(&demolishes, &players[0], 20),
+        (2, 0)
+    );
+    assert_eq!(
+        count_player_demolishes(&demolishes, &players[1], 30),
+        (1, 1)
+    );
+
+    let mut previous = vec![(0, 0); players.len()];
+    for frame_number in 0..50 {
+        for (index, player_id) in players.iter().enumerate() {
+            let counts = count_player_demolishes(&demolishes, player_id, frame_number);
+            assert!(counts.0 >= previous[index].0 && counts.1 >= previous[index].1);
+            previous[index] = counts;
+        }
+    }
+    let total_inflicted: usize = previous.iter().map(|(inflicted, _)| inflicted).sum();
+    let total_taken: usize = previous.iter().map(|(_, taken)| taken).sum();
+    assert_eq!(total_inflicted, demolishes.len());
+    assert_eq!(total_taken, demolishes.len());
+}
+
+#[test]
+fn test_get_actor_rigid_body_at_time() {
+    let actor_id = boxcars::ActorId(5);
+    let object_id = boxcars::ObjectId(7);
+    let body = |x: f32| boxcars::RigidBody {
+        sleeping: false,
+        location: Vector3f {
+            x,
+            y: 2.0 * x,
+            z: 0.0,
+        },
+        rotation: glam_to_quat(&glam::Quat::IDENTITY),
+        linear_velocity: None,
+        angular_velocity: None,
+    };
+    let frame = |time: f32, rigid_body: Option<boxcars::RigidBody>| boxcars::Frame {
+        time,
+        delta: 0.1,
+        new_actors: Vec::new(),
+        deleted_actors: Vec::new(),
+        updated_actors: rigid_body
+            .map(|rigid_body| {
+                vec![boxcars::UpdatedAttribute {
+                    actor_id,
+                    stream_id: boxcars::StreamId(0),
+                    object_id,
+                    attribute: boxcars::Attribute::RigidBody(rigid_body),
+                }]
+            })
+            .unwrap_or_default(),
+    };
+    let frames = vec![
+        frame(1.0, Some(body(100.0))),
+        frame(1.1, None),
+        frame(1.2, None),
+        frame(1.3, Some(body(400.0))),
+        frame(1.4, None),
+    ];
+
+    let halfway = get_actor_rigid_body_at_time(&frames, &actor_id, &object_id, 1.15, 0.0).unwrap();
+    let expected = vec_to_glam(&body(100.0).location).lerp(vec_to_glam(&body(400.0).location), 0.5);
+    assert!((vec_to_glam(&halfway.location) - expected).length() < 1e-3);
+
+    let at_update = get_actor_rigid_body_at_time(&frames, &actor_id, &object_id, 1.3, 0.0);
+    assert_eq!(at_update.unwrap().location.x, 400.0);
+    let close_enough = get_actor_rigid_body_at_time(&frames, &actor_id, &object_id, 1.35, 0.1);
+    assert_eq!(close_enough.unwrap().location.x, 400.0);
+    assert!(get_actor_rigid_body_at_time(&frames, &actor_id, &object_id, 1.4, 0.0).is_err());
+    assert!(matches!(
+        get_actor_rigid_body_at_time(&frames, &actor_id, &object_id, 0.5, 0.0)
+            .unwrap_err()
+            .variant,
+        SubtrActorErrorVariant::TimeOutsideReplay { .. }
+    ));
+}
+
+#[test]
+fn test_get_platform_name() {
+    let mut stats = std::collections::HashMap::new();
+    assert_eq!(
+        get_platform_name(&boxcars::RemoteId::Steam(1), Some(&stats)),
+        Some("Steam".to_string())
+    );
+    assert_eq!(
+        get_platform_name(&boxcars::RemoteId::SplitScreen(0), None),
+        None
+    );
+
+    stats.insert(
+        "Platform".to_string(),
+        boxcars::HeaderProp::Byte {
+            kind: "OnlinePlatform".to_string(),
+            value: Some("OnlinePlatform_Epic".to_string()),
+        },
+    );
+    assert_eq!(
+        get_platform_name(&boxcars::RemoteId::Epic("id".to_string()), Some(&stats)),
+        Some("Epic".to_string())
+    );
+}
+
+#[test]
+fn test_get_goal_distances() {
+    let center = Vector3f {
+        x: 0.0,
+        y: 0.0,
+        z: 93.15,
+    };
+    let (blue, orange) = get_goal_distances(&center);
+    assert!((blue - orange).abs() < 1e-3);
+    assert!(blue > GOAL_LINE_Y - 1.0 && blue < GOAL_LINE_Y + GOAL_HEIGHT);
+
+    let near_blue_goal = Vector3f {
+        x: 0.0,
+        y: -4000.0,
+        z: 93.15,
+    };
+    let (blue, orange) = get_goal_distances(&near_blue_goal);
+    assert!(blue < 1200.0);
+    assert!(orange > 9000.0 && orange < 2.0 * GOAL_LINE_Y);
+}
+
+#[test]
+fn test_headers_vec_with_names_is_unique() {
+    let player = |name: &str| PlayerInfo {
+        is_bot: true,
+        ..test_player_info(name)
+    };
+    let mut meta = ReplayMetaWithHeaders {
+        replay_meta: test_replay_meta(
+            vec![player("Bot"), player("Alice")],
+            vec![player("Bot"), player("Bot (0)")],
+            Vec::new(),
+        ),
+        column_headers: NDArrayColumnHeaders::new(
+            vec!["seconds remaining".to_string()],
+            vec!["boost level".to_string()],
+        ),
+    };
+
+    let headers = meta.headers_vec_with_names();
+    assert_eq!(meta.headers_vec_with_player_names(), headers);
+    assert_eq!(
+        headers,
+        vec![
+            "seconds remaining",
+            "Bot (0) (0) - boost level",
+            "Alice - boost level",
+            "Bot (2) - boost level",
+            "Bot (0) (3) - boost level",
+        ]
+    );
+    let unique: std::collections::HashSet<_> = headers.iter().collect();
+    assert_eq!(unique.len(), headers.len());
+
+    // Appending the index to the duplicated name collides with another name,
+    // as does falling back to the player's index.
+    meta.replay_meta.team_zero = vec![player("A"), player("A")];
+    meta.replay_meta.team_one = vec![player("A (1)"), player("Player 1")];
+    assert_eq!(
+        meta.headers_vec_with_names(),
+        vec![
+            "seconds remaining",
+            "A (0) - boost level",
+            "A (1) (1) - boost level",
+            "A (1) (2) - boost level",
+            "Player 1 - boost level",
+        ]
+    );
+}
+
+fn replay_with_steam_players(players: &[(u64, i32)]) -> boxcars::Replay {
+    let stats = |online_id: u64, team: i32| {
+        vec![
+            (
+                "Name".to_string(),
+                boxcars::HeaderProp::Str("player".to_string()),
+            ),
+            (
+                "Platform".to_string(),
+                boxcars::HeaderProp::Byte {
+                    kind: "OnlinePlatform".to_string(),
+                    value: Some("OnlinePlatform_Steam".to_string()),
+                },
+            ),
+            (
+                "OnlineID".to_string(),
+                boxcars::HeaderProp::QWord(online_id),
+            ),
+            ("Team".to_string(), boxcars::HeaderProp::Int(team)),
+            ("bBot".to_string(), boxcars::HeaderProp::Bool(false)),
+        ]
+    };
+    boxcars::Replay {
+        header_size: 0,
+        header_crc: 0,
+        major_version: 868,
+        minor_version: 32,
+        net_version: Some(10),
+        game_type: "TAGame.Replay_Soccar_TA".to_string(),
+        properties: vec![(
+            "PlayerStats".to_string(),
+            boxcars::HeaderProp::Array(
+                players
+                    .iter()
+                    .map(|(online_id, team)| stats(*online_id, *team))
+                    .collect(),
+            ),
+        )],
+        content_size: 0,
+        content_crc: 0,
+        network_frames: None,
+        levels: Vec::new(),
+        keyframes: Vec::new(),
+        debug_info: Vec::new(),
+        tick_marks: Vec::new(),
+        packages: Vec::new(),
+        objects: Vec::new(),
+        names: Vec::new(),
+        class_indices: Vec::new(),
+        net_cache: Vec::new(),
+    }
+}
+
+/// Builds synthetic replays with network frames every 1/30th of a second, for
+/// tests that need the [`ReplayProcessor`] to discover actors from frames.
+struct ReplayBuilder {
+    replay: boxcars::Replay,
+}
+
+static TEST_GAME_ACTOR: i32 = 1;
+static TEST_TEAM_ZERO_ACTOR: i32 = 2;
+static TEST_TEAM_ONE_ACTOR: i32 = 3;
+static TEST_BALL_ACTOR: i32 = 4;
+
+impl ReplayBuilder {
+    fn new(frame_count: usize) -> Self {
+        let mut replay = replay_with_steam_players(&[]);
+        replay.properties.clear();
+        replay.network_frames = Some(boxcars::NetworkFrames {
+            frames: (0..frame_count)
+                .map(|index| boxcars::Frame {
+                    time: index as f32 / 30.0,
+                    delta: 1.0 / 30.0,
+                    new_actors: Vec::new(),
+                    deleted_actors: Vec::new(),
+                    updated_actors: Vec::new(),
+                })
+                .collect(),
+        });
+        let mut builder = Self { replay };
+        for name in [
+            BALL_TYPES[0],
+            BOOST_TYPE,
+            CAR_TYPE,
+            DODGE_TYPE,
+            DOUBLE_JUMP_TYPE,
+            GAME_TYPE,
+            JUMP_TYPE,
+            PLAYER_TYPE,
+            TEAM_ONE_TYPE,
+            TEAM_ZERO_TYPE,
+            BALL_HIT_TEAM_NUM_KEY,
+            BOOST_AMOUNT_KEY,
+            COMPONENT_ACTIVE_KEY,
+            DEMOLISH_GOAL_EXPLOSION_KEY,
+            HANDBRAKE_KEY,
+            OVERTIME_KEY,
+            PLAYER_NAME_KEY,
+            PLAYER_REPLICATION_KEY,
+            RIGID_BODY_STATE_KEY,
+            SECONDS_REMAINING_KEY,
+            TEAM_KEY,
+            TEAM_SCORE_KEY,
+            UNIQUE_ID_KEY,
+            VEHICLE_KEY,
+        ] {
+            builder.object_id(name);
+        }
+        builder
+    }
+
+    fn with_player_stats(&mut self, players: &[(u64, i32)]) -> &mut Self {
+        self.replay.properties = replay_with_steam_players(players).properties;
+        self
+    }
+
+    fn object_id(&mut self, name: &str) -> boxcars::ObjectId {
+        let objects = &mut self.replay.objects;
+        let index = match objects.iter().position(|object| object == name) {
+            Some(index) => index,
+            None => {
+                objects.push(name.to_string());
+                objects.len() - 1
+            }
+        };
+        boxcars::ObjectId(index as i32)
+    }
+
+    fn frame(&mut self, frame: usize) -> &mut boxcars::Frame {
+        &mut self.replay.network_frames.as_mut().unwrap().frames[frame]
+    }
+
+    fn spawn(&mut self, frame: usize, actor_id: i32, object_name: &str) -> &mut Self {
+        let object_id = self.object_id(object_name);
+        self.frame(frame).new_actors.push(boxcars::NewActor {
+            actor_id: boxcars::ActorId(actor_id),
+            name_id: None,
+            object_id,
+            initial_trajectory: boxcars::Trajectory {
+                location: None,
+                rotation: None,
+            },
+        });
+        self
+    }
+
+    fn update(
+        &mut self,
+        frame: usize,
+        actor_id: i32,
+        key: &str,
+        attribute: boxcars::Attribute,
+    ) -> &mut Self {
+        let object_id = self.object_id(key);
+        self.frame(frame)
+            .updated_actors
+            .push(boxcars::UpdatedAttribute {
+                actor_id: boxcars::ActorId(actor_id),
+                stream_id: boxcars::StreamId(0),
+                object_id,
+                attribute,
+            });
+        self
+    }
+
+    fn delete(&mut self, frame: usize, actor_id: i32) -> &mut Self {
+        self.frame(frame)
+            .deleted_actors
+            .push(boxcars::ActorId(actor_id));
+        self
+    }
+
+    /// Spawns the game event actor, with the given seconds remaining, and the
+    /// actors of both teams.
+    fn spawn_game(&mut self, frame: usize, seconds_remaining: i32) -> &mut Self {
+        self.spawn_game_of_type(frame, GAME_TYPE, seconds_remaining)
+    }
+
+    /// Like [`Self::spawn_game`], but with a game event of the given
+    /// archetype.
+    fn spawn_game_of_type(
+        &mut self,
+        frame: usize,
+        game_type: &str,
+        seconds_remaining: i32,
+    ) -> &mut Self {
+        self.spawn(frame, TEST_GAME_ACTOR, game_type)
+            .update(
+                frame,
+                TEST_GAME_ACTOR,
+                SECONDS_REMAINING_KEY,
+                boxcars::Attribute::Int(seconds_remaining),
+            )
+            .spawn(frame, TEST_TEAM_ZERO_ACTOR, TEAM_ZERO_TYPE)
+            .spawn(frame, TEST_TEAM_ONE_ACTOR, TEAM_ONE_TYPE)
+    }
+
+    /// Spawns the player replication actor of the Steam player `online_id`.
+    fn spawn_player(
+        &mut self,
+        frame: usize,
+        player_actor: i32,
+        online_id: u64,
+        is_team_0: bool,
+    ) -> &mut Self {
+        let team_actor = if is_team_0 {
+            TEST_TEAM_ZERO_ACTOR
+        } else {
+            TEST_TEAM_ONE_ACTOR
+        };
+        self.spawn(frame, player_actor, PLAYER_TYPE)
+            .update(
+                frame,
+                player_actor,
+                UNIQUE_ID_KEY,
+                boxcars::Attribute::UniqueId(Box::new(boxcars::UniqueId {
+                    system_id: 1,
+                    remote_id: boxcars::RemoteId::Steam(online_id),
+                    local_id: 0,
+                })),
+            )
+            .update(
+                frame,
+                player_actor,
+                TEAM_KEY,
+                boxcars::Attribute::ActiveActor(boxcars::ActiveActor {
+                    active: true,
+                    actor: boxcars::ActorId(team_actor),
+                }),
+            )
+            .update(
+                frame,
+                player_actor,
+                PLAYER_NAME_KEY,
+                boxcars::Attribute::String(format!("Player{}", online_id)),
+            )
+    }
+
+    /// Spawns a car for the player, at rest at `location`.
+    fn spawn_car(
+        &mut self,
+        frame: usize,
+        car_actor: i32,
+        player_actor: i32,
+        location: (f32, f32, f32),
+    ) -> &mut Self {
+        self.spawn(frame, car_actor, CAR_TYPE)
+            .update(
+                frame,
+                car_actor,
+                PLAYER_REPLICATION_KEY,
+                boxcars::Attribute::ActiveActor(boxcars::ActiveActor {
+                    active: true,
+                    actor: boxcars::ActorId(player_actor),
+                }),
+            )
+            .set_rigid_body(frame, car_actor, location, (0.0, 0.0, 0.0))
+    }
+
+    /// Spawns a boost component for the car, holding `amount` boost in the
+    /// replicated units.
+    fn spawn_boost(
+        &mut self,
+        frame: usize,
+        boost_actor: i32,
+        car_actor: i32,
+        amount: u8,
+    ) -> &mut Self {
+        self.spawn(frame, boost_actor, BOOST_TYPE)
+            .update(
+                frame,
+                boost_actor,
+                VEHICLE_KEY,
+                boxcars::Attribute::ActiveActor(boxcars::ActiveActor {
+                    active: true,
+                    actor: boxcars::ActorId(car_actor),
+                }),
+            )
+            .update(
+                frame,
+                boost_actor,
+                BOOST_AMOUNT_KEY,
+                boxcars::Attribute::Byte(amount),
+            )
+    }
+
+    fn spawn_ball(&mut self, frame: usize, location: (f32, f32, f32)) -> &mut Self {
+        self.spawn(frame, TEST_BALL_ACTOR, BALL_TYPES[0])
+            .set_rigid_body(frame, TEST_BALL_ACTOR, location, (0.0, 0.0, 0.0))
+    }
+
+    fn set_rigid_body(
+        &mut self,
+        frame: usize,
+        actor_id: i32,
+        (x, y, z): (f32, f32, f32),
+        (vx, vy, vz): (f32, f32, f32),
+    ) -> &mut Self {
+        self.set_rigid_body_rotation(
+            frame,
+            actor_id,
+            (x, y, z),
+            (vx, vy, vz),
+            glam::Quat::IDENTITY,
+            (0.0, 0.0, 0.0),
+        )
+    }
+
+    fn set_rigid_body_rotation(
+        &mut self,
+        frame: usize,
+        actor_id: i32,
+        (x, y, z): (f32, f32, f32),
+        (vx, vy, vz): (f32, f32, f32),
+        rotation: glam::Quat,
+        (ax, ay, az): (f32, f32, f32),
+    ) -> &mut Self {
+        self.update(
+            frame,
+            actor_id,
+            RIGID_BODY_STATE_KEY,
+            boxcars::Attribute::RigidBody(boxcars::RigidBody {
+                sleeping: false,
+                location: Vector3f { x, y, z },
+                rotation: glam_to_quat(&rotation),
+                linear_velocity: Some(Vector3f {
+                    x: vx,
+                    y: vy,
+                    z: vz,
+                }),
+                angular_velocity: Some(Vector3f {
+                    x: ax,
+                    y: ay,
+                    z: az,
+                }),
+            }),
+        )
+    }
+
+    /// Replicates a hit of the ball by a player of the given team.
+    fn touch_ball(&mut self, frame: usize, team: u8, velocity: (f32, f32, f32)) -> &mut Self {
+        self.set_rigid_body(frame, TEST_BALL_ACTOR, (0.0, 0.0, 93.15), velocity)
+            .update(
+                frame,
+                TEST_BALL_ACTOR,
+                BALL_HIT_TEAM_NUM_KEY,
+                boxcars::Attribute::Byte(team),
+            )
+    }
+
+    fn build(&self) -> boxcars::Replay {
+        self.replay.clone()
+    }
+}
+
+/// Builds a replay in which the Steam players `1` (team zero, actors 10 and
+/// 20) and `2` (team one, actors 11 and 21) play for `frame_count` frames with
+/// the ball at the center of the field.
+fn two_player_replay_builder(frame_count: usize) -> ReplayBuilder {
+    let mut builder = ReplayBuilder::new(frame_count);
+    builder
+        .spawn_game(0, 300)
+        .spawn_ball(0, (0.0, 0.0, 93.15))
+        .spawn_player(0, 10, 1, true)
+        .spawn_player(0, 11, 2, false)
+        .spawn_car(0, 20, 10, (0.0, -4608.0, 17.0))
+        .spawn_car(0, 21, 11, (0.0, 4608.0, 17.0));
+    builder
+}
+
+fn player_order(processor: &ReplayProcessor) -> Vec<PlayerId> {
+    processor.iter_player_ids_in_order().cloned().collect()
+}
+
+#[test]
+fn test_player_order_from_headers_matches_frames() {
+    let with_header = two_player_replay_builder(320)
+        .with_player_stats(&[(2, 1), (1, 0)])
+        .build();
+    let without_header = two_player_replay_builder(320).build();
+    assert_eq!(
+        get_player_order_from_headers(&with_header.properties),
+        Some((
+            vec![boxcars::RemoteId::Steam(1)],
+            vec![boxcars::RemoteId::Steam(2)]
+        ))
+    );
+    assert_eq!(
+        player_order(&ReplayProcessor::new(&with_header).unwrap()),
+        player_order(&ReplayProcessor::new(&without_header).unwrap())
+    );
+}
+
+#[test]
+fn test_player_order_from_headers_falls_back_to_frames() {
+    // Player 3 leaves before the end of the match and player 4 only
+    // spectates, so neither of them is listed in the header.
+    let mut builder = two_player_replay_builder(360);
+    builder
+        .spawn_player(0, 12, 3, true)
+        .spawn_car(0, 22, 12, (0.0, -2000.0, 17.0))
+        .delete(340, 22)
+        .delete(340, 12)
+        .spawn(0, 13, PLAYER_TYPE)
+        .update(
+            0,
+            13,
+            UNIQUE_ID_KEY,
+            boxcars::Attribute::UniqueId(Box::new(boxcars::UniqueId {
+                system_id: 1,
+                remote_id: boxcars::RemoteId::Steam(4),
+                local_id: 0,
+            })),
+        );
+    let without_header = builder.build();
+    let with_header = builder.with_player_stats(&[(1, 0), (2, 1)]).build();
+
+    let mut processor = ReplayProcessor::new(&with_header).unwrap();
+    assert_eq!(
+        player_order(&processor),
+        vec![
+            boxcars::RemoteId::Steam(1),
+            boxcars::RemoteId::Steam(3),
+            boxcars::RemoteId::Steam(2)
+        ]
+    );
+    assert_eq!(
+        player_order(&processor),
+        player_order(&ReplayProcessor::new(&without_header).unwrap())
+    );
+
+    let mut collector = NDArrayCollector::<f32>::from_strings(&[], &["PlayerBoost"]).unwrap();
+    processor.process(&mut collector).unwrap();
+    let (meta, _) = collector.get_meta_and_ndarray().unwrap();
+    assert_eq!(meta.replay_meta.spectator_count, 1);
+}
+
+#[test]
+fn test_resume_from_keeps_player_order() {
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .spawn_player(0, 12, 3, true)
+        .spawn_car(0, 22, 12, (0.0, -2000.0, 17.0));
+    let replay = builder.with_player_stats(&[(2, 1), (1, 0), (3, 0)]).build();
+
+    let previous = ReplayProcessor::new(&replay).unwrap();
+    let resumed = ReplayProcessor::resume_from(&previous, &replay);
+    let fresh = ReplayProcessor::new(&replay).unwrap();
+
+    assert_eq!(player_order(&resumed), player_order(&previous));
+    assert_eq!(player_order(&resumed), player_order(&fresh));
+    assert_eq!(resumed.team_zero.len(), 2);
+    assert!(resumed.player_to_actor_id.is_empty());
+}
+
+#[test]
+fn test_error_variant_codes_are_unique() {
+    use SubtrActorErrorVariant::*;
+    let player_id = boxcars::RemoteId::SplitScreen(0);
+    let actor_id = boxcars::ActorId(0);
+    let object_id = boxcars::ObjectId(0);
+    let variants = vec![
+        ReplayParseError(String::new()),
+        NoNetworkFrames,
+        FrameIndexOutOfBounds,
+        InconsistentPlayerSet {
+            found: Default::default(),
+            original: Default::default(),
+        },
+        NoUpdateAfterFrame {
+            actor_id,
+            object_id,
+            frame_index: 0,
+        },
+        NoBoostAmountValue,
+        UnexpectedAttributeType {
+            expected_type: String::new(),
+            actual_type: String::new(),
+        },
+        NoMatchingPlayerId { actor_id },
+        NoGameActor,
+        ActorIdAlreadyExists {
+            actor_id,
+            object_id,
+        },
+        ActorNotFound {
+            name: "",
+            player_id: player_id.clone(),
+        },
+        NoStateForActorId { actor_id },
+        ObjectIdNotFound { name: "" },
+        UnknownObjectName {
+            name: String::new(),
+        },
+        ActorAttributeNotFound {
+            actor_id,
+            name: String::new(),
+        },
+        DerivedKeyValueNotFound {
+            name: String::new(),
+        },
+        BallActorNotFound,
+        UnknownPlayerTeam {
+            player_id: player_id.clone(),
+        },
+        UnknownTeamObjectId {
+            object_id,
+            player_id: player_id.clone(),
+        },
+        EmptyTeamName { player_id },
+        FinishProcessingEarly,
+        InconsistentFeatureLength {
+            expected: 0,
+            actual: 0,
+        },
+        PlayerStatsHeaderNotFound,
+        IncompletePlayerStatsHeader,
+        InterpolationTimeOrderError {
+            start_time: 0.0,
+            time: 0.0,
+            end_time: 0.0,
+        },
+        UpdatedActorIdDoesNotExist {
+            update: boxcars::UpdatedAttribute {
+                actor_id,
+                stream_id: boxcars::StreamId(0),
+                object_id,
+                attribute: boxcars::Attribute::Boolean(false),
+            },
+        },
+        PropertyNotFoundInState { property: "" },
+        InitialFormationNotFound,
+        CouldNotBuildReplayMeta,
+        FloatConversionError,
+        NDArrayShapeError(::ndarray::ShapeError::from_kind(
+            ::ndarray::ErrorKind::IncompatibleShape,
+        )),
+        UnknownFeatureAdderName(String::new()),
+        UnknownPlayerActorType(String::new()),
+        InvalidTimeWindow {
+            start_time: 0.0,
+            end_time: 0.0,
+        },
+        TimeOutsideReplay { time: 0.0 },
+        ValidityMaskNotRecorded,
+        StreamWriteError(String::new()),
+        IncompatibleFeatureAdders(Vec::new()),
+    ];
+
+    let codes: std::collections::HashSet<_> = variants.iter().map(|v| v.code()).collect();
+    assert_eq!(codes.len(), variants.len());
+    assert!(codes.iter().all(|code| !code.is_empty()));
+
+    let payload = SubtrActorError::new(NoNetworkFrames).to_error_payload();
+    assert_eq!(payload.code, "no_network_frames");
+    assert_eq!(payload.message, "Replay has no network frames");
+}
+
+#[test]
+fn test_player_is_active_while_demolished() {
+    // Player 2 demolishes player 1 at frame 100, and player 1 respawns with a
+    // new car at frame 190.
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .update(
+            100,
+            21,
+            DEMOLISH_GOAL_EXPLOSION_KEY,
+            boxcars::Attribute::DemolishFx(Box::new(boxcars::DemolishFx {
+                custom_demo_flag: false,
+                custom_demo_id: 0,
+                attacker_flag: true,
+                attacker: boxcars::ActorId(21),
+                victim_flag: true,
+                victim: boxcars::ActorId(20),
+                attack_velocity: Vector3f {
+                    x: 0.0,
+                    y: -2300.0,
+                    z: 0.0,
+                },
+                victim_velocity: Vector3f {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            })),
+        )
+        .delete(101, 20)
+        .spawn_car(190, 22, 10, (0.0, -4608.0, 17.0));
+    let replay = builder.build();
+
+    let mut collector = NDArrayCollector::<f32>::from_strings(&[], &["PlayerIsActive"]).unwrap();
+    let mut processor = ReplayProcessor::new(&replay).unwrap();
+    processor.process(&mut collector).unwrap();
+    assert_eq!(processor.demolishes.len(), 1);
+    assert_eq!(processor.demolishes[0].frame, 100);
+    assert_eq!(processor.demolishes[0].victim, boxcars::RemoteId::Steam(1));
+
+    let array = collector.get_ndarray().unwrap();
+    let victim_is_active = array.column(0).to_vec();
+    assert!(victim_is_active[..101].iter().all(|value| *value == 1.0));
+    assert!(victim_is_active[101..190].iter().all(|value| *value == 0.0));
+    assert!(victim_is_active[190..].iter().all(|value| *value == 1.0));
+    assert!(array.column(1).iter().all(|value| *value == 1.0));
+}
+
+#[test]
+fn test_apply_velocities_to_rigid_body_rotation() {
+    // Player 1's car lies on its side, with its roof facing the team zero
+    // goal, and spins about the vertical axis of the world. The replay
+    // records the car at frame 1 and, after it has turned by 1.5 radians, at
+    // frame 10.
+    //
+    // The synthetic replay encodes the spin in radians per second about a world
+    // axis, so this only checks that the extrapolation is consistent with that
+    // assumed convention, not that real replays use it.
+    let spin: f32 = 5.0;
+    let turned = spin * 9.0 / 30.0;
+    let orientation = |forward: glam::Vec3, up: glam::Vec3| {
+        glam::Quat::from_mat3(&glam::Mat3::from_cols(forward, up.cross(forward), up))
+    };
+    let tilted = orientation(glam::Vec3::X, glam::Vec3::NEG_Y);
+    let spun = orientation(
+        glam::Vec3::new(turned.cos(), turned.sin(), 0.0),
+        glam::Vec3::new(turned.sin(), -turned.cos(), 0.0),
+    );
+    let location = (0.0, -4608.0, 60.0);
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .set_rigid_body_rotation(1, 20, location, (0.0, 0.0, 0.0), tilted, (0.0, 0.0, spin))
+        .set_rigid_body_rotation(10, 20, location, (0.0, 0.0, 0.0), spun, (0.0, 0.0, spin));
+    let replay = builder.build();
+
+    // Extrapolate the rotation replicated at frame 1 to the time of frame 10.
+    let mut extrapolated = None;
+    ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(
+            &mut |processor: &ReplayProcessor, _: &boxcars::Frame, frame_number, _| {
+                if frame_number == 9 {
+                    extrapolated = Some(
+                        processor
+                            .get_velocity_applied_player_rigid_body(
+                                &boxcars::RemoteId::Steam(1),
+                                10.0 / 30.0,
+                            )?
+                            .rotation,
+                    );
+                }
+                Ok(TimeAdvance::NextFrame)
+            },
+        )
+        .unwrap();
+
+    let extrapolated = extrapolated.unwrap();
+    assert!(quaternion_angle_between(&extrapolated, &glam_to_quat(&spun)) < 1e-3);
+    let up = quat_to_glam(&extrapolated) * glam::Vec3::Z;
+    assert!(up.abs_diff_eq(glam::Vec3::new(turned.sin(), -turned.cos(), 0.0), 1e-3));
+}
+
+#[test]
+fn test_possession_collector_stays_within_active_play() {
+    let touch = |time: f32, player: u64, is_team_0: bool| BallTouchInfo {
+        time,
+        frame: (time * 30.0) as usize,
+        player: boxcars::RemoteId::Steam(player),
+        is_team_0,
+        ball_location: Vector3f {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        ball_velocity: Vector3f {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+    let touches = [
+        touch(1.0, 1, true),
+        touch(2.0, 2, true),
+        touch(4.0, 3, false),
+        touch(20.0, 1, true),
+    ];
+
+    let mut collector = PossessionCollector::new(5.0);
+    for frame in 0..=900 {
+        let time = frame as f32 / 30.0;
+        let touches_so_far: Vec<_> = touches
+            .iter()
+            .filter(|touch| touch.time <= time)
+            .cloned()
+            .collect();
+        // Play stops for a goal between 22 and 25 seconds.
+        let is_ball_in_play = !(22.0..25.0).contains(&time);
+        collector.record_frame(&touches_so_far, is_ball_in_play, time);
+    }
+    let stats = collector.get_stats();
+
+    assert_eq!(stats.team_zero.touches, 3);
+    assert_eq!(stats.team_one.touches, 1);
+    let possession_seconds = stats.team_zero.possession_seconds + stats.team_one.possession_seconds;
+    assert!(possession_seconds <= stats.active_play_seconds + 1e-3);
+    assert!((stats.active_play_seconds - 27.0).abs() < 0.1);
+    // Team zero keeps the ball from 1 to 4 seconds, and from 20 seconds until
+    // play stops at 22. Team one's possession times out 5 seconds after 4.
+    assert!((stats.team_zero.possession_seconds - 5.0).abs() < 0.1);
+    assert!((stats.team_zero.longest_possession_seconds - 3.0).abs() < 0.1);
+    assert!((stats.team_one.possession_seconds - 5.0).abs() < 0.1);
+    assert_eq!(stats.players.len(), 3);
+    assert!(stats.as_json().is_ok());
+}
+
+#[test]
+fn test_get_field_third() {
+    for y in [-5000.0, -2000.0, 0.0, 2000.0, 5000.0, 6000.0] {
+        let team_zero_third = get_field_third(y, true);
+        let team_one_third = get_field_third(y, false);
+        assert!(team_zero_third <= 2);
+        assert_eq!(team_zero_third + team_one_third, 2);
+    }
+    assert_eq!(get_field_third(-4000.0, true), 0);
+    assert_eq!(get_field_third(-4000.0, false), 2);
+    assert_eq!(get_field_third(500.0, true), 1);
+    assert_eq!(get_field_third(500.0, false), 1);
+    assert_eq!(get_field_third(4000.0, true), 2);
+    assert_eq!(get_field_third(4000.0, false), 0);
+    assert_eq!(UNKNOWN_FIELD_THIRD_VALUE, -1.0);
+}
+
+struct FrameCounter(usize);
+
+impl Collector for FrameCounter {
+    fn process_frame(
+        &mut self,
+        _processor: &ReplayProcessor,
+        _frame: &boxcars::Frame,
+        _frame_number: usize,
+        _current_time: f32,
+    ) -> SubtrActorResult<TimeAdvance> {
+        self.0 += 1;
+        Ok(TimeAdvance::NextFrame)
+    }
+}
+
+#[test]
+fn test_process_between() {
+    let replay = two_player_replay_builder(320).build();
+    let count_between = |start_time, end_time| {
+        let mut counter = FrameCounter(0);
+        ReplayProcessor::new(&replay)
+            .unwrap()
+            .process_between(&mut counter, start_time, end_time)
+            .map(|_| counter.0)
+    };
+
+    assert_eq!(count_between(None, None).unwrap(), 320);
+    // Frames are 1/30th of a second apart, so this includes frames 60 to 120.
+    let windowed = count_between(Some(2.0), Some(4.0)).unwrap();
+    assert_eq!(windowed, 61);
+    assert!(windowed < count_between(None, None).unwrap());
+    assert_eq!(count_between(Some(10.0), None).unwrap(), 20);
+
+    let error = count_between(Some(4.0), Some(2.0)).unwrap_err();
+    assert!(matches!(
+        error.variant,
+        SubtrActorErrorVariant::InvalidTimeWindow { .. }
+    ));
+}
+
+#[test]
+fn test_composite_collector_matches_individual_collectors() {
+    let mut replay = replay_with_steam_players(&[]);
+    replay.objects = vec![CAR_TYPE.to_string(), BOOST_TYPE.to_string()];
+    replay.network_frames = Some(boxcars::NetworkFrames {
+        frames: (0..90)
+            .map(|index| boxcars::Frame {
+                time: index as f32 / 30.0,
+                delta: 1.0 / 30.0,
+                new_actors: Vec::new(),
+                deleted_actors: Vec::new(),
+                updated_actors: Vec::new(),
+            })
+            .collect(),
+    });
+    let count_at_fps = |fps: f32| {
+        let mut counter = FrameCounter(0);
+        ReplayProcessor::new(&replay)
+            .unwrap()
+            .process(&mut FrameRateDecorator::new_from_fps(fps, &mut counter))
+            .unwrap();
+        counter.0
+    };
+
+    for fps in [10.0, 30.0] {
+        let (mut first, mut second) = (FrameCounter(0), FrameCounter(0));
+        let mut composite = CompositeCollector::new(
+            FrameRateDecorator::new_from_fps(fps, &mut first),
+            FrameRateDecorator::new_from_fps(fps, &mut second),
+        );
+        ReplayProcessor::new(&replay)
+            .unwrap()
+            .process(&mut composite)
+            .unwrap();
+        composite.into_inner();
+        assert!(first.0 > 0);
+        assert_eq!(first.0, count_at_fps(fps));
+        assert_eq!(second.0, count_at_fps(fps));
+    }
+
+    let mut composite = CompositeCollector::new(FrameCounter(0), FrameCounter(0));
+    ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(&mut composite)
+        .unwrap();
+    let (first, second) = composite.into_inner();
+    assert_eq!((first.0, second.0), (90, 90));
+
+    // Collectors that advance time differently each get the rows they would
+    // get on their own.
+    for (first_fps, second_fps) in [(10.0, 30.0), (30.0, 10.0), (7.0, 12.0)] {
+        let (mut first, mut second) = (FrameCounter(0), FrameCounter(0));
+        let mut composite = CompositeCollector::new(
+            FrameRateDecorator::new_from_fps(first_fps, &mut first),
+            FrameRateDecorator::new_from_fps(second_fps, &mut second),
+        );
+        ReplayProcessor::new(&replay)
+            .unwrap()
+            .process(&mut composite)
+            .unwrap();
+        composite.into_inner();
+        assert_eq!(first.0, count_at_fps(first_fps));
+        assert_eq!(second.0, count_at_fps(second_fps));
+    }
+
+    let mut first = FrameCounter(0);
+    let mut composite = CompositeCollector::new(
+        FrameRateDecorator::new_from_fps(10.0, &mut first),
+        FrameCounter(0),
+    );
+    ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(&mut composite)
+        .unwrap();
+    let (_, second) = composite.into_inner();
+    assert_eq!((first.0, second.0), (count_at_fps(10.0), 90));
+}
+
+/// Builds a replay in which the ball and the car of player `1` accelerate
+/// upwards from rest at `acceleration`, while the car spins about the z axis
+/// at `spin_per_frame` radians per frame. The car of player `2` stays where it
+/// spawned.
+fn accelerating_replay(
+    frame_count: usize,
+    acceleration: f32,
+    spin_per_frame: f32,
+) -> boxcars::Replay {
+    let mut builder = two_player_replay_builder(frame_count);
+    for frame in 0..frame_count {
+        let vz = acceleration * frame as f32 / 30.0;
+        let dz = vz * frame as f32 / 30.0 / 2.0;
+        let rotation = glam::Quat::from_rotation_z(spin_per_frame * frame as f32);
+        builder
+            .set_rigid_body(
+                frame,
+                TEST_BALL_ACTOR,
+                (0.0, 0.0, 93.15 + dz),
+                (0.0, 0.0, vz),
+            )
+            .set_rigid_body_rotation(
+                frame,
+                20,
+                (0.0, -4608.0, 17.0 + dz),
+                (0.0, 0.0, vz),
+                rotation,
+                (0.0, 0.0, 0.0),
+            );
+    }
+    builder.build()
+}
+
+#[test]
+fn test_averaged_ball_rigid_body_is_between_frame_rate_samples() {
+    let replay = accelerating_replay(320, 900.0, 0.0);
+    let mut sampled = NDArrayCollector::<f32>::from_strings(&["BallRigidBody"], &[]).unwrap();
+    ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(&mut FrameRateDecorator::new(
+            DEFAULT_AVERAGING_WINDOW_SECONDS,
+            &mut sampled,
+        ))
+        .unwrap();
+    let mut averaged =
+        NDArrayCollector::<f32>::from_strings(&["AveragedBallRigidBody"], &[]).unwrap();
+    let headers = averaged.get_column_headers().global_headers;
+    assert_eq!(headers.len(), 12);
+    assert!(headers
+        .iter()
+        .all(|header| header.starts_with("Ball - averaged ")));
+    ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(&mut AveragingDecorator::new(
+            DEFAULT_AVERAGING_WINDOW_SECONDS,
+            &mut averaged,
+        ))
+        .unwrap();
+    let sampled = sampled.get_ndarray().unwrap();
+    let averaged = averaged.get_ndarray().unwrap();
+
+    // Each averaged row covers the window between two consecutive samples,
+    // during which the ball accelerates upwards.
+    assert_eq!(averaged.nrows(), sampled.nrows() - 1);
+    let averaged_velocities = averaged.column(8).to_vec();
+    for (row, averaged_velocity) in averaged_velocities.iter().enumerate() {
+        let window_start = sampled[[row, 8]];
+        let window_end = sampled[[row + 1, 8]];
+        assert!(window_start <= *averaged_velocity && *averaged_velocity <= window_end);
+    }
+    assert!(averaged_velocities
+        .windows(2)
+        .all(|velocities| velocities[0] < velocities[1]));
+}
+
+#[test]
+fn test_averaged_rigid_body_ignores_updates_from_before_spawn() {
+    // The ball is replaced by a stationary ball with the same actor id at
+    // frame 150.
+    let mut builder = two_player_replay_builder(320);
+    for frame in 0..150 {
+        builder.set_rigid_body(
+            frame,
+            TEST_BALL_ACTOR,
+            (0.0, 0.0, 93.15),
+            (0.0, 0.0, 1000.0),
+        );
+    }
+    builder
+        .delete(150, TEST_BALL_ACTOR)
+        .spawn(150, TEST_BALL_ACTOR, BALL_TYPES[0])
+        .set_rigid_body(150, TEST_BALL_ACTOR, (0.0, 0.0, 93.15), (0.0, 0.0, 0.0));
+    let replay = builder.build();
+
+    let mut velocity = None;
+    ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(
+            &mut |processor: &ReplayProcessor, frame: &boxcars::Frame, frame_number, _| {
+                if frame_number == 152 {
+                    velocity = processor
+                        .get_averaged_ball_rigid_body(frame.time - 0.1, frame.time)?
+                        .linear_velocity;
+                }
+                Ok(TimeAdvance::NextFrame)
+            },
+        )
+        .unwrap();
+    assert_eq!(velocity.unwrap().z, 0.0);
+}
+
+#[test]
+fn test_generic_numeric_player_adder() {
+    let throttle_key = "TAGame.Vehicle_TA:ReplicatedThrottle";
+    let mut builder = two_player_replay_builder(320);
+    builder.update(100, 20, throttle_key, boxcars::Attribute::Byte(200));
+    let replay = builder.build();
+
+    let throttle = GenericNumericPlayerAdder::arc_new("car", throttle_key, "throttle").unwrap();
+    let other_throttle =
+        GenericNumericPlayerAdder::arc_new("car", throttle_key, "throttle").unwrap();
+    assert!(std::ptr::eq(
+        throttle.get_column_headers()[0],
+        other_throttle.get_column_headers()[0]
+    ));
+    let array = NDArrayCollectorBuilder::<f32>::new()
+        .add_player(throttle)
+        .build()
+        .unwrap()
+        .process_replay(&replay)
+        .unwrap()
+        .get_ndarray()
+        .unwrap();
+    assert_eq!(array[[99, 0]], 0.0);
+    assert_eq!(array[[100, 0]], 200.0);
+    assert_eq!(array[[100, 1]], 0.0);
+
+    // No player has a dodge actor to read the attribute from, but the unknown
+    // key is reported anyway.
+    let unknown =
+        GenericNumericPlayerAdder::arc_new("dodge", "TAGame.Unknown_TA:Key", "unknown").unwrap();
+    let error = NDArrayCollectorBuilder::<f32>::new()
+        .add_player(unknown)
+        .build()
+        .unwrap()
+        .process_replay(&replay)
+        .err()
+        .unwrap();
+    assert!(matches!(
+        error.variant,
+        SubtrActorErrorVariant::UnknownObjectName { .. }
+    ));
+}
+
+#[test]
+fn test_player_ball_distance() {
+    let array = NDArrayCollector::<f32>::from_strings(&[], &["PlayerBallDistance"])
+        .unwrap()
+        .process_replay(&accelerating_replay(320, 900.0, 0.0))
+        .unwrap()
+        .get_ndarray()
+        .unwrap();
+    let distances = |column: usize| array.column(column).to_vec();
+
+    // Player 1's car rises with the ball, while player 2's car is left behind.
+    let expected = (4608.0f32.powi(2) + 76.15f32.powi(2)).sqrt();
+    assert!(distances(0)
+        .iter()
+        .all(|distance| (distance - expected).abs() < 1e-2));
+    let left_behind = distances(1);
+    assert!(left_behind.iter().all(|distance| *distance > 0.0));
+    assert!(left_behind
+        .windows(2)
+        .all(|distances| distances[0] < distances[1]));
+    assert!(left_behind[319] > left_behind[0] + 1000.0);
+}
+
+/// A player feature adder that fails for players without a car, unlike the
+/// built in adders, which fall back to default values.
+struct CarHeight;
+
+impl PlayerFeatureAdder<f32> for CarHeight {
+    fn get_column_headers(&self) -> &[&str] {
+        &["car height"]
+    }
+
+    fn add_features(
+        &self,
+        player_id: &PlayerId,
+        processor: &ReplayProcessor,
+        _frame: &boxcars::Frame,
+        _frame_count: usize,
+        _current_time: f32,
+        vector: &mut Vec<f32>,
+    ) -> SubtrActorResult<()> {
+        vector.push(processor.get_player_rigid_body(player_id)?.location.z);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_per_player_ndarray_collector() {
+    // Player 1's car is removed at frame 101, and replaced at frame 190.
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .delete(101, 20)
+        .spawn_car(190, 22, 10, (0.0, -4608.0, 17.0));
+    let replay = builder.build();
+
+    let mut collector = PerPlayerNDArrayCollector::new(
+        vec![BallRigidBody::arc_new()],
+        vec![std::sync::Arc::new(CarHeight)],
+        -1.0,
+    );
+    ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(&mut collector)
+        .unwrap();
+    let arrays = collector.get_arrays().unwrap();
+    assert_eq!(arrays.global.nrows(), 320);
+    for array in arrays.players.values() {
+        assert_eq!(array.nrows(), arrays.global.nrows());
+    }
+    let heights = |steam_id| {
+        arrays.players[&boxcars::RemoteId::Steam(steam_id)]
+            .column(0)
+            .to_vec()
+    };
+    let first_heights = heights(1);
+    assert!(first_heights[..101].iter().all(|height| *height == 17.0));
+    assert!(first_heights[101..190].iter().all(|height| *height == -1.0));
+    assert!(first_heights[190..].iter().all(|height| *height == 17.0));
+    assert!(heights(2).iter().all(|height| *height == 17.0));
+
+    // Errors that don't mean that the player is absent are returned.
+    let not_numeric =
+        GenericNumericPlayerAdder::arc_new("car", PLAYER_REPLICATION_KEY, "not numeric").unwrap();
+    let mut collector =
+        PerPlayerNDArrayCollector::new(vec![BallRigidBody::arc_new()], vec![not_numeric], -1.0);
+    let error = ReplayProcessor::new(&replay)
+        .unwrap()
+        .process(&mut collector)
+        .unwrap_err();
+    assert!(matches!(
+        error.variant,
+        SubtrActorErrorVariant::UnexpectedAttributeType { .. }
+    ));
+}
+
+static STATEFUL_GLOBAL_ADDERS: [&str; 1] = ["BallAcceleration"];
+static STATEFUL_PLAYER_ADDERS: [&str; 2] = ["PlayerAcceleration", "PlayerRotationDelta"];
+
+fn stateful_features(replay: &boxcars::Replay) -> ::ndarray::Array2<f32> {
+    let mut collector =
+        NDArrayCollector::<f32>::from_strings(&STATEFUL_GLOBAL_ADDERS, &STATEFUL_PLAYER_ADDERS)
+            .unwrap();
+    ReplayProcessor::new(replay)
+        .unwrap()
+        .process(&mut collector)
+        .unwrap();
+    collector.get_ndarray().unwrap()
+}
+
+#[test]
+fn test_stateful_feature_adders_are_not_shared_between_collectors() {
+    let replay_a = accelerating_replay(320, 900.0, 0.1);
+    let replay_b = accelerating_replay(320, -300.0, 0.0);
+    let expected_a = stateful_features(&replay_a);
+    let expected_b = stateful_features(&replay_b);
+    assert!(expected_a
+        .column(2)
+        .iter()
+        .skip(1)
+        .all(|acceleration| (acceleration - 900.0).abs() < 1.0));
+
+    let (features_a, features_b) = process_interleaved(
+        NDArrayCollector::<f32>::from_strings(&STATEFUL_GLOBAL_ADDERS, &STATEFUL_PLAYER_ADDERS)
+            .unwrap(),
+        &replay_a,
+        NDArrayCollector::<f32>::from_strings(&STATEFUL_GLOBAL_ADDERS, &STATEFUL_PLAYER_ADDERS)
+            .unwrap(),
+        &replay_b,
+    );
+    assert_eq!(features_a, expected_a);
+    assert_eq!(features_b, expected_b);
+}
+
+#[test]
+fn test_check_compatibility() {
+    // The ball never exists, so the check is made on the last frame that is
+    // processed to discover the players.
+    let mut builder = ReplayBuilder::new(320);
+    builder
+        .spawn_game(0, 300)
+        .spawn_player(0, 10, 1, true)
+        .spawn_car(0, 20, 10, (0.0, -4608.0, 17.0));
+    let error = NDArrayCollector::<f32>::from_strings(&["BallRigidBody"], &[])
+        .unwrap()
+        .check_compatibility(&builder.build())
+        .unwrap_err();
+    match error.variant {
+        SubtrActorErrorVariant::IncompatibleFeatureAdders(incompatible) => {
+            assert_eq!(incompatible.len(), 1);
+            assert_eq!(incompatible[0].name, "BallRigidBody");
+            assert!(!incompatible[0].is_player_feature_adder);
+        }
+        variant => panic!("Unexpected error variant {:?}", variant),
+    }
+
+    // Checking another replay doesn't leave a velocity behind in the
+    // collector's feature adders, from which the acceleration at the start of
+    // its time window would otherwise be computed.
+    let mut checked_replay = two_player_replay_builder(320);
+    checked_replay.set_rigid_body(0, TEST_BALL_ACTOR, (0.0, 0.0, 93.15), (0.0, 0.0, 5000.0));
+    let replay = accelerating_replay(320, 900.0, 0.1);
+    let mut collector =
+        NDArrayCollector::<f32>::from_strings(&STATEFUL_GLOBAL_ADDERS, &STATEFUL_PLAYER_ADDERS)
+            .unwrap();
+    collector.set_time_window(5.0, 10.0);
+    let unchecked = collector.clone_empty();
+    collector
+        .check_compatibility(&checked_replay.build())
+        .unwrap();
+    let features = collector
+        .process_replay(&replay)
+        .unwrap()
+        .get_ndarray()
+        .unwrap();
+    let unchecked_features = unchecked
+        .process_replay(&replay)
+        .unwrap()
+        .get_ndarray()
+        .unwrap();
+    assert_eq!(features, unchecked_features);
+    assert_eq!(features[[0, 2]], 0.0);
+}
+
+#[test]
+fn test_clone_empty_does_not_share_stateful_feature_adders() {
+    let replay_a = accelerating_replay(320, 900.0, 0.1);
+    let replay_b = accelerating_replay(320, -300.0, 0.0);
+    let template =
+        NDArrayCollector::<f32>::from_strings(&STATEFUL_GLOBAL_ADDERS, &STATEFUL_PLAYER_ADDERS)
+            .unwrap();
+
+    let (features_a, features_b) = process_interleaved(
+        template.clone_empty(),
+        &replay_a,
+        template.clone_empty(),
+        &replay_b,
+    );
+    assert_eq!(features_a, stateful_features(&replay_a));
+    assert_eq!(features_b, stateful_features(&replay_b));
+
+    // The same holds for clones processing replays on separate threads.
+    let (features_a, features_b) = std::thread::scope(|scope| {
+        let handles = [&replay_a, &replay_b].map(|replay| {
+            let collector = template.clone_empty();
+            scope.spawn(move || {
+                collector
+                    .process_replay(replay)
+                    .unwrap()
+                    .get_ndarray()
+                    .unwrap()
+            })
+        });
+        let [handle_a, handle_b] = handles;
+        (handle_a.join().unwrap(), handle_b.join().unwrap())
+    });
+    assert_eq!(features_a, stateful_features(&replay_a));
+    assert_eq!(features_b, stateful_features(&replay_b));
+}
+
+/// Feeds both collectors frame by frame, alternating between the two
+/// replays, so that any state shared by their feature adders is clobbered.
+fn process_interleaved(
+    mut collector_a: NDArrayCollector<f32>,
+    replay_a: &boxcars::Replay,
+    mut collector_b: NDArrayCollector<f32>,
+    replay_b: &boxcars::Replay,
+) -> (::ndarray::Array2<f32>, ::ndarray::Array2<f32>) {
+    let mut processor_a = ReplayProcessor::new(replay_a).unwrap();
+    let mut processor_b = ReplayProcessor::new(replay_b).unwrap();
+    let mut frames_a = processor_a.frames_iter();
+    let mut frames_b = processor_b.frames_iter();
+    while let (Some(context_a), Some(context_b)) = (frames_a.next(), frames_b.next()) {
+        for (collector, frames, context) in [
+            (&mut collector_a, &mut frames_a, context_a.unwrap()),
+            (&mut collector_b, &mut frames_b, context_b.unwrap()),
+        ] {
+            collector
+                .process_frame(
+                    frames.processor(),
+                    context.frame,
+                    context.frame_number,
+                    context.current_time,
+                )
+                .unwrap();
+        }
+    }
+    (
+        collector_a.get_ndarray().unwrap(),
+        collector_b.get_ndarray().unwrap(),
+    )
+}
+
+#[test]
+fn test_reused_processor_matches_fresh_processor() {
+    let replays = [
+        accelerating_replay(320, 900.0, 0.1),
+        accelerating_replay(320, -300.0, 0.0),
+    ];
+    let features = |processor: &mut ReplayProcessor| {
+        let mut collector = NDArrayCollector::<f32>::from_strings(
+            &["BallRigidBody", "BallAcceleration", "SecondsRemaining"],
+            &["PlayerRigidBody", "PlayerBoost", "PlayerRotationDelta"],
+        )
+        .unwrap();
+        processor.process(&mut collector).unwrap();
+        collector.get_meta_and_ndarray().unwrap()
+    };
+
+    let mut processor = ReplayProcessor::new(&replays[0]).unwrap();
+    features(&mut processor);
+    for replay in replays.iter() {
+        processor.reuse_for(replay).unwrap();
+        let reused = features(&mut processor);
+        let fresh = features(&mut ReplayProcessor::new(replay).unwrap());
+        assert_eq!(reused.1, fresh.1);
+        assert_eq!(reused.0.replay_meta, fresh.0.replay_meta);
+    }
+}
+
+#[test]
+fn test_goals_only_credit_touches_since_kickoff() {
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .set_rigid_body(0, 20, (0.0, -150.0, 17.0), (0.0, 0.0, 0.0))
+        .set_rigid_body(0, 21, (0.0, 150.0, 17.0), (0.0, 0.0, 0.0))
+        .touch_ball(30, 0, (0.0, 1000.0, 0.0))
+        .update(
+            60,
+            TEST_GAME_ACTOR,
+            ROUND_COUNTDOWN_KEY,
+            boxcars::Attribute::Int(3),
+        )
+        // Team one scores before anybody touched the ball after the kickoff.
+        .update(
+            90,
+            TEST_TEAM_ONE_ACTOR,
+            TEAM_SCORE_KEY,
+            boxcars::Attribute::Int(1),
+        )
+        .touch_ball(150, 0, (0.0, 1000.0, 0.0))
+        .update(
+            200,
+            TEST_TEAM_ZERO_ACTOR,
+            TEAM_SCORE_KEY,
+            boxcars::Attribute::Int(1),
+        );
+    let replay = builder.build();
+    let mut processor = ReplayProcessor::new(&replay).unwrap();
+    processor
+        .process(&mut |_: &ReplayProcessor, _: &boxcars::Frame, _, _| Ok(TimeAdvance::NextFrame))
+        .unwrap();
+
+    let goals: Vec<_> = processor
+        .goals
+        .iter()
+        .map(|goal| (goal.frame, goal.scorer.clone(), goal.is_own_goal))
+        .collect();
+    assert_eq!(
+        goals,
+        vec![
+            (90, None, false),
+            (200, Some(boxcars::RemoteId::Steam(1)), false)
+        ]
+    );
+}
+
+#[test]
+fn test_process_replay_native_emits_one_row_per_frame() {
+    let replay = two_player_replay_builder(320).build();
+    let frame_count = replay.network_frames.as_ref().unwrap().frames.len();
+
+    let collector = NDArrayCollector::<f32>::from_strings(&["CurrentTime"], &["PlayerBoost"])
+        .unwrap()
+        .process_replay_native(&replay)
+        .unwrap();
+    let array = collector.get_ndarray().unwrap();
+    assert_eq!(array.nrows(), frame_count);
+
+    // Requests to be invoked again before the next frame are ignored.
+    let mut invocations = 0;
+    let _ = (|_: &ReplayProcessor, _: &boxcars::Frame, _, current_time: f32| {
+        invocations += 1;
+        Ok(TimeAdvance::Time(current_time + 0.001))
+    })
+    .process_replay_native(&replay)
+    .unwrap();
+    assert_eq!(invocations, frame_count);
+}
+
+#[test]
+fn test_player_handbrake_uses_last_known_value() {
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .update(100, 20, HANDBRAKE_KEY, boxcars::Attribute::Boolean(true))
+        .update(200, 20, HANDBRAKE_KEY, boxcars::Attribute::Boolean(false));
+    let replay = builder.build();
+
+    let array = NDArrayCollector::<f32>::from_strings(&[], &["PlayerHandbrake"])
+        .unwrap()
+        .process_replay(&replay)
+        .unwrap()
+        .get_ndarray()
+        .unwrap();
+    // The handbrake is only replicated when it changes, so it stays on
+    // between those two frames.
+    let handbrake: Vec<f32> = array.column(0).to_vec();
+    assert!(handbrake[..100].iter().all(|value| *value == 0.0));
+    assert!(handbrake[100..200].iter().all(|value| *value == 1.0));
+    assert!(handbrake[200..].iter().all(|value| *value == 0.0));
+    assert!(array.column(1).iter().all(|value| *value == 0.0));
+}
+
+#[test]
+fn test_metadata_frames_track_overtime() {
+    let mut builder = two_player_replay_builder(320);
+    builder.update(
+        250,
+        TEST_GAME_ACTOR,
+        OVERTIME_KEY,
+        boxcars::Attribute::Boolean(true),
+    );
+    let replay_data = ReplayDataCollector::new()
+        .get_replay_data(&builder.build())
+        .unwrap();
+
+    let is_overtime: Vec<bool> = replay_data
+        .frame_data
+        .metadata_frames
+        .iter()
+        .map(|frame| frame.is_overtime)
+        .collect();
+    assert_eq!(is_overtime.len(), 320);
+    assert!(is_overtime[..250].iter().all(|is_overtime| !is_overtime));
+    assert!(is_overtime[250..].iter().all(|is_overtime| *is_overtime));
+}
+
+#[test]
+fn test_metadata_frame_scores_are_non_decreasing() {
+    let mut builder = two_player_replay_builder(320);
+    for (frame, team_actor, score) in [
+        (100, TEST_TEAM_ZERO_ACTOR, 1),
+        (200, TEST_TEAM_ONE_ACTOR, 1),
+        (250, TEST_TEAM_ZERO_ACTOR, 2),
+    ] {
+        builder.update(
+            frame,
+            team_actor,
+            TEAM_SCORE_KEY,
+            boxcars::Attribute::Int(score),
+        );
+    }
+    let replay_data = ReplayDataCollector::new()
+        .get_replay_data(&builder.build())
+        .unwrap();
+
+    let scores: Vec<(i32, i32)> = replay_data
+        .frame_data
+        .metadata_frames
+        .iter()
+        .map(|frame| (frame.team_0_score, frame.team_1_score))
+        .collect();
+    assert_eq!(scores.first(), Some(&(0, 0)));
+    assert_eq!(scores.last(), Some(&(2, 1)));
+    assert!(scores
+        .windows(2)
+        .all(|pair| pair[0].0 <= pair[1].0 && pair[0].1 <= pair[1].1));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_process_replays_parallel_matches_sequential() {
+    let mut replays: Vec<boxcars::Replay> = (0..4)
+        .map(|index| accelerating_replay(320, 100.0 * index as f32, 0.01 * index as f32))
+        .collect();
+    // A replay that fails to process yields an error in its place.
+    replays[2].network_frames = None;
+    let make_collector = || {
+        NDArrayCollector::<f32>::from_strings(
+            &["BallRigidBody", "BallAcceleration"],
+            &["PlayerRigidBody", "PlayerRotationDelta"],
+        )
+        .unwrap()
+    };
+
+    // Clones of a single template must not share stateful feature adders.
+    let template = make_collector();
+    let parallel: Vec<_> = process_replays_parallel(&replays, || template.clone_empty())
+        .into_iter()
+        .map(|result| result.and_then(|collector| collector.get_ndarray()))
+        .collect();
+    let sequential: Vec<_> = replays
+        .iter()
+        .map(|replay| {
+            make_collector()
+                .process_replay(replay)
+                .and_then(|collector| collector.get_ndarray())
+        })
+        .collect();
+
+    assert_eq!(parallel.len(), replays.len());
+    for (index, (parallel, sequential)) in parallel.iter().zip(sequential.iter()).enumerate() {
+        match (parallel, sequential) {
+            (Ok(parallel), Ok(sequential)) => assert_eq!(parallel, sequential),
+            (Err(parallel), Err(sequential)) => {
+                assert_eq!(index, 2);
+                assert_eq!(parallel.variant.code(), sequential.variant.code());
+            }
+            _ => panic!("Results for replay {} differ", index),
+        }
+    }
+}
+
+#[test]
+fn test_player_camera_settings() {
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .spawn(0, 40, CAMERA_SETTINGS_TYPE)
+        .update(
+            0,
+            40,
+            CAMERA_SETTINGS_PLAYER_KEY,
+            boxcars::Attribute::ActiveActor(boxcars::ActiveActor {
+                active: true,
+                actor: boxcars::ActorId(10),
+            }),
+        )
+        .update(
+            0,
+            40,
+            CAMERA_SETTINGS_PROFILE_KEY,
+            boxcars::Attribute::CamSettings(Box::new(boxcars::CamSettings {
+                fov: 110.0,
+                height: 100.0,
+                angle: -3.0,
+                distance: 270.0,
+                stiffness: 0.5,
+                swivel: 5.0,
+                transition: Some(1.2),
+            })),
+        );
+    let meta = ReplayDataCollector::new()
+        .get_replay_data(&builder.build())
+        .unwrap()
+        .meta;
+
+    let camera_settings = meta.team_zero[0].camera_settings.as_ref().unwrap();
+    assert!((60.0..=110.0).contains(&camera_settings.fov));
+    assert_eq!(camera_settings.swivel_speed, 5.0);
+    assert_eq!(camera_settings.transition_speed, Some(1.2));
+    assert_eq!(meta.team_one[0].camera_settings, None);
+}
+
+/// Returns the serialized frames of the player with the given Steam id.
+fn player_frames_json(frame_data: &FrameData, steam_id: u64) -> Vec<serde_json::Value> {
+    let (_, player_data) = frame_data
+        .players
+        .iter()
+        .find(|(player_id, _)| *player_id == boxcars::RemoteId::Steam(steam_id))
+        .unwrap();
+    match serde_json::to_value(player_data).unwrap()["frames"].take() {
+        serde_json::Value::Array(frames) => frames,
+        frames => panic!("Unexpected frames {:?}", frames),
+    }
+}
+
+#[test]
+fn test_replay_data_inputs() {
+    // Player frames interpolate between rigid body updates, and need a boost
+    // component, so player 1's car gets both.
+    let mut builder = two_player_replay_builder(320);
+    for frame in 0..320 {
+        builder.set_rigid_body(frame, 20, (0.0, -4608.0, 17.0), (0.0, 0.0, 0.0));
+    }
+    builder
+        .spawn_boost(0, 30, 20, 85)
+        .update(100, 20, THROTTLE_KEY, boxcars::Attribute::Byte(255))
+        .update(100, 20, STEER_KEY, boxcars::Attribute::Byte(1))
+        .update(100, 20, HANDBRAKE_KEY, boxcars::Attribute::Boolean(true));
+    let replay = builder.build();
+
+    let without_inputs = ReplayDataCollector::new().get_replay_data(&replay).unwrap();
+    let frames = player_frames_json(&without_inputs.frame_data, 1);
+    assert_eq!(frames.len(), 320);
+    assert!(frames
+        .iter()
+        .all(|frame| frame["Data"].is_object() && frame["Data"].get("inputs").is_none()));
+
+    let with_inputs = ReplayDataCollector::new()
+        .with_inputs()
+        .get_replay_data(&replay)
+        .unwrap();
+    let frames = player_frames_json(&with_inputs.frame_data, 1);
+    let inputs = |frame: usize| frames[frame]["Data"]["inputs"].clone();
+    // Unlike the throttle and steering, the handbrake is off until it is
+    // first replicated.
+    assert_eq!(
+        inputs(99),
+        serde_json::json!({"throttle": null, "steer": null, "handbrake": false})
+    );
+    assert_eq!(
+        inputs(100),
+        serde_json::json!({"throttle": 1.0, "steer": -1.0, "handbrake": true})
+    );
+    assert_eq!(inputs(319), inputs(100));
+}
+
+#[test]
+fn test_replay_data_close_enough() {
+    // The ball rises by 10 units every frame.
+    let raw_height = |frame: usize| 93.15 + 10.0 * frame as f32;
+    let mut builder = two_player_replay_builder(320);
+    for frame in 0..320 {
+        builder.set_rigid_body(
+            frame,
+            TEST_BALL_ACTOR,
+            (0.0, 0.0, raw_height(frame)),
+            (0.0, 0.0, 300.0),
+        );
+    }
+    let replay = builder.build();
+    let ball_heights = |mut collector: ReplayDataCollector| {
+        let mut processor = ReplayProcessor::new(&replay).unwrap();
+        // Sample at times that fall between network frames.
+        processor
+            .process(&mut FrameRateDecorator::new_from_fps(7.0, &mut collector))
+            .unwrap();
+        let replay_data = collector.get_replay_data_from_processor(processor).unwrap();
+        match serde_json::to_value(&replay_data.frame_data.ball_data).unwrap()["frames"].take() {
+            serde_json::Value::Array(frames) => frames
+                .iter()
+                .map(|frame| {
+                    frame["Data"]["rigid_body"]["location"]["z"]
+                        .as_f64()
+                        .unwrap() as f32
+                })
+                .collect::<Vec<_>>(),
+            frames => panic!("Unexpected frames {:?}", frames),
+        }
+    };
+    let is_raw = |height: &f32| (0..320).any(|frame| (raw_height(frame) - height).abs() < 1e-3);
+
+    let interpolated = ball_heights(ReplayDataCollector::new());
+    assert!(!interpolated.iter().all(is_raw));
+
+    let snapped = ball_heights(ReplayDataCollector::new().with_close_enough(1.0));
+    assert_eq!(snapped.len(), interpolated.len());
+    assert!(snapped.iter().all(is_raw));
+}
+
+#[test]
+fn test_stream_frames_writes_a_line_per_frame() {
+    let replay = two_player_replay_builder(320).build();
+    let processed_frames = FrameCounter(0).process_replay(&replay).unwrap().0;
+
+    let mut output = Vec::new();
+    ReplayDataCollector::new()
+        .stream_frames(&replay, &mut output)
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    // Every frame is followed by the trailer.
+    assert_eq!(lines.len(), processed_frames + 1);
+    for (index, line) in lines[..processed_frames].iter().enumerate() {
+        assert_eq!(line["frame"], index);
+    }
+    assert!(lines[processed_frames].get("meta").is_some());
+}
+
+#[test]
+fn test_player_field_third_without_car() {
+    // Player 3 only gets a car at frame 150.
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .spawn_player(0, 12, 3, true)
+        .spawn_car(150, 22, 12, (0.0, 0.0, 17.0));
+    let replay = builder.build();
+
+    let array = NDArrayCollector::<f32>::from_strings(&[], &["PlayerFieldThird"])
+        .unwrap()
+        .process_replay(&replay)
+        .unwrap()
+        .get_ndarray()
+        .unwrap();
+    let late_player = array.column(1).to_vec();
+    assert!(late_player[..150]
+        .iter()
+        .all(|value| *value == UNKNOWN_FIELD_THIRD_VALUE));
+    assert!(late_player[150..].iter().all(|value| *value == 1.0));
+    // Both other players wait in front of their own goal.
+    assert!(array.column(0).iter().all(|value| *value == 0.0));
+    assert!(array.column(2).iter().all(|value| *value == 0.0));
+}
+
+#[test]
+fn test_dribble_still_active_when_processing_stops() {
+    // Player 1 carries the ball on their roof from frame 200 until the end of
+    // the replay.
+    let mut builder = two_player_replay_builder(320);
+    builder.set_rigid_body(200, TEST_BALL_ACTOR, (0.0, -4608.0, 167.0), (0.0, 0.0, 0.0));
+    let replay = builder.build();
+
+    let mut processor = ReplayProcessor::new(&replay).unwrap();
+    let mut frames = processor.frames_iter();
+    for context in frames.by_ref() {
+        if context.unwrap().frame_number == 250 {
+            break;
+        }
+    }
+    let processor = frames.processor();
+    assert!(processor.dribbles.is_empty());
+    let active = processor.get_active_dribble().unwrap();
+    assert_eq!(active.player, boxcars::RemoteId::Steam(1));
+    assert_eq!((active.start_frame, active.end_frame), (200, 250));
+
+    let mut processor = ReplayProcessor::new(&replay).unwrap();
+    processor.process(&mut FrameCounter(0)).unwrap();
+    assert!(processor.get_active_dribble().is_none());
+    assert_eq!(processor.dribbles.len(), 1);
+    assert_eq!(
+        (
+            processor.dribbles[0].start_frame,
+            processor.dribbles[0].end_frame
+        ),
+        (200, 319)
+    );
+}
+
+#[test]
+fn test_collect_actor_rigid_bodies_separates_reused_actor_ids() {
+    // The ball actor is deleted at frame 100 and a new ball with the same id
+    // is spawned at frame 101.
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .set_rigid_body(50, TEST_BALL_ACTOR, (0.0, 100.0, 93.15), (0.0, 0.0, 0.0))
+        .delete(100, TEST_BALL_ACTOR)
+        .spawn_ball(101, (0.0, 0.0, 93.15))
+        .set_rigid_body(150, TEST_BALL_ACTOR, (0.0, -100.0, 93.15), (0.0, 0.0, 0.0));
+    let replay = builder.build();
+    let processor = ReplayProcessor::new(&replay).unwrap();
+    let ball = boxcars::ActorId(TEST_BALL_ACTOR);
+
+    let spawns: Vec<(usize, Vec<(usize, f32)>)> = processor
+        .collect_actor_rigid_bodies(&ball)
+        .unwrap()
+        .into_iter()
+        .map(|(spawn_frame, rigid_bodies)| {
+            (
+                spawn_frame,
+                rigid_bodies
+                    .into_iter()
+                    .map(|(index, rigid_body)| (index, rigid_body.location.y))
+                    .collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        spawns,
+        vec![
+            (0, vec![(0, 0.0), (50, 100.0)]),
+            (101, vec![(101, 0.0), (150, -100.0)])
+        ]
+    );
+    assert!(processor
+        .collect_actor_rigid_bodies(&boxcars::ActorId(99))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_ball_actor_id_follows_respawned_ball() {
+    // The ball is replaced by a ball with a new actor id at frame 100, and
+    // the processor is reset half way through.
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .delete(100, TEST_BALL_ACTOR)
+        .spawn(100, 5, BALL_TYPES[0])
+        .set_rigid_body(100, 5, (0.0, 0.0, 93.15), (0.0, 0.0, 0.0));
+    let replay = builder.build();
+    let mut processor = ReplayProcessor::new(&replay).unwrap();
+
+    let mut ball_ids = Vec::new();
+    let mut frames = processor.frames_iter();
+    while let Some(context) = frames.next() {
+        if [99, 100, 200].contains(&context.unwrap().frame_number) {
+            ball_ids.push(frames.processor().get_ball_actor_id().unwrap());
+        }
+    }
+    assert_eq!(
+        ball_ids,
+        vec![
+            boxcars::ActorId(TEST_BALL_ACTOR),
+            boxcars::ActorId(5),
+            boxcars::ActorId(5)
+        ]
+    );
+
+    processor.reset();
+    assert!(processor.get_ball_actor_id().is_err());
+    assert!(processor.get_ball_actor_ids().is_empty());
+    let mut frames = processor.frames_iter();
+    frames.next().unwrap().unwrap();
+    assert_eq!(
+        frames.processor().get_ball_actor_ids(),
+        &[boxcars::ActorId(TEST_BALL_ACTOR)]
+    );
+}
+
+#[test]
+fn test_boost_pad_states_follow_replicated_pickups() {
+    let pickup_key = "TAGame.VehiclePickup_TA:ReplicatedPickupData";
+    let pickup = |instigator: Option<i32>| {
+        boxcars::Attribute::Pickup(boxcars::Pickup {
+            instigator: instigator.map(boxcars::ActorId),
+            picked_up: instigator.is_some(),
+        })
+    };
+    // Player 1 picks up the small pad in front of their goal at frame 30.
+    // It respawns at frame 60, well before its respawn time has elapsed, is
+    // picked up again at frame 90, and is reset by the kickoff at frame 150.
+    let mut builder = two_player_replay_builder(320);
+    builder
+        .spawn(0, 40, "TAGame.VehiclePickup_Boost_TA")
+        .update(30, 40, pickup_key, pickup(Some(20)))
+        .update(60, 40, pickup_key, pickup(None))
+        .update(90, 40, pickup_key, pickup(Some(20)))
+        .update(
+            150,
+            TEST_GAME_ACTOR,
+            ROUND_COUNTDOWN_KEY,
+            boxcars::Attribute::Int(3),
+        );
+    let replay = builder.build();
+
+    let mut processor = ReplayProcessor::new(&replay).unwrap();
+    let mut pad_states = Vec::new();
+    let mut frames = processor.frames_iter();
+    while let Some(context) = frames.next() {
+        let context = context.unwrap();
+        if [29, 30, 59, 60, 90, 149, 150].contains(&context.frame_number) {
+            let state = frames
+                .processor()
+                .get_boost_pad_states(context.current_time)
+                .unwrap()
+                .remove(0);
+            pad_states.push((context.frame_number, state.is_active));
+        }
+    }
+    assert_eq!(
+        pad_states,
+        vec![
+            (29, true),
+            (30, false),
+            (59, false),
+            (60, true),
+            (90, false),
+            (149, false),
+            (150, true),
+        ]
+    );
+    let pickups: Vec<_> = processor
+        .boost_pickups
+        .iter()
+        .map(|pickup| (pickup.frame, pickup.player.clone(), pickup.pad_index))
+        .collect();
+    assert_eq!(
+        pickups,
+        vec![
+            (30, boxcars::RemoteId::Steam(1), 0),
+            (90, boxcars::RemoteId::Steam(1), 0)
+        ]
+    );
+}
+
+#[test]
+fn test_player_ping_persists_between_updates() {
+    // Player 1's ping is first replicated at frame 100, as 20 * 4 ms.
+    let mut builder = two_player_replay_builder(320);
+    builder.update(100, 10, PLAYER_PING_KEY, boxcars::Attribute::Byte(20));
+    let replay = builder.build();
+
+    let array = NDArrayCollector::<f32>::from_strings(&[], &["PlayerPing"])
+        .unwrap()
+        .process_replay(&replay)
+        .unwrap()
+        .get_ndarray()
+        .unwrap();
+    let ping = array.column(0).to_vec();
+    assert!(ping[..100].iter().all(|value| *value == 0.0));
+    assert!(ping[100..].iter().all(|value| *value == 80.0));
+    assert!(array.column(1).iter().all(|value| *value == 0.0));
+}
+
+#[test]
+fn test_seconds_remaining_of_non_soccar_game_events() {
+    for (game_type, game_mode) in [
+        (RUMBLE_GAME_TYPE, Some(GameMode::Rumble)),
+        ("Archetypes.GameEvent.GameEvent_Unknown", None),
+    ] {
+        let mut builder = ReplayBuilder::new(320);
+        builder
+            .spawn_game_of_type(0, game_type, 120)
+            .spawn_ball(0, (0.0, 0.0, 93.15))
+            .spawn_player(0, 10, 1, true)
+            .spawn_player(0, 11, 2, false)
+            .spawn_car(0, 20, 10, (0.0, -4608.0, 17.0))
+            .spawn_car(0, 21, 11, (0.0, 4608.0, 17.0));
+        let replay = builder.build();
+        let mut processor = ReplayProcessor::new(&replay).unwrap();
+        processor.process(&mut FrameCounter(0)).unwrap();
+
+        assert_eq!(processor.get_seconds_remaining().unwrap(), 120);
+        assert_eq!(processor.get_game_mode().ok(), game_mode);
+    }
+}